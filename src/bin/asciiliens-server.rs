@@ -0,0 +1,74 @@
+// asciiliens/src/bin/asciiliens-server.rs
+
+//! # ASCIIliens Arcade Server
+//!
+//! This binary hosts ASCIIliens for many players at once, e.g. for an office.
+//! It is responsible for:
+//! - Parsing its command line and reporting argument errors.
+//! - Listening for telnet connections and handing each to an `ArcadeServer`
+//!   session, which plays an independent game with that player.
+//! - Pointing every session at one shared high score table.
+//!
+//! The server never touches its own terminal, so it can run in the background.
+
+use asciiliens::cli::{self, ServerArgs, ServerCommand};
+use asciiliens::net::arcade::ArcadeServer;
+use asciiliens::scores::HighScores;
+use std::io;
+use std::process;
+
+/// The entry point of the arcade server.
+///
+/// Argument errors are reported with the usage text and exit code 2; a port
+/// that cannot be listened on exits with code 1.
+fn main() {
+    let command = match cli::parse_server_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, cli::server_usage());
+            process::exit(2);
+        }
+    };
+
+    let result = match command {
+        ServerCommand::Serve(args) => run_server(args),
+        ServerCommand::Help => {
+            println!("{}", cli::server_usage());
+            Ok(())
+        }
+        ServerCommand::Version => {
+            println!("asciiliens-server {}", env!("CARGO_PKG_VERSION"));
+            Ok(())
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+/// Runs the arcade server until it is stopped.
+///
+/// # Arguments
+/// * `args` - The `ServerArgs` parsed from the command line.
+///
+/// # Returns
+/// An `io::Result<()>`, which is only ever an error.
+fn run_server(args: ServerArgs) -> io::Result<()> {
+    let high_scores_path = args.scores.or_else(HighScores::default_path);
+    let server = ArcadeServer::bind(
+        ("0.0.0.0", args.port),
+        args.max_players,
+        high_scores_path.clone(),
+    )?;
+    println!(
+        "The arcade is open for up to {} players. Play with: telnet <this host> {}",
+        args.max_players, args.port
+    );
+    match &high_scores_path {
+        Some(path) => println!("High scores are kept in {}", path.display()),
+        None => println!("No data directory was found, so no high scores are kept."),
+    }
+    server.serve()
+}
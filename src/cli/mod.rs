@@ -0,0 +1,497 @@
+// asciiliens/src/cli/mod.rs
+
+//! This module parses the command lines of the `asciiliens` and
+//! `asciiliens-server` binaries.
+//!
+//! Parsing is kept free of any terminal handling, so that argument errors can be
+//! reported on a normal terminal before the game switches it to raw mode.
+
+use crate::game::options::GameOptions;
+use crate::net::NetMode;
+use crate::util::constants::{
+    ARCADE_DEFAULT_MAX_PLAYERS, ARCADE_DEFAULT_PORT, MAX_LEVEL, NET_DEFAULT_PORT,
+};
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// The number of games `simulate` plays when `--games` is not given.
+const DEFAULT_SIMULATED_GAMES: u32 = 100;
+
+/// How the game advances between frames.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum PlayMode {
+    /// Each key press advances exactly one frame (the classic game).
+    #[default]
+    Turn,
+    /// The game also advances on its own at a fixed tick when no key is pressed.
+    Realtime,
+}
+
+impl PlayMode {
+    /// Returns the lowercase name used on the command line and in saved files.
+    pub fn name(&self) -> &'static str {
+        match self {
+            PlayMode::Turn => "turn",
+            PlayMode::Realtime => "realtime",
+        }
+    }
+
+    /// Returns the other play mode.
+    pub fn next(&self) -> PlayMode {
+        match self {
+            PlayMode::Turn => PlayMode::Realtime,
+            PlayMode::Realtime => PlayMode::Turn,
+        }
+    }
+}
+
+impl FromStr for PlayMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "turn" => Ok(PlayMode::Turn),
+            "realtime" => Ok(PlayMode::Realtime),
+            _ => Err(format!("unknown mode '{}' (expected turn or realtime)", s)),
+        }
+    }
+}
+
+/// The settings for an interactive game.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct PlayArgs {
+    /// The options the game is created with.
+    pub options: GameOptions,
+    /// How the game advances between frames.
+    pub mode: PlayMode,
+    /// The TCP port the screen is streamed to spectators on, if any.
+    pub spectate: Option<u16>,
+    /// Whether spectators may connect from other hosts, rather than only this one.
+    pub spectate_public: bool,
+}
+
+/// A command requested on the command line.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Command {
+    /// Play the game interactively.
+    Play(PlayArgs),
+    /// Watch a recorded game.
+    Replay(PathBuf),
+    /// Print the high score table.
+    Scores,
+    /// Play a batch of games headlessly with the autopilot and print a summary.
+    Simulate {
+        /// The number of games to simulate.
+        games: u32,
+        /// The options shared by every simulated game.
+        options: GameOptions,
+    },
+    /// Host a networked game and wait for another player to join.
+    Host {
+        /// The options of the game.
+        options: GameOptions,
+        /// How the two players play together.
+        mode: NetMode,
+        /// The TCP port to listen on.
+        port: u16,
+    },
+    /// Join a networked game hosted by another player, at `host:port`.
+    Join(String),
+    /// Play today's daily challenge.
+    Daily,
+    /// Print the leaderboard of today's daily challenge.
+    DailyScores,
+    /// Open the practice sandbox on a game with the given options.
+    Sandbox(GameOptions),
+    /// Print the usage text.
+    Help,
+    /// Print the version.
+    Version,
+}
+
+/// The settings of the `asciiliens-server` arcade server.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ServerArgs {
+    /// The TCP port to listen on.
+    pub port: u16,
+    /// How many players may play at once.
+    pub max_players: usize,
+    /// The high score file shared by every player, or `None` for the default one.
+    pub scores: Option<PathBuf>,
+}
+
+impl Default for ServerArgs {
+    fn default() -> Self {
+        Self {
+            port: ARCADE_DEFAULT_PORT,
+            max_players: ARCADE_DEFAULT_MAX_PLAYERS,
+            scores: None,
+        }
+    }
+}
+
+/// A command requested on the command line of `asciiliens-server`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ServerCommand {
+    /// Run the arcade server.
+    Serve(ServerArgs),
+    /// Print the usage text.
+    Help,
+    /// Print the version.
+    Version,
+}
+
+/// An error in the command-line arguments.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CliError(pub String);
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for CliError {}
+
+/// Returns the usage text printed by `--help` and after argument errors.
+pub fn usage() -> String {
+    format!(
+        "ASCIIliens {version} - Turn-Based ASCII Arcade Action
+
+USAGE:
+    asciiliens [COMMAND] [OPTIONS]
+
+COMMANDS:
+    play              Play the game (the default when no command is given)
+    replay <FILE>     Watch a recorded game (the last game played is saved as
+                      'last.replay' in the asciiliens data directory)
+    scores            Print the high score table
+    simulate          Play games with the autopilot and print a summary
+    host              Host a two-player game over the network and wait for
+                      the other player to join
+    join <HOST>       Join a game hosted at HOST, or HOST:PORT
+    daily             Play today's daily challenge, the same run for everyone
+    daily --scores    Print today's daily leaderboard
+    sandbox           Practice in a sandbox: place aliens and blasts, step
+                      frames and tweak the rules while playing
+
+OPTIONS (play, simulate and sandbox):
+    --seed <N>            Seed for alien designs and movement
+    --mode <MODE>         turn (default) or realtime [play only]
+    --spectate <PORT>     Let others on this host watch with 'telnet localhost PORT' [play only]
+    --spectate-public     With --spectate, accept spectators on all network
+                          interfaces, so other hosts can watch [play only]
+    --difficulty <LEVEL>  easy, normal (default), hard or nightmare
+    --level <N>           Starting level, 1 (default) to {max_level}
+    --fire-rule <RULE>    unlimited (default), single-shot, max:N (at most N
+                          blasts in flight) or cooldown:N (N turns between shots)
+    --games <N>           Number of games to simulate (default {games}) [simulate only]
+
+OPTIONS (host, which also takes --seed, --difficulty and --level):
+    --port <N>            TCP port to listen on (default {port})
+    --versus              Play on separate fields for the higher score,
+                          instead of co-op on a shared field
+
+    -h, --help            Print this help
+    -V, --version         Print the version",
+        version = env!("CARGO_PKG_VERSION"),
+        max_level = MAX_LEVEL,
+        games = DEFAULT_SIMULATED_GAMES,
+        port = NET_DEFAULT_PORT,
+    )
+}
+
+/// Returns the usage text of `asciiliens-server`, printed by `--help` and after argument errors.
+pub fn server_usage() -> String {
+    format!(
+        "ASCIIliens arcade server {version} - hosts ASCIIliens for everyone over telnet
+
+USAGE:
+    asciiliens-server [OPTIONS]
+
+Players connect with 'telnet <this host> PORT'. Each gets a game of their
+own, and every finished game is entered into one shared high score table.
+
+OPTIONS:
+    --port <N>            TCP port to listen on (default {port})
+    --max-players <N>     Players admitted at once (default {max_players})
+    --scores <FILE>       High score file (default: the one 'asciiliens' uses)
+
+    -h, --help            Print this help
+    -V, --version         Print the version",
+        version = env!("CARGO_PKG_VERSION"),
+        port = ARCADE_DEFAULT_PORT,
+        max_players = ARCADE_DEFAULT_MAX_PLAYERS,
+    )
+}
+
+/// Parses the command-line arguments (excluding the program name).
+///
+/// With no command, the arguments are parsed as options for `play`.
+///
+/// # Arguments
+/// * `args` - The arguments, e.g. `std::env::args().skip(1)`.
+///
+/// # Returns
+/// The requested `Command`, or a `CliError` describing the first problem found.
+pub fn parse_args<I>(args: I) -> Result<Command, CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    parse_args_with_defaults(args, PlayArgs::default())
+}
+
+/// Parses the command-line arguments, starting `play` from the given defaults.
+///
+/// This lets the saved settings supply the play mode and difficulty while
+/// flags given on the command line still take precedence.
+///
+/// # Arguments
+/// * `args` - The arguments, e.g. `std::env::args().skip(1)`.
+/// * `defaults` - The `PlayArgs` used for anything the arguments do not set.
+///
+/// # Returns
+/// The requested `Command`, or a `CliError` describing the first problem found.
+pub fn parse_args_with_defaults<I>(args: I, defaults: PlayArgs) -> Result<Command, CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let args: Vec<String> = args.into_iter().collect();
+
+    // Help and version win wherever they appear.
+    if args.iter().any(|a| a == "-h" || a == "--help") {
+        return Ok(Command::Help);
+    }
+    if args.iter().any(|a| a == "-V" || a == "--version") {
+        return Ok(Command::Version);
+    }
+
+    let (command, rest) = match args.first().map(String::as_str) {
+        None => return Ok(Command::Play(defaults)),
+        Some(first) if first.starts_with('-') => ("play", &args[..]),
+        Some(first) => (first, &args[1..]),
+    };
+
+    match command {
+        "play" => parse_play_args(rest, defaults).map(Command::Play),
+        "replay" => match rest {
+            [file] => Ok(Command::Replay(PathBuf::from(file))),
+            [] => Err(CliError("'replay' requires a replay file".to_string())),
+            [_, extra, ..] => Err(CliError(format!(
+                "unexpected argument '{}' for 'replay'",
+                extra
+            ))),
+        },
+        "scores" => match rest.first() {
+            None => Ok(Command::Scores),
+            Some(extra) => Err(CliError(format!(
+                "unexpected argument '{}' for 'scores'",
+                extra
+            ))),
+        },
+        "simulate" => parse_simulate_args(rest),
+        "host" => parse_host_args(rest, defaults.options),
+        "join" => match rest {
+            [address] if address.contains(':') => Ok(Command::Join(address.clone())),
+            [address] => Ok(Command::Join(format!("{}:{}", address, NET_DEFAULT_PORT))),
+            [] => Err(CliError("'join' requires the host's address".to_string())),
+            [_, extra, ..] => Err(CliError(format!(
+                "unexpected argument '{}' for 'join'",
+                extra
+            ))),
+        },
+        "daily" => match rest {
+            [] => Ok(Command::Daily),
+            [flag] if flag == "--scores" => Ok(Command::DailyScores),
+            [extra, ..] => Err(CliError(format!(
+                "unexpected argument '{}' for 'daily'",
+                extra
+            ))),
+        },
+        "sandbox" => parse_sandbox_args(rest, defaults.options),
+        "help" => Ok(Command::Help),
+        other => Err(CliError(format!("unknown command '{}'", other))),
+    }
+}
+
+/// Parses the command-line arguments of `asciiliens-server` (excluding the program name).
+///
+/// # Arguments
+/// * `args` - The arguments, e.g. `std::env::args().skip(1)`.
+///
+/// # Returns
+/// The requested `ServerCommand`, or a `CliError` describing the first problem found.
+pub fn parse_server_args<I>(args: I) -> Result<ServerCommand, CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let args: Vec<String> = args.into_iter().collect();
+    if args.iter().any(|a| a == "-h" || a == "--help") {
+        return Ok(ServerCommand::Help);
+    }
+    if args.iter().any(|a| a == "-V" || a == "--version") {
+        return Ok(ServerCommand::Version);
+    }
+
+    let mut server = ServerArgs::default();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if !arg.starts_with("--") {
+            return Err(CliError(format!("unexpected argument '{}'", arg)));
+        }
+        let (name, value) = take_value(arg, &mut iter)?;
+        match name {
+            "--port" => server.port = parse_number(name, value)?,
+            "--max-players" => {
+                server.max_players = parse_number(name, value)?;
+                if server.max_players == 0 {
+                    return Err(CliError("max-players must be at least 1".to_string()));
+                }
+            }
+            "--scores" => server.scores = Some(PathBuf::from(value)),
+            _ => return Err(CliError(format!("unknown option '{}'", name))),
+        }
+    }
+    Ok(ServerCommand::Serve(server))
+}
+
+/// Splits `--name=value` or `--name value` into the option name and its value.
+fn take_value<'a>(
+    arg: &'a str,
+    rest: &mut impl Iterator<Item = &'a String>,
+) -> Result<(&'a str, &'a str), CliError> {
+    if let Some((name, value)) = arg.split_once('=') {
+        return Ok((name, value));
+    }
+    match rest.next() {
+        Some(value) => Ok((arg, value.as_str())),
+        None => Err(CliError(format!("option '{}' requires a value", arg))),
+    }
+}
+
+/// Parses a numeric option value, naming the option in the error.
+fn parse_number<T: FromStr>(name: &str, value: &str) -> Result<T, CliError> {
+    value
+        .parse()
+        .map_err(|_| CliError(format!("invalid value '{}' for '{}'", value, name)))
+}
+
+/// Applies one game option shared by `play` and `simulate`.
+///
+/// # Returns
+/// `Ok(true)` if the option was recognised and applied, `Ok(false)` if it is not a game option.
+fn apply_game_option(options: &mut GameOptions, name: &str, value: &str) -> Result<bool, CliError> {
+    match name {
+        "--seed" => options.seed = Some(parse_number(name, value)?),
+        "--difficulty" => options.difficulty = value.parse().map_err(CliError)?,
+        "--fire-rule" => options.fire_rule = value.parse().map_err(CliError)?,
+        "--level" => {
+            let level: u16 = parse_number(name, value)?;
+            if !(1..=MAX_LEVEL).contains(&level) {
+                return Err(CliError(format!(
+                    "level must be between 1 and {}, got {}",
+                    MAX_LEVEL, level
+                )));
+            }
+            options.level = level;
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+/// Parses the options of the `play` command, starting from `defaults`.
+fn parse_play_args(args: &[String], defaults: PlayArgs) -> Result<PlayArgs, CliError> {
+    let mut play = defaults;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if !arg.starts_with("--") {
+            return Err(CliError(format!("unexpected argument '{}'", arg)));
+        }
+        if arg == "--spectate-public" {
+            play.spectate_public = true;
+            continue;
+        }
+        let (name, value) = take_value(arg, &mut iter)?;
+        if name == "--mode" {
+            play.mode = value.parse().map_err(CliError)?;
+        } else if name == "--spectate" {
+            play.spectate = Some(parse_number(name, value)?);
+        } else if !apply_game_option(&mut play.options, name, value)? {
+            return Err(CliError(format!("unknown option '{}' for 'play'", name)));
+        }
+    }
+    if play.spectate_public && play.spectate.is_none() {
+        return Err(CliError(
+            "'--spectate-public' needs '--spectate <PORT>'".to_string(),
+        ));
+    }
+    Ok(play)
+}
+
+/// Parses the options of the `simulate` command.
+fn parse_simulate_args(args: &[String]) -> Result<Command, CliError> {
+    let mut games = DEFAULT_SIMULATED_GAMES;
+    let mut options = GameOptions::default();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if !arg.starts_with("--") {
+            return Err(CliError(format!("unexpected argument '{}'", arg)));
+        }
+        let (name, value) = take_value(arg, &mut iter)?;
+        if name == "--games" {
+            games = parse_number(name, value)?;
+        } else if !apply_game_option(&mut options, name, value)? {
+            return Err(CliError(format!(
+                "unknown option '{}' for 'simulate'",
+                name
+            )));
+        }
+    }
+    Ok(Command::Simulate { games, options })
+}
+
+/// Parses the options of the `sandbox` command, starting from the given game options.
+fn parse_sandbox_args(args: &[String], defaults: GameOptions) -> Result<Command, CliError> {
+    let mut options = defaults;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if !arg.starts_with("--") {
+            return Err(CliError(format!("unexpected argument '{}'", arg)));
+        }
+        let (name, value) = take_value(arg, &mut iter)?;
+        if !apply_game_option(&mut options, name, value)? {
+            return Err(CliError(format!("unknown option '{}' for 'sandbox'", name)));
+        }
+    }
+    Ok(Command::Sandbox(options))
+}
+
+/// Parses the options of the `host` command, starting from the given game options.
+fn parse_host_args(args: &[String], defaults: GameOptions) -> Result<Command, CliError> {
+    let mut options = defaults;
+    let mut mode = NetMode::CoOp;
+    let mut port = NET_DEFAULT_PORT;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if !arg.starts_with("--") {
+            return Err(CliError(format!("unexpected argument '{}'", arg)));
+        }
+        if arg == "--versus" {
+            mode = NetMode::Versus; // A flag, without a value.
+            continue;
+        }
+        let (name, value) = take_value(arg, &mut iter)?;
+        if name == "--port" {
+            port = parse_number(name, value)?;
+        } else if !apply_game_option(&mut options, name, value)? {
+            return Err(CliError(format!("unknown option '{}' for 'host'", name)));
+        }
+    }
+    Ok(Command::Host {
+        options,
+        mode,
+        port,
+    })
+}
@@ -0,0 +1,236 @@
+// asciiliens/src/config/keys.rs
+
+//! This module defines the rebindable keys used during play.
+
+use crossterm::event::KeyCode;
+
+/// An action during play that can be bound to a key.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum KeyAction {
+    /// Move the ship one column to the left.
+    MoveLeft,
+    /// Move the ship one column to the right.
+    MoveRight,
+    /// Unleash a blast.
+    Fire,
+    /// Pause the game.
+    Pause,
+    /// Quit the game.
+    Quit,
+    /// Step back one turn, in turn-based mode.
+    Rewind,
+}
+
+impl KeyAction {
+    /// Every action, in the order they are listed on the options screen and in the config file.
+    pub const ALL: [KeyAction; 6] = [
+        KeyAction::MoveLeft,
+        KeyAction::MoveRight,
+        KeyAction::Fire,
+        KeyAction::Pause,
+        KeyAction::Quit,
+        KeyAction::Rewind,
+    ];
+
+    /// The actions the second ship of a co-op game has keys of its own for.
+    pub const SECOND_SHIP: [KeyAction; 3] =
+        [KeyAction::MoveLeft, KeyAction::MoveRight, KeyAction::Fire];
+
+    /// Returns the key used for the action in the `[keys]` section of the config file.
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            KeyAction::MoveLeft => "left",
+            KeyAction::MoveRight => "right",
+            KeyAction::Fire => "fire",
+            KeyAction::Pause => "pause",
+            KeyAction::Quit => "quit",
+            KeyAction::Rewind => "rewind",
+        }
+    }
+
+    /// Returns the label shown for the action on the options screen.
+    pub fn label(&self) -> &'static str {
+        match self {
+            KeyAction::MoveLeft => "Key: move left",
+            KeyAction::MoveRight => "Key: move right",
+            KeyAction::Fire => "Key: fire",
+            KeyAction::Pause => "Key: pause",
+            KeyAction::Quit => "Key: quit",
+            KeyAction::Rewind => "Key: rewind",
+        }
+    }
+
+    /// Returns the label shown for the second ship's key for the action on the options screen.
+    pub fn second_ship_label(&self) -> &'static str {
+        match self {
+            KeyAction::MoveLeft => "P2 key: move left",
+            KeyAction::MoveRight => "P2 key: move right",
+            KeyAction::Fire => "P2 key: fire",
+            KeyAction::Pause => "P2 key: pause",
+            KeyAction::Quit => "P2 key: quit",
+            KeyAction::Rewind => "P2 key: rewind",
+        }
+    }
+}
+
+/// The key bound to each `KeyAction`. Every action has a distinct key.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct KeyBindings {
+    left: KeyCode,
+    right: KeyCode,
+    fire: KeyCode,
+    pause: KeyCode,
+    quit: KeyCode,
+    rewind: KeyCode,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            left: KeyCode::Left,
+            right: KeyCode::Right,
+            fire: KeyCode::Char(' '),
+            pause: KeyCode::Char('p'),
+            quit: KeyCode::Char('q'),
+            rewind: KeyCode::Char('u'),
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Returns the default keys of the second ship in a co-op game: A and D
+    /// move and W fires, leaving the arrows and Space to the first ship.
+    ///
+    /// Only the `KeyAction::SECOND_SHIP` keys of these bindings are used;
+    /// pausing, quitting and rewinding always use the first ship's keys.
+    pub fn second_ship() -> Self {
+        Self {
+            left: KeyCode::Char('a'),
+            right: KeyCode::Char('d'),
+            fire: KeyCode::Char('w'),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the key bound to an action.
+    pub fn key(&self, action: KeyAction) -> KeyCode {
+        match action {
+            KeyAction::MoveLeft => self.left,
+            KeyAction::MoveRight => self.right,
+            KeyAction::Fire => self.fire,
+            KeyAction::Pause => self.pause,
+            KeyAction::Quit => self.quit,
+            KeyAction::Rewind => self.rewind,
+        }
+    }
+
+    /// Returns a mutable reference to the key bound to an action.
+    fn key_mut(&mut self, action: KeyAction) -> &mut KeyCode {
+        match action {
+            KeyAction::MoveLeft => &mut self.left,
+            KeyAction::MoveRight => &mut self.right,
+            KeyAction::Fire => &mut self.fire,
+            KeyAction::Pause => &mut self.pause,
+            KeyAction::Quit => &mut self.quit,
+            KeyAction::Rewind => &mut self.rewind,
+        }
+    }
+
+    /// Binds a key to an action.
+    ///
+    /// If another action already uses the key, the two actions swap keys, so
+    /// every action always keeps a key of its own.
+    ///
+    /// # Arguments
+    /// * `action` - The action to rebind.
+    /// * `key` - The new key.
+    pub fn bind(&mut self, action: KeyAction, key: KeyCode) {
+        let key = normalize(key);
+        let previous = self.key(action);
+        if let Some(other) = self.action_for(key) {
+            *self.key_mut(other) = previous;
+        }
+        *self.key_mut(action) = key;
+    }
+
+    /// Returns the action bound to a key, if any. Letters match in either case.
+    pub fn action_for(&self, key: KeyCode) -> Option<KeyAction> {
+        let key = normalize(key);
+        KeyAction::ALL.into_iter().find(|a| self.key(*a) == key)
+    }
+
+    /// Returns every action with its key, in `KeyAction::ALL` order.
+    pub fn entries(&self) -> [(KeyAction, KeyCode); 6] {
+        KeyAction::ALL.map(|a| (a, self.key(a)))
+    }
+}
+
+/// Parses one line of the `[keys]` or `[co_op_keys]` section of the config file.
+///
+/// # Arguments
+/// * `action` - The config name of the action, e.g. `"fire"`.
+/// * `value` - The key name, e.g. `"Space"`.
+///
+/// # Returns
+/// The action and its key, or `None` for an unknown action or key name.
+pub fn parse_binding(action: &str, value: &str) -> Option<(KeyAction, KeyCode)> {
+    let action = KeyAction::ALL
+        .into_iter()
+        .find(|a| a.config_name() == action)?;
+    Some((action, parse_key_name(value)?))
+}
+
+/// Lowercases letter keys, so bindings ignore Shift and Caps Lock.
+fn normalize(key: KeyCode) -> KeyCode {
+    match key {
+        KeyCode::Char(c) => KeyCode::Char(c.to_ascii_lowercase()),
+        other => other,
+    }
+}
+
+/// Returns the name of a key as written in the config file and shown on screen.
+///
+/// # Arguments
+/// * `key` - The key.
+///
+/// # Returns
+/// A name such as `"Left"`, `"Space"` or `"p"`.
+pub fn key_name(key: KeyCode) -> String {
+    match key {
+        KeyCode::Left => "Left".to_string(),
+        KeyCode::Right => "Right".to_string(),
+        KeyCode::Up => "Up".to_string(),
+        KeyCode::Down => "Down".to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        other => format!("{:?}", other),
+    }
+}
+
+/// Parses a key name written by `key_name`.
+///
+/// # Arguments
+/// * `name` - The key name, matched case-insensitively for named keys.
+///
+/// # Returns
+/// `Some(KeyCode)`, or `None` for a name that cannot be bound.
+pub fn parse_key_name(name: &str) -> Option<KeyCode> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(normalize(KeyCode::Char(c)));
+    }
+    match name.to_ascii_lowercase().as_str() {
+        "left" => Some(KeyCode::Left),
+        "right" => Some(KeyCode::Right),
+        "up" => Some(KeyCode::Up),
+        "down" => Some(KeyCode::Down),
+        "enter" => Some(KeyCode::Enter),
+        "tab" => Some(KeyCode::Tab),
+        "backspace" => Some(KeyCode::Backspace),
+        "space" => Some(KeyCode::Char(' ')),
+        _ => None,
+    }
+}
@@ -0,0 +1,303 @@
+// asciiliens/src/config/mod.rs
+
+//! This module holds the player's settings and persists them to a small
+//! TOML-style config file in the user's config directory:
+//!
+//! ```text
+//! mode = "turn"
+//! difficulty = "normal"
+//! fire_rule = "unlimited"
+//! theme = "classic"
+//! glyphs = "unicode"
+//! sound = false
+//! reduced_motion = false
+//! starfield = false
+//! ghost = false
+//! preview = false
+//!
+//! [keys]
+//! left = "Left"
+//! right = "Right"
+//! fire = "Space"
+//! pause = "p"
+//! quit = "q"
+//! rewind = "u"
+//!
+//! [co_op_keys]
+//! left = "a"
+//! right = "d"
+//! fire = "w"
+//! ```
+//!
+//! Loading never fails: a missing file, an unknown key or an invalid value
+//! simply leaves the affected setting at its default, and a key bound twice,
+//! even once for each ship, is swapped as if it had been rebound on the
+//! options screen.
+
+pub mod keys;
+
+use self::keys::{KeyAction, KeyBindings};
+use crate::cli::{PlayArgs, PlayMode};
+use crate::display::theme::{GlyphSet, Theme};
+use crate::game::difficulty::Difficulty;
+use crate::game::options::GameOptions;
+use crate::game::rules::FireRule;
+use crate::util::paths;
+use crossterm::event::KeyCode;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The name of the config file inside the game's config directory.
+const CONFIG_FILE_NAME: &str = "config.toml";
+
+/// The settings the player can change on the options screen.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Settings {
+    /// How the game advances between frames.
+    pub mode: PlayMode,
+    /// The difficulty new games are played at.
+    pub difficulty: Difficulty,
+    /// How many blasts new games allow in flight and how often one may be unleashed.
+    pub fire_rule: FireRule,
+    /// The color everything is drawn in.
+    pub theme: Theme,
+    /// The characters the game may draw with.
+    pub glyphs: GlyphSet,
+    /// The keys used during play.
+    pub keys: KeyBindings,
+    /// Whether hits and the end of a game ring the terminal bell.
+    pub sound: bool,
+    /// Whether animated effects are replaced by static ones.
+    pub reduced_motion: bool,
+    /// Whether a scrolling starfield is drawn behind the playfield.
+    pub starfield: bool,
+    /// Whether seeded games show a ghost ship replaying the best previous run.
+    pub ghost: bool,
+    /// Whether games show where blasts and aliens go next, and a countdown to the next descent.
+    pub preview: bool,
+    /// The keys of the second ship in a co-op game. Only moving and firing use them.
+    pub co_op_keys: KeyBindings,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            mode: PlayMode::default(),
+            difficulty: Difficulty::default(),
+            fire_rule: FireRule::default(),
+            theme: Theme::default(),
+            glyphs: GlyphSet::default(),
+            keys: KeyBindings::default(),
+            sound: false,
+            reduced_motion: false,
+            starfield: false,
+            ghost: false,
+            preview: false,
+            co_op_keys: KeyBindings::second_ship(),
+        }
+    }
+}
+
+impl Settings {
+    /// Returns the default location of the config file, inside the user's config directory.
+    ///
+    /// # Returns
+    /// `Some(PathBuf)` for the config file, or `None` if no config directory is known.
+    pub fn default_path() -> Option<PathBuf> {
+        paths::config_file(CONFIG_FILE_NAME)
+    }
+
+    /// Loads the settings from the given file, falling back to defaults for
+    /// anything missing or invalid.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the config file.
+    ///
+    /// # Returns
+    /// The loaded `Settings`.
+    pub fn load_from(path: &Path) -> Settings {
+        match fs::read_to_string(path) {
+            Ok(contents) => Settings::parse(&contents),
+            Err(_) => Settings::default(),
+        }
+    }
+
+    /// Parses settings from the contents of a config file.
+    ///
+    /// # Arguments
+    /// * `contents` - The text of the config file.
+    ///
+    /// # Returns
+    /// The parsed `Settings`; unknown keys and invalid values are ignored.
+    pub fn parse(contents: &str) -> Settings {
+        let mut settings = Settings::default();
+        let mut section = String::new();
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                section = name.trim().to_string();
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = unquote(value.trim());
+            match (section.as_str(), key.trim()) {
+                ("", "mode") => set_parsed(&mut settings.mode, value),
+                ("", "difficulty") => set_parsed(&mut settings.difficulty, value),
+                ("", "fire_rule") => set_parsed(&mut settings.fire_rule, value),
+                ("", "theme") => set_parsed(&mut settings.theme, value),
+                ("", "glyphs") => set_parsed(&mut settings.glyphs, value),
+                ("", "sound") => set_parsed(&mut settings.sound, value),
+                ("", "reduced_motion") => set_parsed(&mut settings.reduced_motion, value),
+                ("", "starfield") => set_parsed(&mut settings.starfield, value),
+                ("", "ghost") => set_parsed(&mut settings.ghost, value),
+                ("", "preview") => set_parsed(&mut settings.preview, value),
+                ("keys", action) => {
+                    if let Some((action, key)) = keys::parse_binding(action, value) {
+                        settings.bind_key(action, key);
+                    }
+                }
+                ("co_op_keys", action) => {
+                    if let Some((action, key)) = keys::parse_binding(action, value) {
+                        settings.bind_co_op_key(action, key);
+                    }
+                }
+                _ => { /* Ignore unknown keys. */ }
+            }
+        }
+        settings
+    }
+
+    /// Serializes the settings into the config file format.
+    pub fn serialize(&self) -> String {
+        let mut contents = format!(
+            "# ASCIIliens settings\nmode = \"{}\"\ndifficulty = \"{}\"\nfire_rule = \"{}\"\ntheme = \"{}\"\nglyphs = \"{}\"\nsound = {}\nreduced_motion = {}\nstarfield = {}\nghost = {}\npreview = {}\n\n[keys]\n",
+            self.mode.name(),
+            self.difficulty.name(),
+            self.fire_rule,
+            self.theme.name(),
+            self.glyphs.name(),
+            self.sound,
+            self.reduced_motion,
+            self.starfield,
+            self.ghost,
+            self.preview
+        );
+        for (action, key) in self.keys.entries() {
+            contents.push_str(&format!(
+                "{} = \"{}\"\n",
+                action.config_name(),
+                keys::key_name(key)
+            ));
+        }
+        contents.push_str("\n[co_op_keys]\n");
+        for (action, key) in self.co_op_keys.entries() {
+            if !KeyAction::SECOND_SHIP.contains(&action) {
+                continue; // The second ship pauses, quits and rewinds with the first ship's keys.
+            }
+            contents.push_str(&format!(
+                "{} = \"{}\"\n",
+                action.config_name(),
+                keys::key_name(key)
+            ));
+        }
+        contents
+    }
+
+    /// Binds a key to an action of the first ship.
+    ///
+    /// A key already used by the first ship or by the second ship of a co-op
+    /// game is swapped with the action's previous key, so no key is ever used
+    /// by both ships.
+    ///
+    /// # Arguments
+    /// * `action` - The action to rebind.
+    /// * `key` - The new key.
+    pub fn bind_key(&mut self, action: KeyAction, key: KeyCode) {
+        let previous = self.keys.key(action);
+        if let Some(other) = self.second_ship_action_for(key) {
+            self.co_op_keys.bind(other, previous);
+        }
+        self.keys.bind(action, key);
+    }
+
+    /// Binds a key to an action of the second ship in a co-op game, swapping
+    /// keys with either ship like `bind_key`. Actions outside
+    /// `KeyAction::SECOND_SHIP` are ignored.
+    ///
+    /// # Arguments
+    /// * `action` - The action to rebind.
+    /// * `key` - The new key.
+    pub fn bind_co_op_key(&mut self, action: KeyAction, key: KeyCode) {
+        if !KeyAction::SECOND_SHIP.contains(&action) {
+            return;
+        }
+        let previous = self.co_op_keys.key(action);
+        if let Some(other) = self.keys.action_for(key) {
+            self.keys.bind(other, previous);
+        }
+        self.co_op_keys.bind(action, key);
+    }
+
+    /// Returns the second ship's action bound to a key, if any.
+    fn second_ship_action_for(&self, key: KeyCode) -> Option<KeyAction> {
+        self.co_op_keys
+            .action_for(key)
+            .filter(|action| KeyAction::SECOND_SHIP.contains(action))
+    }
+
+    /// Saves the settings to the given file, creating its directory if needed.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the config file.
+    ///
+    /// # Returns
+    /// An `io::Result<()>` indicating success or failure of the write.
+    pub fn save_to(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.serialize())
+    }
+
+    /// Returns whether the playfield starfield should be shown. Since the stars
+    /// scroll, reduced motion hides them too.
+    pub fn show_starfield(&self) -> bool {
+        self.starfield && !self.reduced_motion
+    }
+
+    /// Returns the play settings as the defaults for command-line parsing, so
+    /// flags given on the command line override the config file.
+    pub fn play_args(&self) -> PlayArgs {
+        PlayArgs {
+            options: GameOptions {
+                difficulty: self.difficulty,
+                fire_rule: self.fire_rule,
+                ..GameOptions::default()
+            },
+            mode: self.mode,
+            spectate: None,
+            spectate_public: false,
+        }
+    }
+}
+
+/// Strips one pair of surrounding double quotes, if present.
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+/// Replaces `target` with the parsed value, leaving it unchanged if the value is invalid.
+fn set_parsed<T: std::str::FromStr>(target: &mut T, value: &str) {
+    if let Ok(parsed) = value.parse() {
+        *target = parsed;
+    }
+}
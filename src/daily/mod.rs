@@ -0,0 +1,350 @@
+// asciiliens/src/daily/mod.rs
+
+//! This module runs the daily challenge: one run a day, the same for everyone.
+//!
+//! The seed and the wave (starting level) of the day's run are derived from
+//! the date in UTC, so everyone who plays on the same day faces the identical
+//! invasion. The wave climbs through the week, from 1 on Monday to 7 on
+//! Sunday. Daily results are kept in a leaderboard file of their own, one
+//! `|`-separated line per run, and every run ends with a short text card to
+//! share with the rest of the team.
+
+use crate::game::difficulty::Difficulty;
+use crate::game::options::GameOptions;
+use crate::game::{Game, GameState};
+use crate::util::paths;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Mutex, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The name of the daily leaderboard file inside the game's data directory.
+const DAILY_FILE_NAME: &str = "daily.txt";
+/// The number of days of results kept in the daily leaderboard file.
+const DAILY_KEEP_DAYS: i64 = 30;
+/// The number of seconds in a day.
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Held while the daily leaderboard file is read, updated and written back.
+static RECORD_LOCK: Mutex<()> = Mutex::new(());
+
+/// The daily challenge of one day.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+pub struct DailyChallenge {
+    days: i64, // Days since 1970-01-01.
+}
+
+impl DailyChallenge {
+    /// Returns today's challenge, by the date in UTC.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        Self::from_days_since_epoch((seconds / SECONDS_PER_DAY) as i64)
+    }
+
+    /// Returns the challenge of the given day.
+    ///
+    /// # Arguments
+    /// * `days` - The number of days since 1970-01-01.
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        Self { days }
+    }
+
+    /// Returns the number of days since 1970-01-01.
+    pub fn days_since_epoch(&self) -> i64 {
+        self.days
+    }
+
+    /// Returns the date as year, month (1 to 12) and day of the month (1 to 31).
+    pub fn date(&self) -> (i64, u32, u32) {
+        // Converts days to a date in the proleptic Gregorian calendar, counting
+        // years from March so that the leap day comes last.
+        let z = self.days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_from_march = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u32;
+        let month = if month_from_march < 10 {
+            month_from_march + 3
+        } else {
+            month_from_march - 9
+        } as u32;
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        (year, month, day)
+    }
+
+    /// Returns the wave of the day's run: 1 on Monday, climbing to 7 on Sunday.
+    pub fn wave(&self) -> u16 {
+        // 1970-01-01 was a Thursday, the fourth day of the week.
+        (self.days + 3).rem_euclid(7) as u16 + 1
+    }
+
+    /// Returns the seed of the day's run, the same for everyone.
+    pub fn seed(&self) -> u64 {
+        // FNV-1a, so the seed never depends on the platform or the Rust version.
+        format!("asciiliens daily {}", self)
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+            })
+    }
+
+    /// Returns the options of the day's run. The difficulty is always normal,
+    /// so everyone's scores are comparable.
+    pub fn options(&self) -> GameOptions {
+        GameOptions {
+            seed: Some(self.seed()),
+            difficulty: Difficulty::Normal,
+            level: self.wave(),
+            ..GameOptions::default()
+        }
+    }
+}
+
+impl fmt::Display for DailyChallenge {
+    /// Formats the challenge as its date, e.g. `2026-10-18`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (year, month, day) = self.date();
+        write!(f, "{:04}-{:02}-{:02}", year, month, day)
+    }
+}
+
+impl FromStr for DailyChallenge {
+    type Err = String;
+
+    /// Parses a date such as `2026-10-18`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid date '{}' (expected YYYY-MM-DD)", s);
+        let mut parts = s.splitn(3, '-');
+        let mut next = || -> Result<i64, String> {
+            parts
+                .next()
+                .and_then(|part| part.parse().ok())
+                .ok_or_else(invalid)
+        };
+        let (year, month, day) = (next()?, next()?, next()?);
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return Err(invalid());
+        }
+        // The inverse of `date`; a day past the end of its month, such as
+        // 02-30, comes back as a different date and is rejected.
+        let shifted_year = if month <= 2 { year - 1 } else { year };
+        let era = shifted_year.div_euclid(400);
+        let year_of_era = shifted_year.rem_euclid(400);
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        let challenge = Self::from_days_since_epoch(era * 146_097 + day_of_era - 719_468);
+        if challenge.date() != (year, month as u32, day as u32) {
+            return Err(invalid());
+        }
+        Ok(challenge)
+    }
+}
+
+/// One finished run of a daily challenge.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DailyEntry {
+    /// The day the run was played.
+    pub challenge: DailyChallenge,
+    /// The final score.
+    pub score: i32,
+    /// Whether the run was won.
+    pub won: bool,
+    /// The number of blasts that hit an alien.
+    pub hits: u32,
+    /// The number of blasts unleashed.
+    pub shots_fired: u32,
+}
+
+impl DailyEntry {
+    /// Creates an entry describing a finished run of the given challenge.
+    pub fn from_game(challenge: DailyChallenge, game: &Game) -> Self {
+        Self {
+            challenge,
+            score: game.score(),
+            won: game.state() == GameState::Win,
+            hits: game.stats().hits,
+            shots_fired: game.stats().shots_fired,
+        }
+    }
+
+    /// Returns the percentage of blasts that hit an alien, rounded down, or 0 if none were unleashed.
+    pub fn accuracy_percent(&self) -> u32 {
+        (self.hits * 100).checked_div(self.shots_fired).unwrap_or(0)
+    }
+
+    /// Returns the shareable result card of the run.
+    ///
+    /// # Arguments
+    /// * `rank` - The run's 1-based rank among the day's runs and the number
+    ///   of runs that day, or `None` if there is no leaderboard to rank it in.
+    ///
+    /// # Returns
+    /// The lines of the card, ready to print and paste.
+    pub fn card(&self, rank: Option<(usize, usize)>) -> Vec<String> {
+        let mut lines = vec![
+            format!("ASCIIliens daily challenge {}", self.challenge),
+            format!(
+                "Wave {} | {} | Score {}",
+                self.challenge.wave(),
+                if self.won { "Planet saved" } else { "Invaded" },
+                self.score
+            ),
+            format!(
+                "Accuracy {}% ({} of {} shots)",
+                self.accuracy_percent(),
+                self.hits,
+                self.shots_fired
+            ),
+        ];
+        if let Some((rank, runs)) = rank {
+            lines.push(format!("#{} of {} runs today", rank, runs));
+        }
+        lines
+    }
+
+    /// Serializes the entry as one line of the leaderboard file.
+    fn serialize(&self) -> String {
+        format!(
+            "{}|{}|{}|{}|{}",
+            self.challenge,
+            self.score,
+            if self.won { "win" } else { "loss" },
+            self.hits,
+            self.shots_fired
+        )
+    }
+
+    /// Parses one line of the leaderboard file.
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.trim().split('|');
+        let entry = Self {
+            challenge: fields.next()?.parse().ok()?,
+            score: fields.next()?.parse().ok()?,
+            won: fields.next()? == "win",
+            hits: fields.next()?.parse().ok()?,
+            shots_fired: fields.next()?.parse().ok()?,
+        };
+        Some(entry)
+    }
+}
+
+/// The results of the daily challenges of the last few days.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct DailyLeaderboard {
+    entries: Vec<DailyEntry>, // Oldest day first, and best score first within a day.
+}
+
+impl DailyLeaderboard {
+    /// Returns the default location of the daily leaderboard file.
+    pub fn default_path() -> Option<PathBuf> {
+        paths::data_file(DAILY_FILE_NAME)
+    }
+
+    /// Returns the runs of the given day, best score first.
+    pub fn entries_for(&self, challenge: DailyChallenge) -> Vec<&DailyEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.challenge == challenge)
+            .collect()
+    }
+
+    /// Adds a run, dropping the days that are too old to keep.
+    ///
+    /// # Returns
+    /// The run's 1-based rank among the runs of its day. Ties keep the earlier run ahead.
+    pub fn add(&mut self, entry: DailyEntry) -> usize {
+        let oldest_kept = entry.challenge.days_since_epoch() - DAILY_KEEP_DAYS + 1;
+        self.entries
+            .retain(|e| e.challenge.days_since_epoch() >= oldest_kept);
+        let index = self
+            .entries
+            .iter()
+            .position(|e| {
+                e.challenge > entry.challenge
+                    || (e.challenge == entry.challenge && entry.score > e.score)
+            })
+            .unwrap_or(self.entries.len());
+        self.entries.insert(index, entry);
+        self.entries[..=index]
+            .iter()
+            .filter(|e| e.challenge == entry.challenge)
+            .count()
+    }
+
+    /// Loads the leaderboard from the given file. A missing file yields an
+    /// empty leaderboard and malformed lines are skipped.
+    pub fn load_from(path: &Path) -> DailyLeaderboard {
+        let mut leaderboard = DailyLeaderboard::default();
+        if let Ok(contents) = fs::read_to_string(path) {
+            for entry in contents.lines().filter_map(DailyEntry::parse) {
+                leaderboard.add(entry);
+            }
+        }
+        leaderboard
+    }
+
+    /// Saves the leaderboard to the given file, creating its directory if needed.
+    pub fn save_to(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let contents: String = self.entries.iter().map(|e| e.serialize() + "\n").collect();
+        let mut temporary = path.as_os_str().to_owned();
+        temporary.push(".tmp");
+        fs::write(&temporary, contents)?;
+        fs::rename(&temporary, path)
+    }
+
+    /// Enters a finished run into the leaderboard file at the given path, while
+    /// holding a lock, so runs finishing at the same time keep their entries too.
+    ///
+    /// # Arguments
+    /// * `path` - The leaderboard file.
+    /// * `entry` - The finished run.
+    ///
+    /// # Returns
+    /// The run's 1-based rank among the runs of its day, and the number of runs that day.
+    pub fn record_in(path: &Path, entry: DailyEntry) -> io::Result<(usize, usize)> {
+        let _guard = RECORD_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut leaderboard = Self::load_from(path);
+        let rank = leaderboard.add(entry);
+        leaderboard.save_to(path)?;
+        Ok((rank, leaderboard.entries_for(entry.challenge).len()))
+    }
+
+    /// Formats the runs of the given day as plain text lines, for printing.
+    pub fn table_lines(&self, challenge: DailyChallenge) -> Vec<String> {
+        let entries = self.entries_for(challenge);
+        if entries.is_empty() {
+            return vec![format!(
+                "Nobody has played the daily challenge of {} yet.",
+                challenge
+            )];
+        }
+        let mut lines = vec![
+            format!("Daily challenge {} (wave {})", challenge, challenge.wave()),
+            format!(
+                "{:>4}  {:>7}  {:<6}  {:>8}",
+                "Rank", "Score", "Result", "Accuracy"
+            ),
+        ];
+        for (rank, entry) in entries.iter().enumerate() {
+            lines.push(format!(
+                "{:>4}  {:>7}  {:<6}  {:>7}%",
+                rank + 1,
+                entry.score,
+                if entry.won { "Win" } else { "Loss" },
+                entry.accuracy_percent()
+            ));
+        }
+        lines
+    }
+}
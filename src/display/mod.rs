@@ -4,16 +4,28 @@
 //! and graphical elements of the ASCIIliens game to the terminal.
 //! It abstracts away the low-level `crossterm` commands for display.
 
+/// Declares the `terminal` submodule, which restores the terminal on every exit path.
+pub mod terminal;
+/// Declares the `theme` submodule, which defines the color theme and glyph set.
+pub mod theme;
+
+use crate::game::difficulty::Difficulty;
+use crate::game::stats::SessionStats;
 use crate::game::GameState;
+use crate::profile::achievements::Achievement;
+use crate::profile::Profile;
+use crate::scores::HighScores;
 use crate::util::constants::{
-    FINAL_SCORE_LABEL, GAME_HEIGHT, GAME_WIDTH, INSTRUCTIONS_TEXT, INTRO_TITLE_ART, LOSE_ART,
-    PLAY_AGAIN_PROMPT, READY_PROMPT, SCORING_TEXT, TAUNT_PHRASES, WIN_ART,
+    ALIEN_POSES, FINAL_SCORE_LABEL, GAME_HEIGHT, GAME_WIDTH, HIGH_SCORES_ART, HOT_SEAT_ART,
+    INSTRUCTIONS_TEXT, INTRO_TITLE_ART, LOSE_ART, NET_ART, NET_END_PROMPT, OPTIONS_ART,
+    OPTIONS_VISIBLE_SETTINGS, PAUSE_ART, PLAY_AGAIN_PROMPT, PROFILE_ART, READY_PROMPT,
+    RETURN_PROMPT, TAUNT_PHRASES, TITLE_BLINK_FRAMES, TITLE_PARADE_SPACING, TITLE_STARS_PER_ROW,
+    TURN_PROMPT, WIN_ART,
 };
 use crossterm::{
     cursor::MoveTo,
-    event::{Event, KeyCode},
     execute, queue,
-    style::Print,
+    style::{Attribute, Print, SetAttribute},
     terminal::{Clear, ClearType},
 };
 use std::io::{self, Write};
@@ -43,6 +55,10 @@ pub fn show_screen<W: Write>(
     // Clear the entire terminal screen and move the cursor to the top-left corner.
     execute!(stdout, Clear(ClearType::All), MoveTo(0, 0))?;
 
+    // Each line is positioned explicitly, as a bare newline does not return the
+    // cursor to the first column while the terminal is in raw mode.
+    let mut current_y = 0;
+
     // Print each line of the top ASCII art, centering it horizontally.
     for line in top_art {
         // Calculate padding to center the line.
@@ -52,10 +68,11 @@ pub fn show_screen<W: Write>(
             // If the line is too long, just print it as is (will overflow if not handled by terminal).
             line.to_string()
         };
-        queue!(stdout, Print(format!("{}\n", padded_line)))?;
+        queue!(stdout, MoveTo(0, current_y), Print(padded_line))?;
+        current_y += 1;
     }
     // Add an extra line break for visual spacing after the art.
-    queue!(stdout, Print("\n"))?;
+    current_y += 1;
 
     // Print each line of the body text, centering it horizontally.
     for line in body_text {
@@ -66,10 +83,9 @@ pub fn show_screen<W: Write>(
             // If the line is too long, print as is.
             line.to_string()
         };
-        queue!(stdout, Print(format!("{}\n", padded_line)))?;
+        queue!(stdout, MoveTo(0, current_y), Print(padded_line))?;
+        current_y += 1;
     }
-    // Add an extra line break for visual spacing after the body text.
-    queue!(stdout, Print("\n"))?;
 
     // Move the cursor to the calculated position for the prompt and print it.
     // The prompt is centered horizontally on the `prompt_line_y`.
@@ -83,147 +99,577 @@ pub fn show_screen<W: Write>(
     Ok(())
 }
 
-/// Displays the game's introductory screen, including the title art, instructions,
-/// scoring information, and a "Ready?" prompt. It also handles
-/// cycling through taunt phrases if the player chooses not to start immediately.
+/// Draws the game's introductory screen, including the title art, instructions,
+/// scoring information, the selected difficulty and a "Ready?" prompt.
 ///
-/// This function loops until the player input 'Y' or 'y' to start the game.
-/// If 'N' or 'n' is pressed, a taunt phrase is displayed and cycled through.
-/// Other keys are ignored.
+/// The scoring text is generated from the difficulty's `ScoringRules`. Once the
+/// player has hesitated, the current taunt phrase is shown between two borders.
+/// When animated, aliens march across below the title art, stars drift along
+/// the empty rows and the prompt blinks. Input is handled by the title scene;
+/// this function only draws.
 ///
 /// # Arguments
 /// * `stdout` - A mutable reference to a `Write` implementor, typically `io::stdout()`.
+/// * `difficulty` - The selected difficulty preset.
+/// * `taunt` - The index into `TAUNT_PHRASES` of the taunt to show, or `None` before the first 'n'.
+/// * `frame` - The animation frame to draw, or `None` for the static screen.
 ///
 /// # Returns
-/// An `io::Result<()>` indicating success or failure of the display and input operations.
-pub fn show_intro_screen<W: Write>(stdout: &mut W) -> io::Result<()> {
-    let mut taunt_index = 0;
-    // Flag to control if a taunt should be displayed. Initially false,
-    // becomes true only after the first 'n' input.
-    let mut show_taunt = false;
-
+/// An `io::Result<()>` indicating success or failure of the display operations.
+pub fn draw_intro_screen<W: Write>(
+    stdout: &mut W,
+    difficulty: Difficulty,
+    taunt: Option<usize>,
+    frame: Option<u64>,
+) -> io::Result<()> {
     // Determine the target width for the taunt borders. Use GAME_WIDTH for consistent width.
     let taunt_border_len = GAME_WIDTH as usize;
     let taunt_separator_top = ">>>>>".repeat((taunt_border_len / 5) + 1); // Repeat to fill width
     let taunt_separator_bottom = "<<<<<".repeat((taunt_border_len / 5) + 1); // Repeat to fill width
 
-    'intro_loop: loop {
-        // Display the main intro screen elements (art, basic instructions).
-        // The prompt itself will be handled at the very end of this loop.
-        show_screen(
-            stdout,
-            &INTRO_TITLE_ART,
-            &INSTRUCTIONS_TEXT,
-            GAME_HEIGHT - 1,
-            "",
-        )?; // Pass empty string for prompt initially.
-
-        // Calculate the starting Y-position for the first dynamic text block (scoring).
-        // This accounts for the art lines, and the blank lines added by `show_screen` *before* the prompt.
-        let mut current_y = INTRO_TITLE_ART.len() as u16 + 1 /* blank after art */ +
-                            INSTRUCTIONS_TEXT.len() as u16 + 1; /* blank after instructions */
-
-        // Print the scoring information.
-        for line in SCORING_TEXT.iter() {
-            let padded_line = format!("{: ^width$}", line, width = GAME_WIDTH as usize);
-            queue!(stdout, MoveTo(0, current_y), Print(padded_line))?;
-            current_y += 1;
+    // Display the main intro screen elements (art, basic instructions).
+    // The prompt itself will be handled at the very end.
+    show_screen(
+        stdout,
+        &INTRO_TITLE_ART,
+        &INSTRUCTIONS_TEXT,
+        GAME_HEIGHT - 1,
+        "",
+    )?; // Pass empty string for prompt initially.
+
+    // Calculate the starting Y-position for the first dynamic text block (scoring).
+    // This accounts for the art lines, and the blank lines added by `show_screen` *before* the prompt.
+    let parade_y = INTRO_TITLE_ART.len() as u16; // The blank line after the art.
+    let mut current_y = INTRO_TITLE_ART.len() as u16 + 1 /* blank after art */ +
+                        INSTRUCTIONS_TEXT.len() as u16 + 1; /* blank after instructions */
+    let mut blank_rows = vec![current_y - 1]; // Rows left empty, where stars may drift.
+
+    // Print the scoring information, generated from the active rules.
+    for line in difficulty.scoring_rules().describe() {
+        let padded_line = format!("{: ^width$}", line, width = GAME_WIDTH as usize);
+        queue!(stdout, MoveTo(0, current_y), Print(padded_line))?;
+        current_y += 1;
+    }
+    blank_rows.push(current_y);
+    current_y += 1; // Add a blank line after scoring.
+
+    // Print the selected difficulty preset.
+    let difficulty_line = format!(
+        "Difficulty [D]: {} - {}",
+        difficulty.name().to_uppercase(),
+        difficulty.summary()
+    );
+    let padded_line = format!("{: ^width$}", difficulty_line, width = GAME_WIDTH as usize);
+    queue!(stdout, MoveTo(0, current_y), Print(padded_line))?;
+    blank_rows.push(current_y + 1);
+    current_y += 2; // Add a blank line after the difficulty.
+
+    // Handle the display of taunt phrases once the player has hesitated.
+    if let Some(taunt_index) = taunt {
+        let current_taunt = TAUNT_PHRASES[taunt_index % TAUNT_PHRASES.len()];
+
+        // Print top separator.
+        let padded_separator_top = format!(
+            "{: ^width$}",
+            &taunt_separator_top[..taunt_border_len],
+            width = GAME_WIDTH as usize
+        );
+        queue!(stdout, MoveTo(0, current_y), Print(padded_separator_top))?;
+        current_y += 1;
+
+        // Print current taunt phrase.
+        let padded_taunt = format!("{: ^width$}", current_taunt, width = GAME_WIDTH as usize);
+        queue!(stdout, MoveTo(0, current_y), Print(padded_taunt))?;
+        current_y += 1;
+
+        // Print bottom separator.
+        let padded_separator_bottom = format!(
+            "{: ^width$}",
+            &taunt_separator_bottom[..taunt_border_len],
+            width = GAME_WIDTH as usize
+        );
+        queue!(stdout, MoveTo(0, current_y), Print(padded_separator_bottom))?;
+        current_y += 1;
+    } else {
+        // If taunts are not yet active, leave the 3 lines where taunt & separators would go empty.
+        blank_rows.extend(current_y..current_y + 3);
+        current_y += 3;
+    }
+    blank_rows.push(current_y);
+    current_y += 1; // Add a blank line before the READY_PROMPT.
+
+    if let Some(frame) = frame {
+        draw_title_parade(stdout, parade_y, frame)?;
+        for &row in &blank_rows {
+            draw_title_stars(stdout, row, frame)?;
         }
-        current_y += 1; // Add a blank line after scoring.
-
-        // Handle the display of taunt phrases if `show_taunt` is active.
-        if show_taunt {
-            let current_taunt = TAUNT_PHRASES[taunt_index];
-
-            // Print top separator.
-            let padded_separator_top = format!(
-                "{: ^width$}",
-                &taunt_separator_top[..taunt_border_len],
-                width = GAME_WIDTH as usize
-            );
-            queue!(stdout, MoveTo(0, current_y), Print(padded_separator_top))?;
-            current_y += 1;
-
-            // Print current taunt phrase.
-            let padded_taunt = format!("{: ^width$}", current_taunt, width = GAME_WIDTH as usize);
-            queue!(stdout, MoveTo(0, current_y), Print(padded_taunt))?;
-            current_y += 1;
-
-            // Print bottom separator.
-            let padded_separator_bottom = format!(
-                "{: ^width$}",
-                &taunt_separator_bottom[..taunt_border_len],
-                width = GAME_WIDTH as usize
-            );
-            queue!(stdout, MoveTo(0, current_y), Print(padded_separator_bottom))?;
-            current_y += 1;
-        } else {
-            // If taunts are not yet active, ensure the taunt area is clear.
-            // Clear the 3 lines where taunt & separators would go.
-            for i in 0..3 {
-                queue!(
-                    stdout,
-                    MoveTo(0, current_y + i),
-                    Print(format!("{: <width$}", "", width = GAME_WIDTH as usize))
-                )?;
-            }
-            current_y += 3; // Advance y for the cleared space.
+        if (frame / TITLE_BLINK_FRAMES) % 2 == 1 {
+            return Ok(()); // The prompt is in the hidden half of its blink.
         }
-        current_y += 1; // Add a blank line before the READY_PROMPT.
-
-        // Now, finally print the READY_PROMPT at the calculated current_y.
-        queue!(
-            stdout,
-            MoveTo((GAME_WIDTH - READY_PROMPT.len() as u16) / 2, current_y),
-            Print(READY_PROMPT)
-        )?;
-
-        // Position the cursor immediately after the "Ready? [Y/n] " prompt.
-        queue!(
-            stdout,
-            MoveTo(
-                (GAME_WIDTH - READY_PROMPT.len() as u16) / 2 + READY_PROMPT.len() as u16,
-                current_y
-            )
-        )?;
-        stdout.flush()?; // Flush to ensure everything is visible before reading input.
-
-        // Wait for player input.
-        // In a more complex application, input reading might be handled by a central event loop.
-        // For this game's structure, direct input reading here is acceptable for simplicity.
-        if let Ok(Event::Key(key_event)) = crossterm::event::read() {
-            match key_event.code {
-                KeyCode::Char(c) if c.eq_ignore_ascii_case(&'y') => break 'intro_loop, // Start game.
-                KeyCode::Char(c) if c.eq_ignore_ascii_case(&'n') => {
-                    show_taunt = true; // Activate taunts after the first 'n'.
-                                       // Cycle to the next taunt phrase.
-                    taunt_index = (taunt_index + 1) % TAUNT_PHRASES.len();
-                }
-                _ => { /* Ignore all other key presses and loop again. */ }
-            }
+    }
+
+    // Now, finally print the READY_PROMPT at the calculated current_y,
+    // leaving the cursor immediately after it.
+    queue!(
+        stdout,
+        MoveTo((GAME_WIDTH - READY_PROMPT.len() as u16) / 2, current_y),
+        Print(READY_PROMPT)
+    )?;
+    Ok(())
+}
+
+/// Draws one frame of the aliens marching across a row of the title screen.
+///
+/// Each alien advances one column per frame, wrapping around the screen, and
+/// alternates between its `ALIEN_POSES` as it steps, showing each pose's top row.
+///
+/// # Arguments
+/// * `stdout` - A mutable reference to a `Write` implementor.
+/// * `y` - The row to draw the parade on.
+/// * `frame` - The animation frame.
+///
+/// # Returns
+/// An `io::Result<()>` indicating success or failure of the display operations.
+fn draw_title_parade<W: Write>(stdout: &mut W, y: u16, frame: u64) -> io::Result<()> {
+    let width = GAME_WIDTH as usize;
+    let mut row = vec![' '; width];
+    for (i, x) in (0..width).step_by(TITLE_PARADE_SPACING).enumerate() {
+        let poses = ALIEN_POSES[i % ALIEN_POSES.len()];
+        let pose = poses[frame as usize % poses.len()];
+        let x = (x + frame as usize) % width;
+        row[x] = pose[0];
+        row[(x + 1) % width] = pose[1];
+    }
+    queue!(
+        stdout,
+        MoveTo(0, y),
+        Print(row.into_iter().collect::<String>())
+    )?;
+    Ok(())
+}
+
+/// Draws the stars drifting along an empty row of the title screen.
+///
+/// Star positions are derived from the row, so every frame is reproducible.
+/// Stars drift left at one of two speeds and occasionally twinkle.
+///
+/// # Arguments
+/// * `stdout` - A mutable reference to a `Write` implementor.
+/// * `y` - The row to draw the stars on.
+/// * `frame` - The animation frame.
+///
+/// # Returns
+/// An `io::Result<()>` indicating success or failure of the display operations.
+fn draw_title_stars<W: Write>(stdout: &mut W, y: u16, frame: u64) -> io::Result<()> {
+    let width = GAME_WIDTH as u64;
+    let mut row = vec![' '; width as usize];
+    for star in 0..TITLE_STARS_PER_ROW as u64 {
+        let start = (u64::from(y) * 7919 + star * 104_729) % width;
+        let speed = 1 + (u64::from(y) + star) % 2; // Frames per column: near stars drift faster.
+        let x = (start + width - (frame / speed) % width) % width;
+        let twinkle = (frame + star + u64::from(y)).is_multiple_of(9);
+        row[x as usize] = if twinkle { '*' } else { '.' };
+    }
+    queue!(
+        stdout,
+        MoveTo(0, y),
+        Print(row.into_iter().collect::<String>())
+    )?;
+    Ok(())
+}
+
+/// Formats the toast text announcing a newly unlocked achievement.
+///
+/// # Arguments
+/// * `achievement` - The `Achievement` that was unlocked.
+///
+/// # Returns
+/// The toast text.
+pub fn achievement_toast(achievement: Achievement) -> String {
+    format!("*** Achievement unlocked: {} ***", achievement.name())
+}
+
+/// Draws a toast message centered on the top row of the playfield.
+///
+/// Like `Game::draw`, this only queues commands; the caller flushes.
+///
+/// # Arguments
+/// * `stdout` - A mutable reference to a `Write` implementor, typically `io::stdout()`.
+/// * `text` - The toast text to display.
+///
+/// # Returns
+/// An `io::Result<()>` indicating success or failure of the drawing operations.
+pub fn draw_toast<W: Write>(stdout: &mut W, text: &str) -> io::Result<()> {
+    let padded_toast = format!("{: ^width$}", text, width = GAME_WIDTH as usize);
+    queue!(stdout, MoveTo(0, 0), Print(padded_toast))?;
+    Ok(())
+}
+
+/// Draws the sandbox's panel over the playfield: its status and key help on
+/// the top two rows, and the cursor in reverse video.
+///
+/// Like `Game::draw`, this only queues commands; the caller flushes.
+///
+/// # Arguments
+/// * `stdout` - A mutable reference to a `Write` implementor, typically `io::stdout()`.
+/// * `status` - The line showing the sandbox's tweaks.
+/// * `help` - The line listing the sandbox's keys.
+/// * `cursor` - The cursor's x and y coordinates.
+///
+/// # Returns
+/// An `io::Result<()>` indicating success or failure of the drawing operations.
+pub fn draw_sandbox_panel<W: Write>(
+    stdout: &mut W,
+    status: &str,
+    help: &str,
+    cursor: (u16, u16),
+) -> io::Result<()> {
+    queue!(
+        stdout,
+        MoveTo(0, 0),
+        Print(status),
+        MoveTo(0, 1),
+        Print(help),
+        MoveTo(cursor.0, cursor.1),
+        SetAttribute(Attribute::Reverse),
+        Print('+'),
+        SetAttribute(Attribute::NoReverse)
+    )?;
+    Ok(())
+}
+
+/// Draws a short label at the right end of the top row of the playfield,
+/// e.g. whose turn it is in a hot-seat match.
+///
+/// Like `Game::draw`, this only queues commands; the caller flushes.
+///
+/// # Arguments
+/// * `stdout` - A mutable reference to a `Write` implementor, typically `io::stdout()`.
+/// * `text` - The label to display.
+///
+/// # Returns
+/// An `io::Result<()>` indicating success or failure of the drawing operations.
+pub fn draw_corner_label<W: Write>(stdout: &mut W, text: &str) -> io::Result<()> {
+    let x = GAME_WIDTH.saturating_sub(text.chars().count() as u16);
+    queue!(stdout, MoveTo(x, 0), Print(text))?;
+    Ok(())
+}
+
+/// Formats the scores of a match as the lines of a table: one row per player,
+/// with the score of each round played so far and the player's total.
+///
+/// # Arguments
+/// * `scores` - Each player's score for every round they have played.
+/// * `rounds` - The number of rounds in the match; unplayed rounds show `-`.
+///
+/// # Returns
+/// A `Vec<String>` of table lines, including the borders.
+pub fn match_table(scores: &[Vec<i32>], rounds: usize) -> Vec<String> {
+    let border = format!(
+        "+{}+{}+{}+",
+        "-".repeat(10),
+        "-".repeat(9).repeat(rounds),
+        "-".repeat(9)
+    );
+    let round_headers: String = (1..=rounds)
+        .map(|r| format!(" {:>7} ", format!("R{}", r)))
+        .collect();
+    let mut lines = vec![
+        border.clone(),
+        format!("| {:<8} |{}| {:>7} |", "Player", round_headers, "Total"),
+        border.clone(),
+    ];
+    for (player, player_scores) in scores.iter().enumerate() {
+        let cells: String = (0..rounds)
+            .map(|r| match player_scores.get(r) {
+                Some(score) => format!(" {:>7} ", score),
+                None => format!(" {:>7} ", "-"),
+            })
+            .collect();
+        let total: i32 = player_scores.iter().sum();
+        lines.push(format!(
+            "| {:<8} |{}| {:>7} |",
+            format!("P{}", player + 1),
+            cells,
+            total
+        ));
+    }
+    lines.push(border);
+    lines
+}
+
+/// Draws the screen shown between the turns of a hot-seat match, announcing
+/// whose turn it is along with the scores so far.
+///
+/// # Arguments
+/// * `stdout` - A mutable reference to a `Write` implementor, typically `io::stdout()`.
+/// * `player` - The index of the player about to play (0 for player 1).
+/// * `round` - The index of the round being played (0 for the first).
+/// * `scores` - Each player's score for every round they have played.
+/// * `rounds` - The number of rounds in the match.
+///
+/// # Returns
+/// An `io::Result<()>` indicating success or failure of the display operations.
+pub fn draw_turn_screen<W: Write>(
+    stdout: &mut W,
+    player: usize,
+    round: usize,
+    scores: &[Vec<i32>],
+    rounds: usize,
+) -> io::Result<()> {
+    let announcement = format!("PLAYER {}, GET READY!", player + 1);
+    let round_line = format!("Round {} of {}", round + 1, rounds);
+    let table = match_table(scores, rounds);
+    let mut body_lines: Vec<&str> = vec![&announcement, &round_line, ""];
+    body_lines.extend(table.iter().map(String::as_str));
+    show_screen(
+        stdout,
+        &HOT_SEAT_ART,
+        &body_lines,
+        GAME_HEIGHT - 1,
+        TURN_PROMPT,
+    )
+}
+
+/// Draws the combined results of a finished match: the winner, every
+/// player's scores, and a "Play Again?" prompt.
+///
+/// # Arguments
+/// * `stdout` - A mutable reference to a `Write` implementor, typically `io::stdout()`.
+/// * `scores` - Each player's score for every round.
+/// * `rounds` - The number of rounds in the match.
+/// * `winner` - The index of the winning player, or `None` for a draw.
+///
+/// # Returns
+/// An `io::Result<()>` indicating success or failure of the display operations.
+pub fn draw_match_end_screen<W: Write>(
+    stdout: &mut W,
+    scores: &[Vec<i32>],
+    rounds: usize,
+    winner: Option<usize>,
+) -> io::Result<()> {
+    let verdict = match winner {
+        Some(player) => format!("PLAYER {} WINS THE MATCH!", player + 1),
+        None => "THE MATCH IS A DRAW!".to_string(),
+    };
+    let table = match_table(scores, rounds);
+    let mut body_lines: Vec<&str> = vec![&verdict, ""];
+    body_lines.extend(table.iter().map(String::as_str));
+    show_screen(
+        stdout,
+        &HOT_SEAT_ART,
+        &body_lines,
+        GAME_HEIGHT - 1,
+        PLAY_AGAIN_PROMPT,
+    )
+}
+
+/// Draws the end screen of a networked game, explaining how it ended.
+///
+/// # Arguments
+/// * `stdout` - A mutable reference to a `Write` implementor, typically `io::stdout()`.
+/// * `lines` - The lines describing the outcome.
+///
+/// # Returns
+/// An `io::Result<()>` indicating success or failure of the display operations.
+pub fn draw_net_end_screen<W: Write>(stdout: &mut W, lines: &[String]) -> io::Result<()> {
+    let body_lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+    show_screen(
+        stdout,
+        &NET_ART,
+        &body_lines,
+        GAME_HEIGHT - 1,
+        NET_END_PROMPT,
+    )
+}
+
+/// Draws the pilot profile screen: lifetime statistics and every achievement,
+/// marked as unlocked or locked.
+///
+/// # Arguments
+/// * `stdout` - A mutable reference to a `Write` implementor, typically `io::stdout()`.
+/// * `profile` - The player's `Profile`.
+///
+/// # Returns
+/// An `io::Result<()>` indicating success or failure of the display operations.
+pub fn draw_profile_screen<W: Write>(stdout: &mut W, profile: &Profile) -> io::Result<()> {
+    let best_accuracy = match profile.best_accuracy {
+        Some(accuracy) => format!("{:.1}%", accuracy),
+        None => "-".to_string(),
+    };
+    let mut body_lines = vec![
+        format!("{:<24}{:>10}", "Games played", profile.games_played),
+        format!("{:<24}{:>10}", "Wins", profile.wins),
+        format!(
+            "{:<24}{:>10}",
+            "ASCIIliens destroyed", profile.aliens_destroyed
+        ),
+        format!("{:<24}{:>10}", "Best accuracy", best_accuracy),
+        String::new(),
+        format!(
+            "Achievements ({}/{}):",
+            profile.achievements.len(),
+            Achievement::ALL.len()
+        ),
+    ];
+    for achievement in Achievement::ALL.iter() {
+        let mark = if profile.has_unlocked(*achievement) {
+            "x"
+        } else {
+            " "
+        };
+        body_lines.push(format!(
+            "[{}] {:<17} {:<53}",
+            mark,
+            achievement.name(),
+            achievement.description()
+        ));
+    }
+    let body_refs: Vec<&str> = body_lines.iter().map(String::as_str).collect();
+
+    show_screen(
+        stdout,
+        &PROFILE_ART,
+        &body_refs,
+        GAME_HEIGHT - 1,
+        RETURN_PROMPT,
+    )
+}
+
+/// Draws the high score table screen.
+///
+/// # Arguments
+/// * `stdout` - A mutable reference to a `Write` implementor, typically `io::stdout()`.
+/// * `high_scores` - The `HighScores` to list.
+///
+/// # Returns
+/// An `io::Result<()>` indicating success or failure of the display operations.
+pub fn draw_high_scores_screen<W: Write>(
+    stdout: &mut W,
+    high_scores: &HighScores,
+) -> io::Result<()> {
+    let lines = high_scores.table_lines();
+    let body_refs: Vec<&str> = lines.iter().map(String::as_str).collect();
+    show_screen(
+        stdout,
+        &HIGH_SCORES_ART,
+        &body_refs,
+        GAME_HEIGHT - 1,
+        RETURN_PROMPT,
+    )
+}
+
+/// Draws the options screen: one line per setting, with the selected line marked.
+///
+/// At most `OPTIONS_VISIBLE_SETTINGS` lines are shown. A longer list scrolls to
+/// keep the selected setting in view, with a note saying which way there is more.
+///
+/// # Arguments
+/// * `stdout` - A mutable reference to a `Write` implementor, typically `io::stdout()`.
+/// * `settings` - The `(label, value)` pairs of the settings, in display order.
+/// * `selected` - The index of the selected setting.
+/// * `help` - The key help shown below the settings.
+///
+/// # Returns
+/// An `io::Result<()>` indicating success or failure of the display operations.
+pub fn draw_options_screen<W: Write>(
+    stdout: &mut W,
+    settings: &[(&str, String)],
+    selected: usize,
+    help: &str,
+) -> io::Result<()> {
+    let first = (selected + 1).saturating_sub(OPTIONS_VISIBLE_SETTINGS);
+    let last = (first + OPTIONS_VISIBLE_SETTINGS).min(settings.len());
+    let mut body_lines: Vec<String> = settings[first..last]
+        .iter()
+        .enumerate()
+        .map(|(offset, (label, value))| {
+            let marker = if first + offset == selected { ">" } else { " " };
+            format!("{} {:<20}< {:^12} >", marker, label, value)
+        })
+        .collect();
+    body_lines.push(
+        match (first > 0, last < settings.len()) {
+            (true, true) => "(more above and below)",
+            (true, false) => "(more above)",
+            (false, true) => "(more below)",
+            (false, false) => "",
         }
+        .to_string(),
+    );
+    body_lines.push(help.to_string());
+    let body_refs: Vec<&str> = body_lines.iter().map(String::as_str).collect();
+    show_screen(stdout, &OPTIONS_ART, &body_refs, GAME_HEIGHT - 1, "")
+}
+
+/// Draws the pause banner over the playfield.
+///
+/// Like `Game::draw`, this only queues commands; the caller flushes.
+///
+/// # Arguments
+/// * `stdout` - A mutable reference to a `Write` implementor, typically `io::stdout()`.
+///
+/// # Returns
+/// An `io::Result<()>` indicating success or failure of the drawing operations.
+pub fn draw_pause_overlay<W: Write>(stdout: &mut W) -> io::Result<()> {
+    let top_y = (GAME_HEIGHT - PAUSE_ART.len() as u16) / 2;
+    for (offset, line) in PAUSE_ART.iter().enumerate() {
+        let x = (GAME_WIDTH.saturating_sub(line.chars().count() as u16)) / 2;
+        queue!(stdout, MoveTo(x, top_y + offset as u16), Print(line))?;
     }
     Ok(())
 }
 
-/// Displays the game end screen, showing whether the player won or lost,
-/// their final score, and a "Play Again?" prompt.
+/// Formats session statistics as the lines of a two-column results table.
 ///
-/// This function waits for player input ('Y'/'y' to play again, 'N'/'n'/'Esc' to quit).
+/// # Arguments
+/// * `stats` - The `SessionStats` gathered during the game.
+///
+/// # Returns
+/// A `Vec<String>` of equally wide lines, including the table borders.
+pub fn results_table(stats: &SessionStats) -> Vec<String> {
+    let closest_approach = match stats.closest_approach {
+        Some(rows) => format!("{} rows", rows),
+        None => "-".to_string(),
+    };
+    let rows = [
+        ("Shots fired", stats.shots_fired.to_string()),
+        ("Hits", stats.hits.to_string()),
+        ("Accuracy", format!("{:.1}%", stats.accuracy_percent())),
+        ("Longest kill streak", stats.longest_streak.to_string()),
+        ("Frames survived", stats.frames_survived.to_string()),
+        ("Moves made", stats.moves_made.to_string()),
+        ("Closest alien approach", closest_approach),
+    ];
+
+    let border = format!("+{}+{}+", "-".repeat(24), "-".repeat(12));
+    let mut lines = vec![border.clone()];
+    for (label, value) in rows.iter() {
+        lines.push(format!("| {:<22} | {:>10} |", label, value));
+    }
+    lines.push(border);
+    lines
+}
+
+/// Draws the game end screen, showing whether the player won or lost,
+/// their final score, a table of session statistics, and a "Play Again?" prompt.
 ///
 /// # Arguments
 /// * `stdout` - A mutable reference to a `Write` implementor, typically `io::stdout()`.
 /// * `game_state` - The final `GameState` (either `Win` or `GameOver`).
 /// * `score` - The player's final score.
+/// * `stats` - The `SessionStats` gathered during the game, shown as a results table.
+/// * `unlocked` - The achievements unlocked during the game, listed below the table.
 ///
 /// # Returns
-/// An `io::Result<bool>`: `Ok(true)` if the player chooses to play again,
-/// `Ok(false)` if they choose to quit.
-pub fn show_game_end_screen<W: Write>(
+/// An `io::Result<()>` indicating success or failure of the display operations.
+pub fn draw_game_end_screen<W: Write>(
     stdout: &mut W,
     game_state: GameState,
     score: i32,
-) -> io::Result<bool> {
+    stats: &SessionStats,
+    unlocked: &[Achievement],
+) -> io::Result<()> {
     // Determine which ASCII art and status message to display based on the `game_state`.
     let (art, status_message) = match game_state {
         GameState::Win => (&WIN_ART, "YOU WON! :) "),
@@ -236,37 +682,24 @@ pub fn show_game_end_screen<W: Write>(
     let score_text = format!("{}: {}", FINAL_SCORE_LABEL, score);
 
     // Prepare the body text lines for the `show_screen` function.
-    let body_lines: Vec<&str> = vec![
+    let table = results_table(stats);
+    let mut body_lines: Vec<&str> = vec![
         status_message,
         &score_text,
         "", // Add an empty line for spacing.
     ];
+    body_lines.extend(table.iter().map(String::as_str));
+    let unlocked_names = unlocked
+        .iter()
+        .map(|a| a.name())
+        .collect::<Vec<_>>()
+        .join(", ");
+    if !unlocked.is_empty() {
+        body_lines.push("Achievements unlocked:");
+        body_lines.push(&unlocked_names);
+    }
 
     // Display the game end screen.
     // The prompt line is fixed at GAME_HEIGHT - 1.
-    show_screen(stdout, art, &body_lines, GAME_HEIGHT - 1, PLAY_AGAIN_PROMPT)?;
-
-    // Position the cursor immediately after the "Play again? [Y/n] " prompt.
-    queue!(
-        stdout,
-        MoveTo(
-            (GAME_WIDTH - PLAY_AGAIN_PROMPT.len() as u16) / 2 + PLAY_AGAIN_PROMPT.len() as u16,
-            GAME_HEIGHT - 1
-        )
-    )?;
-    stdout.flush()?; // Ensure cursor is positioned before reading input.
-
-    // Loop to wait for valid player input ('Y'/'y' to play again, 'N'/'n'/'Esc' to quit).
-    // Similar to `show_intro_screen`, direct input reading is used for simplicity here.
-    let play_again = loop {
-        if let Ok(Event::Key(key_event)) = crossterm::event::read() {
-            match key_event.code {
-                KeyCode::Char(c) if c.eq_ignore_ascii_case(&'y') => break true,
-                KeyCode::Char(c) if c.eq_ignore_ascii_case(&'n') => break false,
-                KeyCode::Esc => break false,
-                _ => { /* Ignore other keys. */ }
-            }
-        }
-    };
-    Ok(play_again)
+    show_screen(stdout, art, &body_lines, GAME_HEIGHT - 1, PLAY_AGAIN_PROMPT)
 }
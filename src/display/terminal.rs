@@ -0,0 +1,129 @@
+// asciiliens/src/display/terminal.rs
+
+//! This module owns the terminal state the game changes while it is played:
+//! raw mode, the alternate screen and the hidden cursor.
+//!
+//! `TerminalSession` puts the terminal into game mode and restores it when
+//! dropped, so an early `?` return cannot leave the terminal unusable. The panic
+//! hook and signal handlers installed by `install_panic_hook` and
+//! `install_signal_handlers` restore it on the paths that skip `Drop` as well.
+
+use crossterm::{
+    cursor,
+    event::{KeyCode, KeyEvent, KeyModifiers},
+    execute,
+    style::ResetColor,
+    terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use std::io::{self, Write};
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether a `TerminalSession` currently has the terminal in game mode.
+///
+/// Restoration only happens when this flips from `true` to `false`, so the
+/// panic hook, a signal and `Drop` can all race to restore without doubling up.
+static SESSION_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// An RAII guard that keeps the terminal in game mode while it is alive.
+///
+/// Entering switches to the alternate screen, hides the cursor, enables raw
+/// mode and clears the screen. Dropping the guard undoes all of it.
+#[derive(Debug)]
+pub struct TerminalSession {
+    _private: (), // Only `enter` can create a session.
+}
+
+impl TerminalSession {
+    /// Puts the terminal into game mode.
+    ///
+    /// If any step fails, the steps already taken are undone before the error is returned.
+    ///
+    /// # Arguments
+    /// * `stdout` - A mutable reference to a `Write` implementor, typically `io::stdout()`.
+    ///
+    /// # Returns
+    /// An `io::Result` with the guard that restores the terminal when dropped.
+    pub fn enter<W: Write>(stdout: &mut W) -> io::Result<TerminalSession> {
+        SESSION_ACTIVE.store(true, Ordering::SeqCst);
+        let session = TerminalSession { _private: () }; // Restores on any early return below.
+        execute!(stdout, EnterAlternateScreen, cursor::Hide)?;
+        // Raw mode delivers key presses unbuffered, which the game loop relies on.
+        terminal::enable_raw_mode()?;
+        execute!(stdout, terminal::Clear(terminal::ClearType::All))?;
+        Ok(session)
+    }
+}
+
+impl Drop for TerminalSession {
+    fn drop(&mut self) {
+        restore();
+    }
+}
+
+/// Restores the terminal if a `TerminalSession` left it in game mode.
+///
+/// Shows the cursor, disables raw mode, leaves the alternate screen and resets
+/// colors. Errors are ignored: this runs while panicking or exiting, where
+/// there is nothing better to do than try every step.
+pub fn restore() {
+    if !SESSION_ACTIVE.swap(false, Ordering::SeqCst) {
+        return; // Already restored, or never entered.
+    }
+    let mut stdout = io::stdout();
+    let _ = execute!(stdout, cursor::Show);
+    let _ = terminal::disable_raw_mode();
+    let _ = execute!(stdout, LeaveAlternateScreen, ResetColor);
+}
+
+/// Installs a panic hook that restores the terminal before the panic message is printed.
+///
+/// The previous hook still runs afterwards, so the message lands on the normal
+/// screen instead of vanishing with the alternate one.
+pub fn install_panic_hook() {
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        restore();
+        previous_hook(info);
+    }));
+}
+
+/// Installs handlers for SIGINT and SIGTERM that restore the terminal and exit.
+///
+/// The process exits with the conventional `128 + signal` status. In raw mode
+/// Ctrl-C arrives as a key press instead of SIGINT; see `is_interrupt`.
+///
+/// # Returns
+/// An `io::Result<()>` indicating whether the handlers could be registered.
+#[cfg(unix)]
+pub fn install_signal_handlers() -> io::Result<()> {
+    use signal_hook::consts::{SIGINT, SIGTERM};
+    use signal_hook::iterator::Signals;
+
+    let mut signals = Signals::new([SIGINT, SIGTERM])?;
+    std::thread::spawn(move || {
+        if let Some(signal) = signals.forever().next() {
+            restore();
+            std::process::exit(128 + signal);
+        }
+    });
+    Ok(())
+}
+
+/// Installs handlers for SIGINT and SIGTERM; a no-op on platforms without Unix signals.
+#[cfg(not(unix))]
+pub fn install_signal_handlers() -> io::Result<()> {
+    Ok(())
+}
+
+/// Returns whether a key press is Ctrl-C, which raw mode delivers as a key instead of SIGINT.
+///
+/// # Arguments
+/// * `key_event` - The key press to check.
+///
+/// # Returns
+/// `true` if the player pressed Ctrl-C.
+pub fn is_interrupt(key_event: &KeyEvent) -> bool {
+    key_event.modifiers.contains(KeyModifiers::CONTROL)
+        && matches!(key_event.code, KeyCode::Char('c') | KeyCode::Char('C'))
+}
@@ -0,0 +1,208 @@
+// asciiliens/src/display/theme.rs
+
+//! This module defines the visual settings applied to everything drawn: the
+//! color theme and the glyph set.
+//!
+//! The glyph set is applied by `GlyphWriter`, which sits between the screens
+//! and the terminal, so individual screens never need to know about it.
+
+use crossterm::style::Color;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+/// The color everything is drawn in.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Theme {
+    /// The terminal's own colors.
+    #[default]
+    Classic,
+    /// Green phosphor, like the CRT the original game ran on.
+    Green,
+    /// Amber phosphor.
+    Amber,
+}
+
+impl Theme {
+    /// Every theme, in the order the options screen cycles through them.
+    pub const ALL: [Theme; 3] = [Theme::Classic, Theme::Green, Theme::Amber];
+
+    /// Returns the lowercase name used in the config file.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Theme::Classic => "classic",
+            Theme::Green => "green",
+            Theme::Amber => "amber",
+        }
+    }
+
+    /// Returns the theme after this one, wrapping around to the first.
+    pub fn next(&self) -> Theme {
+        let index = Theme::ALL.iter().position(|t| t == self).unwrap_or(0);
+        Theme::ALL[(index + 1) % Theme::ALL.len()]
+    }
+
+    /// Returns the foreground color of the theme, or `None` to keep the terminal's own.
+    pub fn color(&self) -> Option<Color> {
+        match self {
+            Theme::Classic => None,
+            Theme::Green => Some(Color::Green),
+            Theme::Amber => Some(Color::DarkYellow),
+        }
+    }
+}
+
+impl fmt::Display for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Theme::ALL
+            .into_iter()
+            .find(|t| t.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("unknown theme '{}'", s))
+    }
+}
+
+/// The characters the game may draw with.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum GlyphSet {
+    /// Box-drawing borders and the extended alien designs.
+    #[default]
+    Unicode,
+    /// Plain 7-bit ASCII, for terminals and fonts without the extended characters.
+    Ascii,
+}
+
+impl GlyphSet {
+    /// Every glyph set, in the order the options screen cycles through them.
+    pub const ALL: [GlyphSet; 2] = [GlyphSet::Unicode, GlyphSet::Ascii];
+
+    /// Returns the lowercase name used in the config file.
+    pub fn name(&self) -> &'static str {
+        match self {
+            GlyphSet::Unicode => "unicode",
+            GlyphSet::Ascii => "ascii",
+        }
+    }
+
+    /// Returns the glyph set after this one, wrapping around to the first.
+    pub fn next(&self) -> GlyphSet {
+        let index = GlyphSet::ALL.iter().position(|g| g == self).unwrap_or(0);
+        GlyphSet::ALL[(index + 1) % GlyphSet::ALL.len()]
+    }
+
+    /// Converts a character to one this glyph set can draw.
+    ///
+    /// # Arguments
+    /// * `c` - The character to draw.
+    ///
+    /// # Returns
+    /// The character itself, or its closest ASCII stand-in for `GlyphSet::Ascii`.
+    pub fn convert(&self, c: char) -> char {
+        if *self == GlyphSet::Unicode || c.is_ascii() {
+            return c;
+        }
+        match c {
+            '║' | '│' => '|',
+            '═' => '=',
+            '─' | '–' => '-',
+            '·' => '.',
+            '╔' | '╗' | '╚' | '╝' | '╠' | '╣' | '╦' | '╩' | '╬' => '+',
+            _ => '#', // Blocks, shades and other symbols.
+        }
+    }
+}
+
+impl fmt::Display for GlyphSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for GlyphSet {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        GlyphSet::ALL
+            .into_iter()
+            .find(|g| g.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("unknown glyph set '{}'", s))
+    }
+}
+
+/// A `Write` adapter that converts every character written to the given glyph set.
+///
+/// Text is passed through untouched for `GlyphSet::Unicode`. Otherwise bytes
+/// are decoded as UTF-8, converted character by character, and an incomplete
+/// character at the end of a write is held back until the rest arrives.
+/// Escape sequences are plain ASCII and pass through unchanged.
+pub struct GlyphWriter<W: Write> {
+    inner: W,
+    glyphs: GlyphSet,
+    pending: Vec<u8>, // The start of a character split across writes.
+}
+
+impl<W: Write> GlyphWriter<W> {
+    /// Wraps a writer.
+    ///
+    /// # Arguments
+    /// * `inner` - The writer to send converted text to.
+    /// * `glyphs` - The glyph set to convert to.
+    pub fn new(inner: W, glyphs: GlyphSet) -> Self {
+        Self {
+            inner,
+            glyphs,
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl<W: Write> Write for GlyphWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.glyphs == GlyphSet::Unicode {
+            return self.inner.write(buf);
+        }
+        self.pending.extend_from_slice(buf);
+        let mut converted = String::with_capacity(self.pending.len());
+        let mut rest: &[u8] = &self.pending;
+        loop {
+            match std::str::from_utf8(rest) {
+                Ok(text) => {
+                    converted.extend(text.chars().map(|c| self.glyphs.convert(c)));
+                    rest = &[];
+                    break;
+                }
+                Err(e) => {
+                    let (valid, after) = rest.split_at(e.valid_up_to());
+                    let text = std::str::from_utf8(valid).unwrap_or_default();
+                    converted.extend(text.chars().map(|c| self.glyphs.convert(c)));
+                    match e.error_len() {
+                        // An invalid byte is replaced; decoding resumes after it.
+                        Some(len) => {
+                            converted.push('?');
+                            rest = &after[len..];
+                        }
+                        // An incomplete character: wait for the rest of it.
+                        None => {
+                            rest = after;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        self.pending = rest.to_vec();
+        self.inner.write_all(converted.as_bytes())?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -2,36 +2,124 @@
 
 //! This module defines the `Alien` struct and its associated logic,
 //! representing an enemy alien within the game. It handles alien movement,
-//! collision detection with blasts, and explosion animations.
+//! collision detection with blasts, and explosion animations, along with the
+//! `AlienSprite` describing an alien's size and animation poses.
 
 use super::blast::Blast;
 use crate::util::constants::{
-    ALIEN_DESIGNS, ALIEN_HEIGHT, ALIEN_WIDTH, BLAST_CHAR, EXPLOSION_STAGE_1, EXPLOSION_STAGE_2,
-    EXPLOSION_STAGE_3, EXPLOSION_STAGE_4, GAME_WIDTH,
+    ALIEN_DESIGNS, ALIEN_HEIGHT, ALIEN_POSES, ALIEN_WIDTH, BLAST_CHAR, EXPLOSION_STAGE_1,
+    EXPLOSION_STAGE_2, EXPLOSION_STAGE_3, EXPLOSION_STAGE_4, GAME_WIDTH,
 };
 use rand::Rng; // Import `Blast` from the parent `game` module.
 
+/// The look of an alien: its size in characters and the poses it alternates
+/// between as it steps, like the classic arcade invaders.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct AlienSprite {
+    width: u16,
+    height: u16,
+    /// Each pose lists `width * height` characters, row by row.
+    poses: &'static [&'static [char]],
+}
+
+impl AlienSprite {
+    /// Creates a sprite of any size.
+    ///
+    /// # Arguments
+    /// * `width` - The sprite's width in characters (at least 1).
+    /// * `height` - The sprite's height in characters (at least 1).
+    /// * `poses` - The animation poses, each `width * height` characters listed row by row.
+    ///
+    /// # Returns
+    /// A new `AlienSprite`.
+    ///
+    /// # Panics
+    /// If there are no poses, or a pose does not have `width * height` characters.
+    pub fn new(width: u16, height: u16, poses: &'static [&'static [char]]) -> Self {
+        let cells = usize::from(width) * usize::from(height);
+        assert!(
+            cells > 0 && !poses.is_empty() && poses.iter().all(|pose| pose.len() == cells),
+            "every pose of a {}x{} sprite needs {} characters",
+            width,
+            height,
+            cells
+        );
+        Self {
+            width,
+            height,
+            poses,
+        }
+    }
+
+    /// Returns the standard sprite of an alien kind, using its poses in `ALIEN_POSES`.
+    ///
+    /// # Arguments
+    /// * `kind` - The alien's kind (its index in `ALIEN_DESIGNS`).
+    pub fn for_kind(kind: usize) -> Self {
+        Self::new(
+            ALIEN_WIDTH,
+            ALIEN_HEIGHT,
+            ALIEN_POSES[kind % ALIEN_POSES.len()],
+        )
+    }
+
+    /// Returns the sprite's width in characters.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Returns the sprite's height in characters.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Returns the number of animation poses.
+    pub fn pose_count(&self) -> usize {
+        self.poses.len()
+    }
+
+    /// Returns the characters of a pose, row by row. Pose indices wrap around.
+    pub fn pose(&self, index: usize) -> &'static [char] {
+        self.poses[index % self.poses.len()]
+    }
+}
+
 /// Represents an alien enemy in the game.
 ///
-/// An `Alien` has a position (`x`, `y`), a status (`alive`), a visual
-/// `design`, and an `explosion_frame` to manage its destruction animation.
+/// An `Alien` has a position (`x`, `y`), a status (`alive`), a `sprite`
+/// with the pose it currently shows, and an `explosion_frame` to manage its
+/// destruction animation.
 #[derive(Debug, Clone, Copy)]
 pub struct Alien {
     /// The x-coordinate of the alien's top-left corner.
     x: u16,
     /// The y-coordinate of the alien's top-left corner.
     y: u16,
+    /// The x-coordinate the alien occupied at the start of the current frame.
+    prev_x: u16,
+    /// The y-coordinate the alien occupied at the start of the current frame.
+    prev_y: u16,
     /// A boolean indicating whether the alien is currently alive.
     /// An alien is `alive` until its explosion animation completes.
     alive: bool,
-    /// The 2x2 ASCII character design for this specific alien.
-    /// This is chosen randomly upon creation from `ALIEN_DESIGNS`.
-    design: [char; 4],
+    /// The kind of alien, i.e. the index of its design in `ALIEN_DESIGNS`.
+    /// Scoring rules may reward each kind differently.
+    kind: usize,
+    /// The alien's size and animation poses. Standard aliens use their kind's
+    /// sprite from `ALIEN_POSES`, chosen randomly upon creation.
+    sprite: AlienSprite,
+    /// The index of the pose currently shown, advanced every time the alien steps.
+    pose: usize,
     /// The current frame of the explosion animation.
     /// - `0`: The alien is not exploding.
     /// - `1` to `4`: The alien is in an explosion animation stage (progressing through `EXPLOSION_STAGE_X`).
     /// - `5`: The explosion animation is complete, and the alien is ready for removal.
     explosion_frame: u8,
+    /// The player's hit streak at the moment this alien was hit (0 if never hit).
+    /// Used to apply streak bonuses once the explosion completes.
+    hit_streak: u32,
+    /// The index of the ship whose blast hit this alien, credited with its reward.
+    hit_by: usize,
 }
 
 impl Alien {
@@ -49,13 +137,47 @@ impl Alien {
     /// # Returns
     /// A new `Alien` instance.
     pub fn new(x: u16, y: u16, rng: &mut impl Rng) -> Self {
+        // Select a random alien design from the predefined array.
+        let kind = rng.gen_range(0..ALIEN_DESIGNS.len());
         Self {
             x,
             y,
+            prev_x: x,
+            prev_y: y,
             alive: true,
-            // Select a random alien design from the predefined array.
-            design: ALIEN_DESIGNS[rng.gen_range(0..ALIEN_DESIGNS.len())],
+            kind,
+            sprite: AlienSprite::for_kind(kind),
+            pose: 0,
             explosion_frame: 0, // All aliens start not exploding.
+            hit_streak: 0,
+            hit_by: 0,
+        }
+    }
+
+    /// Creates a new `Alien` of the given kind that looks like a custom sprite,
+    /// which may be larger than the standard `ALIEN_WIDTH` x `ALIEN_HEIGHT`.
+    ///
+    /// # Arguments
+    /// * `x` - The initial x-coordinate of the alien's top-left corner.
+    /// * `y` - The initial y-coordinate of the alien's top-left corner.
+    /// * `kind` - The alien's kind, which decides its reward.
+    /// * `sprite` - The alien's size and animation poses.
+    ///
+    /// # Returns
+    /// A new `Alien` instance.
+    pub fn with_sprite(x: u16, y: u16, kind: usize, sprite: AlienSprite) -> Self {
+        Self {
+            x,
+            y,
+            prev_x: x,
+            prev_y: y,
+            alive: true,
+            kind,
+            sprite,
+            pose: 0,
+            explosion_frame: 0,
+            hit_streak: 0,
+            hit_by: 0,
         }
     }
 
@@ -68,7 +190,7 @@ impl Alien {
     /// * `x` - The initial x-coordinate.
     /// * `y` - The initial y-coordinate.
     /// * `alive` - Whether the alien is initially alive.
-    /// * `design` - The 2x2 character design.
+    /// * `design` - The 2x2 character design, one of `ALIEN_DESIGNS`.
     /// * `explosion_frame` - The initial explosion frame.
     ///
     /// # Returns
@@ -81,12 +203,12 @@ impl Alien {
         design: [char; 4],
         explosion_frame: u8,
     ) -> Self {
+        // Recover the kind from the design; unknown designs count as the first kind.
+        let kind = ALIEN_DESIGNS.iter().position(|d| *d == design).unwrap_or(0);
         Self {
-            x,
-            y,
             alive,
-            design,
             explosion_frame,
+            ..Self::with_sprite(x, y, kind, AlienSprite::for_kind(kind))
         }
     }
 
@@ -134,38 +256,100 @@ impl Alien {
         self.explosion_frame += 1;
     }
 
-    /// Returns the alien's design.
+    /// Returns the alien's kind (the index of its design in `ALIEN_DESIGNS`).
+    pub fn kind(&self) -> usize {
+        self.kind
+    }
+
+    /// Returns the player's hit streak at the moment this alien was hit.
+    pub fn hit_streak(&self) -> u32 {
+        self.hit_streak
+    }
+
+    /// Records the player's hit streak at the moment this alien was hit.
+    pub fn set_hit_streak(&mut self, streak: u32) {
+        self.hit_streak = streak;
+    }
+
+    /// Returns the index of the ship whose blast hit this alien (0 if never hit).
+    pub fn hit_by(&self) -> usize {
+        self.hit_by
+    }
+
+    /// Records the index of the ship whose blast hit this alien.
+    pub fn set_hit_by(&mut self, ship: usize) {
+        self.hit_by = ship;
+    }
+
+    /// Returns the design of the alien's kind in `ALIEN_DESIGNS`.
     /// Used for testing and display.
     pub fn design(&self) -> [char; 4] {
-        self.design
+        ALIEN_DESIGNS[self.kind % ALIEN_DESIGNS.len()]
+    }
+
+    /// Returns the alien's sprite.
+    pub fn sprite(&self) -> AlienSprite {
+        self.sprite
+    }
+
+    /// Returns the alien's width in characters.
+    pub fn width(&self) -> u16 {
+        self.sprite.width()
+    }
+
+    /// Returns the alien's height in characters.
+    pub fn height(&self) -> u16 {
+        self.sprite.height()
+    }
+
+    /// Returns the index of the pose the alien currently shows.
+    pub fn pose(&self) -> usize {
+        self.pose
     }
 
-    /// Moves the alien one unit to the left.
+    /// Switches to the next animation pose, wrapping around after the last one.
+    fn step_pose(&mut self) {
+        self.pose = (self.pose + 1) % self.sprite.pose_count();
+    }
+
+    /// Records the alien's current position as the start of a new frame.
+    ///
+    /// Called by the game before any entity moves, so that collision detection
+    /// can check the full area the alien swept through during the frame.
+    pub fn begin_frame(&mut self) {
+        self.prev_x = self.x;
+        self.prev_y = self.y;
+    }
+
+    /// Moves the alien one unit to the left, stepping to its next pose.
     ///
     /// The movement is constrained by the left edge of the game screen,
     /// ensuring the alien never moves out of bounds (x-coordinate cannot go below 0).
     pub fn move_left(&mut self) {
         if self.x > 0 {
             self.x -= 1;
+            self.step_pose();
         }
     }
 
-    /// Moves the alien one unit to the right.
+    /// Moves the alien one unit to the right, stepping to its next pose.
     ///
     /// The movement is constrained by the right edge of the game screen,
-    /// ensuring the alien's rightmost part (`x + ALIEN_WIDTH`) does not
+    /// ensuring the alien's rightmost part (`x + width`) does not
     /// exceed `GAME_WIDTH`.
     pub fn move_right(&mut self) {
-        if self.x < GAME_WIDTH - ALIEN_WIDTH {
+        if self.x + self.width() < GAME_WIDTH {
             self.x += 1;
+            self.step_pose();
         }
     }
 
-    /// Moves the alien one unit downwards.
+    /// Moves the alien one unit downwards, stepping to its next pose.
     ///
     /// This method simply increments the alien's `y` coordinate.
     pub fn move_down(&mut self) {
         self.y += 1;
+        self.step_pose();
     }
 
     /// Checks if a `Blast`'s position overlaps with the alien's bounding box.
@@ -184,31 +368,64 @@ impl Alien {
             && self.explosion_frame == 0 // Only collide if the alien is not currently exploding.
             // Check if the blast's x-coordinate is within the alien's horizontal span.
             && blast.x() >= self.x
-            && blast.x() < self.x + ALIEN_WIDTH
+            && blast.x() < self.x + self.width()
             // Check if the blast's y-coordinate is within the alien's vertical span.
             && blast.y() >= self.y
-            && blast.y() < self.y + ALIEN_HEIGHT
+            && blast.y() < self.y + self.height()
     }
 
-    /// Generates the two lines of display strings for the alien,
-    /// accounting for its original design or current explosion animation stage.
+    /// Checks if the path a `Blast` swept through this frame crosses the area
+    /// the alien swept through this frame.
+    ///
+    /// The blast's path is the vertical segment from `blast.prev_y()` up to
+    /// `blast.y()` in its column. The alien's swept area is the bounding box
+    /// covering both its position at the start of the frame and its current one.
+    /// This prevents a blast and an alien from passing through each other when
+    /// their combined movement in one frame exceeds the alien's height.
+    ///
+    /// As with `collides_with_blast`, dead or exploding aliens never collide.
+    ///
+    /// # Arguments
+    /// * `blast` - A reference to the `Blast` to check collision against.
     ///
-    /// If `explosion_frame` is 0, the alien's original `design` is returned.
-    /// Otherwise, `BLAST_CHAR` (`*`) characters are substituted into the design
+    /// # Returns
+    /// `true` if the swept paths overlap and the alien is alive and not exploding.
+    pub fn swept_collides_with_blast(&self, blast: &Blast) -> bool {
+        // The alien's bounding box across the whole frame.
+        let left = self.x.min(self.prev_x);
+        let right = self.x.max(self.prev_x) + self.width() - 1;
+        let top = self.y.min(self.prev_y);
+        let bottom = self.y.max(self.prev_y) + self.height() - 1;
+
+        // Blasts travel upwards, so `y` is the top of the path and `prev_y` its bottom.
+        let path_top = blast.y().min(blast.prev_y());
+        let path_bottom = blast.y().max(blast.prev_y());
+
+        self.alive
+            && self.explosion_frame == 0
+            && blast.x() >= left
+            && blast.x() <= right
+            && path_top <= bottom
+            && path_bottom >= top
+    }
+
+    /// Generates the display strings for the alien, one per row,
+    /// accounting for its current pose or explosion animation stage.
+    ///
+    /// If `explosion_frame` is 0, the alien's current pose is returned.
+    /// Otherwise, `BLAST_CHAR` (`*`) characters are substituted into the pose
     /// based on the current `explosion_frame` and `EXPLOSION_STAGE_X` constants.
+    /// Each stage marks a share of the four quarters of a 2x2 sprite; larger
+    /// sprites explode the same share of their characters, row by row.
     ///
     /// # Returns
-    /// A tuple containing two `String`s: (top row of characters, bottom row of characters).
-    pub fn display_strings(&self) -> (String, String) {
-        if self.explosion_frame == 0 {
-            // If the alien is not exploding, return its original design.
-            (
-                format!("{}{}", self.design[0], self.design[1]),
-                format!("{}{}", self.design[2], self.design[3]),
-            )
+    /// A `Vec<String>` with `height` rows of `width` characters, from the top row down.
+    pub fn display_strings(&self) -> Vec<String> {
+        let pose = self.sprite.pose(self.pose);
+        // The number of characters, counted row by row, replaced by `BLAST_CHAR`.
+        let exploded = if self.explosion_frame == 0 {
+            0
         } else {
-            // If the alien is exploding, determine which parts of its design
-            // should be replaced by `BLAST_CHAR` (`*`).
             let explosion_stages = [
                 EXPLOSION_STAGE_1,
                 EXPLOSION_STAGE_2,
@@ -216,36 +433,30 @@ impl Alien {
                 EXPLOSION_STAGE_4,
             ];
             // Calculate the current stage index (explosion_frame 1 maps to index 0, etc.).
-            let stage_idx = (self.explosion_frame - 1) as usize;
-            let current_stage = explosion_stages[stage_idx];
-
-            // Determine characters for the top row based on the current explosion stage.
-            let top_left = if current_stage[0] == 1 {
-                BLAST_CHAR
-            } else {
-                self.design[0]
-            };
-            let top_right = if current_stage[1] == 1 {
-                BLAST_CHAR
-            } else {
-                self.design[1]
-            };
-            // Determine characters for the bottom row based on the current explosion stage.
-            let bottom_left = if current_stage[2] == 1 {
-                BLAST_CHAR
-            } else {
-                self.design[2]
-            };
-            let bottom_right = if current_stage[3] == 1 {
-                BLAST_CHAR
-            } else {
-                self.design[3]
-            };
-
-            (
-                format!("{}{}", top_left, top_right),
-                format!("{}{}", bottom_left, bottom_right),
-            )
-        }
+            let stage_idx = usize::from(self.explosion_frame - 1).min(explosion_stages.len() - 1);
+            let quarters = explosion_stages[stage_idx]
+                .iter()
+                .filter(|&&cell| cell == 1)
+                .count();
+            (quarters * pose.len()).div_ceil(4)
+        };
+
+        pose.chunks(usize::from(self.width()))
+            .enumerate()
+            .map(|(row, chars)| {
+                chars
+                    .iter()
+                    .enumerate()
+                    .map(|(col, &c)| {
+                        let index = row * usize::from(self.width()) + col;
+                        if index < exploded {
+                            BLAST_CHAR
+                        } else {
+                            c
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
     }
 }
@@ -6,13 +6,20 @@
 /// Represents a blast unleashed by the player's ship.
 ///
 /// A `Blast` has an `x` and `y` coordinate, determining its position
-/// on the game screen.
+/// on the game screen, and remembers where it was before its last move so
+/// that collision detection can check the whole path it swept through.
 #[derive(Debug, Clone, Copy)]
 pub struct Blast {
     /// The x-coordinate (horizontal position) of the blast.
     x: u16,
     /// The y-coordinate (vertical position) of the blast.
     y: u16,
+    /// The y-coordinate the blast occupied before its most recent move.
+    prev_y: u16,
+    /// The number of rows the blast travels upwards on each move.
+    speed: u16,
+    /// The index of the ship that unleashed the blast (0 for the first ship).
+    owner: usize,
 }
 
 impl Blast {
@@ -25,7 +32,42 @@ impl Blast {
     /// # Returns
     /// A new `Blast` instance.
     pub fn new(x: u16, y: u16) -> Self {
-        Self { x, y }
+        Self::with_speed(x, y, 1)
+    }
+
+    /// Creates a new `Blast` instance that travels `speed` rows per move.
+    ///
+    /// # Arguments
+    /// * `x` - The initial x-coordinate of the blast.
+    /// * `y` - The initial y-coordinate of the blast.
+    /// * `speed` - The number of rows the blast moves up each frame.
+    ///
+    /// # Returns
+    /// A new `Blast` instance.
+    pub fn with_speed(x: u16, y: u16, speed: u16) -> Self {
+        Self {
+            x,
+            y,
+            prev_y: y,
+            speed,
+            owner: 0,
+        }
+    }
+
+    /// Attributes the blast to the ship that unleashed it.
+    ///
+    /// # Arguments
+    /// * `owner` - The index of the ship in the game (0 for the first ship).
+    ///
+    /// # Returns
+    /// The same `Blast`, owned by the given ship.
+    pub fn owned_by(self, owner: usize) -> Self {
+        Self { owner, ..self }
+    }
+
+    /// Returns the index of the ship that unleashed the blast.
+    pub fn owner(&self) -> usize {
+        self.owner
     }
 
     /// Returns the blast's current x-coordinate.
@@ -38,23 +80,33 @@ impl Blast {
         self.y
     }
 
+    /// Returns the y-coordinate the blast occupied before its most recent move.
+    ///
+    /// Together with `y()`, this describes the vertical segment the blast swept
+    /// through during the current frame.
+    pub fn prev_y(&self) -> u16 {
+        self.prev_y
+    }
+
     /// Sets the blast's y-coordinate.
     /// This is primarily for internal modifications, e.g., marking for removal.
     pub fn set_y(&mut self, y: u16) {
         self.y = y;
     }
 
-    /// Moves the blast upwards by one row.
+    /// Moves the blast upwards by its speed (one row for a regular blast).
     ///
-    /// This method decrements the blast's `y` coordinate. It returns a boolean
+    /// This method records the current position as `prev_y` and then decrements
+    /// the blast's `y` coordinate, stopping at the top edge. It returns a boolean
     /// indicating whether the blast is still within the visible screen area
     /// (i.e., its `y` coordinate is greater than or equal to 0 after the move).
     ///
     /// # Returns
     /// `true` if the blast is still on screen after moving, `false` otherwise (if it reached y=0 and would move off).
     pub fn move_up(&mut self) -> bool {
+        self.prev_y = self.y;
         if self.y > 0 {
-            self.y -= 1;
+            self.y = self.y.saturating_sub(self.speed);
             true
         } else {
             false // Blast would go off screen if moved further up.
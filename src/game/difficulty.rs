@@ -0,0 +1,123 @@
+// asciiliens/src/game/difficulty.rs
+
+//! This module defines the difficulty presets a game can be played at.
+
+use super::scoring::ScoringRules;
+use crate::util::constants::{ALIEN_DESIGNS, ALIEN_MOVE_DOWN_FREQ};
+use std::fmt;
+use std::str::FromStr;
+
+/// A difficulty preset, bundling the balance values a game is played with:
+/// how often the formation descends, how many aliens close in on the player
+/// each frame, the starting score and the reward per alien.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Difficulty {
+    /// Slow descent and more starting points.
+    Easy,
+    /// The classic game.
+    #[default]
+    Normal,
+    /// Faster descent, two aliens closing in per frame, bigger rewards.
+    Hard,
+    /// Relentless descent, three aliens closing in per frame, the biggest rewards.
+    Nightmare,
+}
+
+impl Difficulty {
+    /// Every difficulty, from easiest to hardest.
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Easy,
+        Difficulty::Normal,
+        Difficulty::Hard,
+        Difficulty::Nightmare,
+    ];
+
+    /// Returns the lowercase name used on the command line and in saved files.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+            Difficulty::Nightmare => "nightmare",
+        }
+    }
+
+    /// Returns the difficulty after this one, wrapping around to `Easy`.
+    /// Used to cycle through the presets on the intro screen.
+    pub fn next(&self) -> Difficulty {
+        let index = Difficulty::ALL.iter().position(|d| d == self).unwrap_or(0);
+        Difficulty::ALL[(index + 1) % Difficulty::ALL.len()]
+    }
+
+    /// Returns the number of frames between each descent of the alien formation.
+    pub fn descent_frequency(&self) -> u64 {
+        match self {
+            Difficulty::Easy => 14,
+            Difficulty::Normal => ALIEN_MOVE_DOWN_FREQ,
+            Difficulty::Hard => 7,
+            Difficulty::Nightmare => 5,
+        }
+    }
+
+    /// Returns the number of aliens that step towards the player each frame.
+    pub fn aliens_moving_per_frame(&self) -> usize {
+        match self {
+            Difficulty::Easy | Difficulty::Normal => 1,
+            Difficulty::Hard => 2,
+            Difficulty::Nightmare => 3,
+        }
+    }
+
+    /// Returns a one-line summary of the preset, for the intro screen.
+    pub fn summary(&self) -> String {
+        let movers = self.aliens_moving_per_frame();
+        format!(
+            "descent every {} turns, {} {} in per turn",
+            self.descent_frequency(),
+            movers,
+            if movers == 1 {
+                "alien closes"
+            } else {
+                "aliens close"
+            }
+        )
+    }
+
+    /// Returns the scoring rules for this difficulty.
+    pub fn scoring_rules(&self) -> ScoringRules {
+        let (initial_score, kill_reward) = match self {
+            Difficulty::Easy => (150, 250),
+            Difficulty::Normal => (100, 250),
+            Difficulty::Hard => (75, 300),
+            Difficulty::Nightmare => (50, 400),
+        };
+        ScoringRules {
+            initial_score,
+            kill_rewards: [kill_reward; ALIEN_DESIGNS.len()],
+            ..ScoringRules::default()
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Difficulty::ALL
+            .iter()
+            .copied()
+            .find(|d| d.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                format!(
+                    "unknown difficulty '{}' (expected easy, normal, hard or nightmare)",
+                    s
+                )
+            })
+    }
+}
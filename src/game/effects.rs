@@ -0,0 +1,154 @@
+// asciiliens/src/game/effects.rs
+
+//! This module defines the cosmetic effects layered over the playfield:
+//! debris flying out of destroyed aliens, score popups floating upward, and
+//! the flash of the player's ship when it is destroyed.
+//!
+//! Effects never touch the game's random number generator or score, so they
+//! cannot change how a game plays out.
+
+use crate::util::constants::{
+    DEATH_FLASH_FRAMES, DEBRIS_FRAMES, DEBRIS_PARTICLES, GAME_HEIGHT, GAME_WIDTH,
+    SCORE_POPUP_FRAMES,
+};
+
+/// A piece of debris flying away from a destroyed alien.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Particle {
+    x: i16,
+    y: i16,
+    dx: i16, // Columns moved per frame.
+    dy: i16, // Rows moved per frame.
+    glyph: char,
+    frames_left: u8,
+}
+
+impl Particle {
+    /// Returns the particle's position, or `None` if it has left the playfield.
+    pub fn position(&self) -> Option<(u16, u16)> {
+        on_playfield(self.x, self.y)
+    }
+
+    /// Returns the character the particle is drawn with.
+    pub fn glyph(&self) -> char {
+        self.glyph
+    }
+}
+
+/// The points earned for an alien, floating upward from where it was destroyed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ScorePopup {
+    x: i16,
+    y: i16,
+    text: String,
+    frames_left: u8,
+}
+
+impl ScorePopup {
+    /// Returns the popup's position, or `None` if it has floated off the playfield.
+    ///
+    /// The column is moved left when needed, so the whole text fits on screen.
+    pub fn position(&self) -> Option<(u16, u16)> {
+        let max_x = GAME_WIDTH as i16 - self.text.len() as i16;
+        on_playfield(self.x.min(max_x), self.y)
+    }
+
+    /// Returns the popup's text, e.g. `"+250"`.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Every effect currently on screen.
+#[derive(Debug, Default, Clone)]
+pub struct Effects {
+    particles: Vec<Particle>,
+    popups: Vec<ScorePopup>,
+    flash_frames: u8, // Frames the player's ship has left to flash.
+}
+
+impl Effects {
+    /// Throws a burst of `DEBRIS_PARTICLES` out from a point.
+    ///
+    /// # Arguments
+    /// * `x` - The column the debris starts from.
+    /// * `y` - The row the debris starts from.
+    pub fn spawn_debris(&mut self, x: u16, y: u16) {
+        self.particles
+            .extend(DEBRIS_PARTICLES.iter().map(|&(dx, dy, glyph)| Particle {
+                x: x as i16,
+                y: y as i16,
+                dx,
+                dy,
+                glyph,
+                frames_left: DEBRIS_FRAMES,
+            }));
+    }
+
+    /// Shows the points earned at a point, e.g. `+250`.
+    ///
+    /// # Arguments
+    /// * `x` - The column of the popup's first character.
+    /// * `y` - The row the popup starts on.
+    /// * `points` - The points earned.
+    pub fn spawn_popup(&mut self, x: u16, y: u16, points: i32) {
+        self.popups.push(ScorePopup {
+            x: x as i16,
+            y: y as i16,
+            text: format!("{:+}", points),
+            frames_left: SCORE_POPUP_FRAMES,
+        });
+    }
+
+    /// Starts the flash of the player's destroyed ship.
+    pub fn start_flash(&mut self) {
+        self.flash_frames = DEATH_FLASH_FRAMES;
+    }
+
+    /// Advances every effect by one frame: debris flies on, popups float up
+    /// a row, the flash counts down, and expired effects are removed.
+    pub fn advance(&mut self) {
+        for particle in self.particles.iter_mut() {
+            particle.x += particle.dx;
+            particle.y += particle.dy;
+            particle.frames_left -= 1;
+        }
+        self.particles
+            .retain(|p| p.frames_left > 0 && p.position().is_some());
+        for popup in self.popups.iter_mut() {
+            popup.y -= 1;
+            popup.frames_left -= 1;
+        }
+        self.popups
+            .retain(|p| p.frames_left > 0 && p.position().is_some());
+        self.flash_frames = self.flash_frames.saturating_sub(1);
+    }
+
+    /// Returns the debris on screen.
+    pub fn particles(&self) -> &[Particle] {
+        &self.particles
+    }
+
+    /// Returns the score popups on screen.
+    pub fn popups(&self) -> &[ScorePopup] {
+        &self.popups
+    }
+
+    /// Returns the number of frames the player's ship has left to flash, or 0 if it is not flashing.
+    pub fn flash_frames(&self) -> u8 {
+        self.flash_frames
+    }
+
+    /// Returns whether any effect is still on screen.
+    pub fn is_active(&self) -> bool {
+        !self.particles.is_empty() || !self.popups.is_empty() || self.flash_frames > 0
+    }
+}
+
+/// Converts a position to screen coordinates, or `None` if it is outside the
+/// playfield. The score row at the bottom is not part of the playfield.
+fn on_playfield(x: i16, y: i16) -> Option<(u16, u16)> {
+    let x = u16::try_from(x).ok().filter(|x| *x < GAME_WIDTH)?;
+    let y = u16::try_from(y).ok().filter(|y| *y < GAME_HEIGHT - 1)?;
+    Some((x, y))
+}
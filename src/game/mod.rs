@@ -6,20 +6,36 @@
 // Declare sub-modules that are part of the `game` module.
 pub mod alien;
 pub mod blast;
+pub mod difficulty;
+pub mod effects;
+pub mod options;
 pub mod player;
+pub mod preview;
+pub mod rules;
+pub mod scoring;
+pub mod starfield;
+pub mod stats;
 
 // Import necessary crates and modules for game operations.
 use crate::util::constants::{
-    ALIEN_HEIGHT, ALIEN_MOVE_DOWN_FREQ, ALIEN_WIDTH, BLAST_CHAR, GAME_HEIGHT, GAME_WIDTH,
-    INITIAL_SCORE, PLAYER_WIDTH,
+    BLAST_CHAR, DEATH_FLASH_ART, DESCENT_PREVIEW_CHAR, GAME_HEIGHT, GAME_WIDTH, PLAYER_SHIP_ART,
+    PLAYER_TWO_SHIP_ART, PLAYER_WIDTH,
 };
-use rand::Rng; // Required for random number generation, e.g., alien movement.
+use rand::rngs::StdRng; // Seedable RNG, so a game can be replayed from its seed.
+use rand::{Rng, SeedableRng}; // Required for random number generation, e.g., alien movement.
 use std::io::{self, Write}; // Standard I/O traits for drawing.
 
 // Import public structs from sub-modules for direct use within `game` module.
 use self::alien::Alien;
 use self::blast::Blast;
+use self::effects::Effects;
+use self::options::GameOptions;
 use self::player::Player;
+use self::preview::TurnPreview;
+use self::rules::{FireRejection, FireRule};
+use self::scoring::ScoringRules;
+use self::starfield::Starfield;
+use self::stats::SessionStats;
 
 // --- Game State Enums ---
 
@@ -43,7 +59,7 @@ pub enum GameState {
 /// from user input or internal game mechanics.
 ///
 /// These events drive the game's state updates in the `Game::update` method.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum GameEvent {
     /// Instructs the player ship to move one column to the left.
     MoveLeft,
@@ -56,6 +72,12 @@ pub enum GameEvent {
     /// Advances the game simulation by one frame without specific player action.
     /// This is a fallback for non-action inputs, ensuring aliens still move.
     AdvanceFrame,
+    /// Instructs the second ship of a co-op game to move one column to the left.
+    SecondMoveLeft,
+    /// Instructs the second ship of a co-op game to move one column to the right.
+    SecondMoveRight,
+    /// Instructs the second ship of a co-op game to unleash a blast.
+    SecondFire,
 }
 
 // --- Main Game Struct ---
@@ -64,15 +86,33 @@ pub enum GameEvent {
 ///
 /// This struct manages the player, blasts, aliens, game progression,
 /// score, and random number generation for dynamic behaviors.
-#[derive(Debug)]
+///
+/// A co-op game has two ships on the field. They share the score and the
+/// game ends once both are destroyed, but every ship also tallies the points
+/// its own blasts earned.
+#[derive(Debug, Clone)]
 pub struct Game {
-    player: Player,
+    players: Vec<Player>, // One ship, or two in a co-op game.
     blasts: Vec<Blast>,
     aliens: Vec<Alien>,
     frame_counter: u64,
     game_state: GameState,
-    rng: rand::rngs::ThreadRng, // Keep this as ThreadRng for main game logic
+    rng: StdRng, // Seeded from `options.seed`, so the whole game is reproducible.
+    options: GameOptions,
     score: i32,
+    fire_rejection: Option<FireRejection>,
+    scoring: ScoringRules,
+    stats: SessionStats,
+    streak: u32,
+    starfield: Starfield,
+    starfield_visible: bool, // Whether `draw` shows the starfield behind the entities.
+    effects: Effects,        // Cosmetic debris, popups and flashes; never affects play.
+    ghost_x: Option<u16>,    // Where a previous run's ship was on this frame; never affects play.
+    preview_visible: bool,   // Whether `draw` shows the turn preview; never affects play.
+    reduced_motion: bool,    // Whether `draw` leaves out the effects; never affects play.
+    descent_frequency: u64,  // Every how many frames the aliens descend.
+    invulnerable: bool,      // Set in the sandbox to keep the ships from being destroyed.
+    endless: bool,           // Set in the sandbox to keep playing once the field is empty.
 }
 
 impl Default for Game {
@@ -91,17 +131,87 @@ impl Game {
     /// # Returns
     /// A new `Game` instance ready for the first frame of gameplay.
     pub fn new() -> Self {
+        Self::with_options(GameOptions::default())
+    }
+
+    /// Creates a new `Game` instance from the given options.
+    ///
+    /// The difficulty determines the scoring rules, the level determines how far
+    /// down the alien formation starts, and the seed (random if `None`) drives
+    /// every random decision in the game.
+    ///
+    /// # Arguments
+    /// * `options` - The `GameOptions` for this game.
+    ///
+    /// # Returns
+    /// A new `Game` instance ready for the first frame of gameplay.
+    pub fn with_options(options: GameOptions) -> Self {
+        Self::build(options, options.difficulty.scoring_rules())
+    }
+
+    /// Creates a new `Game` instance that scores according to the given rules.
+    ///
+    /// The game starts with `scoring.initial_score` points; otherwise this is
+    /// identical to `Game::new`.
+    ///
+    /// # Arguments
+    /// * `scoring` - The `ScoringRules` used for every score change in this game.
+    ///
+    /// # Returns
+    /// A new `Game` instance ready for the first frame of gameplay.
+    pub fn with_scoring_rules(scoring: ScoringRules) -> Self {
+        Self::build(GameOptions::default(), scoring)
+    }
+
+    /// Creates the game from its options and the scoring rules it should use.
+    fn build(options: GameOptions, scoring: ScoringRules) -> Self {
+        // Resolve the seed up front so it can be reported (and replayed) later.
+        let seed = options
+            .seed
+            .unwrap_or_else(|| rand::thread_rng().gen::<u64>());
+        let options = GameOptions {
+            seed: Some(seed),
+            ..options
+        };
+        let players = if options.co_op {
+            // Each ship starts a third of the way in from its side of the screen.
+            vec![
+                Player::with_art(GAME_WIDTH / 3, PLAYER_SHIP_ART),
+                Player::with_art(GAME_WIDTH * 2 / 3, PLAYER_TWO_SHIP_ART),
+            ]
+        } else {
+            vec![Player::new()]
+        };
         let mut game = Self {
-            player: Player::new(),
+            players,
             blasts: Vec::new(),
             aliens: Vec::new(), // Initialize aliens as empty for now
             frame_counter: 0,
             game_state: GameState::Playing,
-            rng: rand::thread_rng(), // Default RNG for actual gameplay
-            score: INITIAL_SCORE,    // The game starts with INITIAL_SCORE points.
+            rng: StdRng::seed_from_u64(seed),
+            options,
+            score: scoring.initial_score, // The game starts with the rules' initial score.
+            fire_rejection: None,
+            scoring,
+            stats: SessionStats::default(),
+            streak: 0,
+            starfield: Starfield::new(seed),
+            starfield_visible: false,
+            ghost_x: None,
+            preview_visible: false,
+            reduced_motion: false,
+            descent_frequency: options.difficulty.descent_frequency(),
+            invulnerable: false,
+            endless: false,
+            effects: Effects::default(),
         };
         // Now, call the associated function to populate aliens, borrowing disjoint fields
         Game::initialize_aliens_for_game(&mut game.aliens, &mut game.rng);
+        // Higher levels start the whole formation closer to the player.
+        let offset = options.formation_offset();
+        for alien in game.aliens.iter_mut() {
+            alien.set_y(alien.y() + offset);
+        }
         game
     }
 
@@ -121,6 +231,128 @@ impl Game {
         }
     }
 
+    /// Returns the starfield scrolling behind the playfield.
+    pub fn starfield(&self) -> &Starfield {
+        &self.starfield
+    }
+
+    /// Returns whether `draw` shows the starfield.
+    pub fn starfield_visible(&self) -> bool {
+        self.starfield_visible
+    }
+
+    /// Sets whether `draw` shows the starfield. The stars scroll either way,
+    /// so toggling this never changes how the game plays out.
+    pub fn set_starfield_visible(&mut self, visible: bool) {
+        self.starfield_visible = visible;
+    }
+
+    /// Returns the center x-coordinate of the ghost ship, if one is shown.
+    pub fn ghost_x(&self) -> Option<u16> {
+        self.ghost_x
+    }
+
+    /// Sets where `draw` shows the ghost ship: a dim ship marking where a
+    /// previous run's ship was on the same frame. The ghost is only drawn,
+    /// so it never changes how the game plays out.
+    ///
+    /// # Arguments
+    /// * `x` - The ghost ship's center x-coordinate, or `None` to hide it.
+    pub fn set_ghost_x(&mut self, x: Option<u16>) {
+        self.ghost_x = x;
+    }
+
+    /// Returns whether `draw` shows the turn preview.
+    pub fn preview_visible(&self) -> bool {
+        self.preview_visible
+    }
+
+    /// Sets whether `draw` shows the turn preview: dim marks where the blasts
+    /// will be next frame and where the aliens will land on their next
+    /// descent, and a countdown to it on the status line. The preview is worked
+    /// out on a clone of the game, so it never changes how the game plays out.
+    pub fn set_preview_visible(&mut self, visible: bool) {
+        self.preview_visible = visible;
+    }
+
+    /// Returns whether `draw` leaves out the debris, score popups and death flash.
+    pub fn reduced_motion(&self) -> bool {
+        self.reduced_motion
+    }
+
+    /// Sets whether `draw` leaves out the debris, score popups and death flash,
+    /// for players who find the motion uncomfortable. The effects still run,
+    /// so this never changes how the game plays out.
+    pub fn set_reduced_motion(&mut self, reduced: bool) {
+        self.reduced_motion = reduced;
+    }
+
+    /// Looks ahead at the blasts' next positions and the aliens' next descent.
+    ///
+    /// # Returns
+    /// The `TurnPreview`, worked out on a clone of the game.
+    pub fn preview(&self) -> TurnPreview {
+        TurnPreview::of(self)
+    }
+
+    /// Returns every how many frames the aliens descend.
+    pub fn descent_frequency(&self) -> u64 {
+        self.descent_frequency
+    }
+
+    /// Sets every how many frames the aliens descend, overriding the difficulty's
+    /// `Difficulty::descent_frequency`. Used by the sandbox to explore balance.
+    ///
+    /// # Arguments
+    /// * `frames` - The number of frames between descents; 0 counts as 1.
+    pub fn set_descent_frequency(&mut self, frames: u64) {
+        self.descent_frequency = frames.max(1);
+    }
+
+    /// Returns whether the ships are invulnerable.
+    pub fn invulnerable(&self) -> bool {
+        self.invulnerable
+    }
+
+    /// Sets whether the ships are invulnerable. An alien ramming an
+    /// invulnerable ship is wrecked instead, and aliens reaching the ships'
+    /// row land without ending the game. Used by the sandbox.
+    pub fn set_invulnerable(&mut self, invulnerable: bool) {
+        self.invulnerable = invulnerable;
+    }
+
+    /// Returns whether the game goes on once every alien is gone.
+    pub fn endless(&self) -> bool {
+        self.endless
+    }
+
+    /// Sets whether the game goes on once every alien is gone, instead of
+    /// being won, so more aliens can be added. Used by the sandbox.
+    pub fn set_endless(&mut self, endless: bool) {
+        self.endless = endless;
+    }
+
+    /// Returns the cosmetic effects on screen.
+    pub fn effects(&self) -> &Effects {
+        &self.effects
+    }
+
+    /// Advances the cosmetic effects by one frame without advancing the game,
+    /// so they can play out after the game has ended.
+    pub fn animate_effects(&mut self) {
+        self.effects.advance();
+    }
+
+    /// Returns the options this game was created with, with the seed always resolved.
+    pub fn options(&self) -> GameOptions {
+        self.options
+    }
+
+    /// Returns the seed driving this game's random decisions.
+    pub fn seed(&self) -> u64 {
+        self.options.seed.unwrap_or_default()
+    }
+
     /// Returns the current state of the game.
     ///
     /// # Returns
@@ -137,9 +369,14 @@ impl Game {
         self.score
     }
 
-    /// Returns an immutable reference to the player.
+    /// Returns an immutable reference to the player (the first ship in a co-op game).
     pub fn player(&self) -> &Player {
-        &self.player
+        &self.players[0]
+    }
+
+    /// Returns every ship in the game: one, or two in a co-op game.
+    pub fn players(&self) -> &[Player] {
+        &self.players
     }
 
     /// Returns an immutable slice of active blasts.
@@ -169,9 +406,86 @@ impl Game {
         self.frame_counter
     }
 
+    /// Returns the rule currently limiting how blasts may be unleashed.
+    pub fn fire_rule(&self) -> FireRule {
+        self.options.fire_rule
+    }
+
+    /// Sets the rule limiting how blasts may be unleashed, usually chosen
+    /// with `GameOptions::fire_rule` instead. The rule is part of the game's
+    /// options, so a replay recorded afterwards plays by it too.
+    pub fn set_fire_rule(&mut self, fire_rule: FireRule) {
+        self.options.fire_rule = fire_rule;
+    }
+
+    /// Returns why the most recent `GameEvent::Fire` was rejected, if it was.
+    ///
+    /// This is cleared at the start of every update, so it only reflects the last frame.
+    pub fn fire_rejection(&self) -> Option<FireRejection> {
+        self.fire_rejection
+    }
+
+    /// Returns the scoring rules in effect for this game.
+    pub fn scoring(&self) -> &ScoringRules {
+        &self.scoring
+    }
+
+    /// Returns the statistics gathered so far in this session.
+    pub fn stats(&self) -> &SessionStats {
+        &self.stats
+    }
+
+    /// Returns the number of consecutive blasts that have hit an alien without a miss.
+    pub fn streak(&self) -> u32 {
+        self.streak
+    }
+
+    /// Returns a fingerprint of everything that decides how the game plays on:
+    /// the frame, state and score, and every ship, blast and alien.
+    ///
+    /// The hash is FNV-1a over fixed-width values, so two copies of a game agree
+    /// on it whichever build or platform computed them. Cosmetic state such as
+    /// the starfield and effects is left out.
+    ///
+    /// # Returns
+    /// The 64-bit hash of the game's state.
+    pub fn state_hash(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+        let mut hash = FNV_OFFSET_BASIS;
+        let mut feed = |value: u64| {
+            for byte in value.to_le_bytes() {
+                hash = (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME);
+            }
+        };
+
+        feed(self.frame_counter);
+        feed(self.game_state as u64);
+        feed(self.score as u64);
+        feed(u64::from(self.streak));
+        for player in &self.players {
+            feed(u64::from(player.x()));
+            feed(u64::from(player.destroyed()));
+            feed(player.points() as u64);
+        }
+        for blast in &self.blasts {
+            feed(u64::from(blast.x()));
+            feed(u64::from(blast.y()));
+            feed(blast.owner() as u64);
+        }
+        for alien in &self.aliens {
+            feed(u64::from(alien.x()));
+            feed(u64::from(alien.y()));
+            feed(alien.kind() as u64);
+            feed(u64::from(alien.alive()));
+            feed(u64::from(alien.explosion_frame()));
+        }
+        hash
+    }
+
     /// Returns a mutable reference to the game's RNG.
     #[allow(dead_code)] // Only used in tests
-    pub(crate) fn rng_mut(&mut self) -> &mut rand::rngs::ThreadRng {
+    pub(crate) fn rng_mut(&mut self) -> &mut StdRng {
         &mut self.rng
     }
 
@@ -181,9 +495,9 @@ impl Game {
     /// 1. Incrementing the frame counter.
     /// 2. Processing player input (`GameEvent`).
     /// 3. Updating blast positions.
-    /// 4. Handling collisions between blasts and aliens.
-    /// 5. Advancing alien explosion animations.
-    /// 6. Handling alien horizontal and vertical movements.
+    /// 4. Handling alien horizontal and vertical movements.
+    /// 5. Handling collisions between blasts and aliens, along the paths both swept this frame.
+    /// 6. Advancing alien explosion animations.
     /// 7. Checking for game over or win conditions.
     ///
     /// Game updates only occur if the `game_state` is `Playing`.
@@ -191,6 +505,24 @@ impl Game {
     /// # Arguments
     /// * `event` - The `GameEvent` that triggered this update (e.g., player move, fire, quit).
     pub fn update(&mut self, event: GameEvent) {
+        self.play_frame(&[event]);
+    }
+
+    /// Updates the game by a single frame in which both ships of a co-op game act.
+    ///
+    /// This is `update` for players whose actions arrive together, as in a
+    /// networked co-op turn: both events are applied before anything moves, so
+    /// the turn lasts one frame, just like a key press in a local co-op game.
+    ///
+    /// # Arguments
+    /// * `first` - The first ship's event.
+    /// * `second` - The second ship's event, e.g. `GameEvent::SecondFire`.
+    pub fn update_co_op(&mut self, first: GameEvent, second: GameEvent) {
+        self.play_frame(&[first, second]);
+    }
+
+    /// Plays one frame, applying the given events in order before the entities move.
+    fn play_frame(&mut self, events: &[GameEvent]) {
         // If the game is no longer in the `Playing` state, no further updates should occur.
         if self.game_state != GameState::Playing {
             return;
@@ -198,21 +530,48 @@ impl Game {
 
         // Increment the global frame counter for timing game events.
         self.frame_counter += 1;
+        // Any fire rejection only applies to the frame in which it happened.
+        self.fire_rejection = None;
 
-        // Process the specific `GameEvent` received.
+        // Process the `GameEvent`s received, in order.
+        for event in events {
+            self.apply_event(*event);
+        }
+
+        // Remember where every alien starts this frame, so collisions can be swept.
+        for alien in self.aliens.iter_mut() {
+            alien.begin_frame();
+        }
+
+        // Perform sequence of update steps for all game entities.
+        // Collisions are resolved only once both blasts and aliens have moved,
+        // so a blast and an alien can never swap places without meeting, and
+        // blasts that reached the top edge are only removed afterwards, so the
+        // last rows they flew through are checked too.
+        self.move_blasts();
+        self.update_alien_movement();
+        self.handle_collisions();
+        self.remove_spent_blasts();
+        self.update_explosions(); // Update any ongoing alien explosion animations.
+        self.starfield.scroll(self.frame_counter);
+        self.record_closest_approach();
+        self.check_game_over_conditions(); // Check if the game has ended (win, lose).
+
+        // Quitting ends the session without playing out the frame.
+        if self.game_state != GameState::Quit {
+            self.stats.frames_survived += 1;
+        }
+    }
+
+    /// Applies a player's event at the start of a frame.
+    fn apply_event(&mut self, event: GameEvent) {
         match event {
-            GameEvent::MoveLeft => {
-                self.player.move_left();
-                self.score = self.score.saturating_sub(1); // Deduct 1 point for each movement.
-            }
-            GameEvent::MoveRight => {
-                self.player.move_right();
-                self.score = self.score.saturating_sub(1); // Deduct 1 point for each movement.
-            }
-            GameEvent::Fire => {
-                self.fire_blast();
-                self.score = self.score.saturating_sub(1); // Deduct 1 point for each blast unleashed.
-            }
+            GameEvent::MoveLeft => self.move_ship(0, Player::move_left),
+            GameEvent::MoveRight => self.move_ship(0, Player::move_right),
+            GameEvent::Fire => self.fire(0),
+            GameEvent::SecondMoveLeft => self.move_ship(1, Player::move_left),
+            GameEvent::SecondMoveRight => self.move_ship(1, Player::move_right),
+            GameEvent::SecondFire => self.fire(1),
             GameEvent::Quit => {
                 // If a quit event occurs, set the game state to `Quit`.
                 self.game_state = GameState::Quit;
@@ -222,75 +581,179 @@ impl Game {
                 // This allows alien movement and other time-based events to still occur.
             }
         }
+    }
 
-        // Perform sequence of update steps for all game entities.
-        self.update_blasts();
-        self.handle_collisions();
-        self.update_explosions(); // Update any ongoing alien explosion animations.
-        self.update_alien_movement();
-        self.check_game_over_conditions(); // Check if the game has ended (win, lose).
+    /// Returns the ship with the given index, unless there is no such ship or it has been destroyed.
+    fn active_ship(&mut self, ship: usize) -> Option<&mut Player> {
+        self.players
+            .get_mut(ship)
+            .filter(|player| !player.destroyed())
+    }
+
+    /// Moves a ship one column and charges the move to the score and the ship's points.
+    ///
+    /// A missing or destroyed ship cannot move, so the frame simply advances.
+    ///
+    /// # Arguments
+    /// * `ship` - The index of the ship (0 for the first ship).
+    /// * `movement` - The move to make, e.g. `Player::move_left`.
+    fn move_ship(&mut self, ship: usize, movement: fn(&mut Player)) {
+        let cost = self.scoring.move_cost;
+        let Some(player) = self.active_ship(ship) else {
+            return;
+        };
+        movement(player);
+        player.add_points(-cost);
+        self.stats.moves_made += 1;
+        self.score = self.score.saturating_sub(cost); // Deduct the cost of each movement.
+    }
+
+    /// Lets a ship unleash a blast if the fire rule allows it, charging the
+    /// score and the ship's points either way.
+    ///
+    /// The fire rule applies to each ship on its own: only the ship's own
+    /// blasts count towards the limit, and only its own last blast towards the cooldown.
+    ///
+    /// # Arguments
+    /// * `ship` - The index of the ship (0 for the first ship).
+    fn fire(&mut self, ship: usize) {
+        let Some(player) = self.players.get(ship).filter(|p| !p.destroyed()) else {
+            return; // A missing or destroyed ship cannot fire.
+        };
+        let blasts_in_flight = self.blasts.iter().filter(|b| b.owner() == ship).count();
+        let cost = match self.options.fire_rule.check(
+            blasts_in_flight,
+            self.frame_counter,
+            player.last_fire_frame(),
+        ) {
+            Ok(()) => {
+                self.fire_blast(ship);
+                self.scoring.fire_cost // Deduct the cost of each blast unleashed.
+            }
+            Err(rejection) => {
+                // No blast leaves the ship, so only the (usually zero) rejected-fire cost applies.
+                self.fire_rejection = Some(rejection);
+                self.scoring.rejected_fire_cost
+            }
+        };
+        self.players[ship].add_points(-cost);
+        self.score = self.score.saturating_sub(cost);
+    }
+
+    /// Records in the session stats how close the nearest active alien is to the player's row.
+    fn record_closest_approach(&mut self) {
+        let player_y = self.player().y_pos();
+        let nearest = self
+            .aliens
+            .iter()
+            .filter(|alien| alien.alive() && alien.explosion_frame() == 0)
+            // Empty rows between the alien's bottom edge and the player's row.
+            .map(|alien| player_y.saturating_sub(alien.y() + alien.height()))
+            .min();
+        if let Some(rows) = nearest {
+            self.stats.record_approach(rows);
+        }
     }
 
-    /// Unleashes a new `Blast` from the player's current horizontal position.
+    /// Unleashes a new `Blast` from a ship's current horizontal position.
     ///
-    /// The blast is initialized just above the player's ship.
-    fn fire_blast(&mut self) {
-        self.blasts.push(Blast::new(
-            self.player.x(),
-            self.player.y_pos().saturating_sub(1), // Blast starts one row above player.
-        ));
+    /// The blast is initialized just above the ship and attributed to it.
+    ///
+    /// # Arguments
+    /// * `ship` - The index of the ship (0 for the first ship).
+    fn fire_blast(&mut self, ship: usize) {
+        let player = &mut self.players[ship];
+        self.blasts.push(
+            Blast::new(
+                player.x(),
+                player.y_pos().saturating_sub(1), // Blast starts one row above player.
+            )
+            .owned_by(ship),
+        );
+        player.set_last_fire_frame(self.frame_counter);
+        self.stats.shots_fired += 1;
     }
 
     /// Updates the vertical positions of all active blasts and removes any that
     /// have gone off-screen (reached the top edge of the game area).
     ///
-    /// This method iterates through all blasts, moves them up, and then filters
-    /// out those that are no longer visible.
+    /// This is `move_blasts` followed by `remove_spent_blasts`, without the
+    /// collision check `update` resolves between the two.
+    #[allow(dead_code)] // Only used in tests
     pub(crate) fn update_blasts(&mut self) {
-        // Iterate through blasts using a mutable iterator.
-        // For each blast, attempt to move it up.
+        self.move_blasts();
+        self.remove_spent_blasts();
+    }
+
+    /// Moves every blast up by its speed. Blasts that reach the top edge stay
+    /// until `remove_spent_blasts`, so they can still hit an alien on the way.
+    fn move_blasts(&mut self) {
         for blast in &mut self.blasts {
             blast.move_up();
         }
-        // After all blasts have attempted to move, retain only those that are still on screen.
-        // `retain` here correctly takes an immutable reference to check the `y` position.
+    }
+
+    /// Removes the blasts that reached the top edge without hitting anything.
+    /// Such a blast is a miss and resets the player's hit streak.
+    fn remove_spent_blasts(&mut self) {
+        if self.blasts.iter().any(|blast| blast.y() == 0) {
+            self.streak = 0; // A blast left the screen without hitting anything.
+        }
         self.blasts.retain(|blast| blast.y() > 0);
     }
 
     /// Detects and handles collisions between blasts and aliens.
     ///
+    /// Collisions are swept: a blast hits an alien if the path the blast travelled
+    /// this frame crosses the area the alien travelled this frame
+    /// (see `Alien::swept_collides_with_blast`), not just their final cells.
+    ///
     /// When a blast collides with an alive and non-exploding alien:
     /// - The alien begins its explosion animation (`explosion_frame` is set to 1).
-    /// - The blast is marked for removal (its `y` is set to 0), and then filtered out.
+    /// - The blast is removed.
+    ///
+    /// Blasts that reached the top edge this frame are checked too, along the
+    /// path they flew, and are left for `remove_spent_blasts` if they missed.
     ///
-    /// Each blast can only hit one alien.
+    /// Each blast can only hit one alien: the lowest one on its path, since that
+    /// is the one an upward-travelling blast reaches first.
     pub(crate) fn handle_collisions(&mut self) {
-        // Iterate through all blasts.
-        self.blasts.iter_mut().for_each(|blast| {
-            // Only process blasts that are still on screen (not already marked for removal).
-            if blast.y() > 0 {
-                // Find the first alien that this blast collides with.
-                for alien in self.aliens.iter_mut() {
-                    if alien.collides_with_blast(blast) {
-                        // If an alien is hit, start its explosion animation.
-                        alien.set_explosion_frame(1);
-                        // Mark the blast for removal by moving it off-screen.
-                        blast.set_y(0);
-                        break; // A blast can only hit one alien.
-                    }
-                }
-            }
+        // Keep every blast that did not hit an alien.
+        self.blasts.retain(|blast| {
+            // Find the lowest alien whose swept area this blast's path crosses.
+            let hit_alien = self
+                .aliens
+                .iter_mut()
+                .filter(|alien| alien.swept_collides_with_blast(blast))
+                .max_by_key(|alien| alien.y());
+            let Some(alien) = hit_alien else {
+                return true;
+            };
+            // Count the hit and remember the streak it extended, for the kill reward.
+            self.stats.hits += 1;
+            self.streak += 1;
+            self.stats.longest_streak = self.stats.longest_streak.max(self.streak);
+            alien.set_hit_streak(self.streak);
+            // The shooter is credited with the reward.
+            alien.set_hit_by(blast.owner());
+            // If an alien is hit, start its explosion animation.
+            alien.set_explosion_frame(1);
+            false // The blast is spent on the alien.
         });
-        // Remove all blasts that have hit an alien or gone off-screen.
-        self.blasts.retain(|blast| blast.y() > 0);
     }
 
     /// Advances explosion animation frames for aliens and awards score
     /// when an explosion sequence completes.
     ///
     /// Aliens progress through 4 explosion stages. Upon completing the 4th stage
-    /// (moving to frame 5), the alien is marked as `!alive` and points are awarded.
+    /// (moving to frame 5), the alien is marked as `!alive` and the kill reward for
+    /// its kind and hit streak is awarded according to the `ScoringRules`, both to
+    /// the score and to the points of the ship whose blast hit it.
+    ///
+    /// The cosmetic effects advance here too, and each destroyed alien throws
+    /// out debris and a popup showing the points it earned.
     pub(crate) fn update_explosions(&mut self) {
+        self.effects.advance();
         // Refactored from .for_each to a standard for loop for clarity on side effects.
         for alien in self.aliens.iter_mut() {
             if alien.explosion_frame() > 0 && alien.explosion_frame() < 5 {
@@ -300,7 +763,18 @@ impl Game {
                 // mark the alien as not alive and award points.
                 if alien.explosion_frame() == 5 {
                     alien.set_alive(false); // Alien is now fully exploded and no longer active.
-                    self.score = self.score.saturating_add(250); // Award points for destroying an alien.
+                    self.stats.kills += 1;
+                    let reward = self.scoring.kill_reward(alien.kind(), alien.hit_streak());
+                    self.score = self.score.saturating_add(reward); // Award points for destroying an alien.
+                    if let Some(shooter) = self.players.get_mut(alien.hit_by()) {
+                        shooter.add_points(reward);
+                    }
+                    self.effects.spawn_debris(
+                        alien.x() + alien.width() / 2,
+                        alien.y() + alien.height() / 2,
+                    );
+                    self.effects
+                        .spawn_popup(alien.x(), alien.y().saturating_sub(1), reward);
                 }
             }
         }
@@ -308,30 +782,47 @@ impl Game {
 
     /// Manages the movement of aliens, both horizontally and vertically.
     ///
-    /// - **Horizontal Movement**: In each frame, one random *alive and non-exploding*
-    ///   alien will attempt to move horizontally towards the player.
+    /// - **Horizontal Movement**: In each frame, a number of distinct random *alive and
+    ///   non-exploding* aliens (one on Normal, see `Difficulty::aliens_moving_per_frame`)
+    ///   will attempt to move horizontally towards the nearest surviving ship.
     /// - **Vertical Movement**: All *alive and non-exploding* aliens move one row down
-    ///   periodically, based on `ALIEN_MOVE_DOWN_FREQ`.
+    ///   periodically, every `Difficulty::descent_frequency` frames
+    ///   (`ALIEN_MOVE_DOWN_FREQ` on Normal) unless `set_descent_frequency` changed it.
     fn update_alien_movement(&mut self) {
-        // Horizontal movement: One random alien moves towards the player.
-        // Collect mutable references to aliens that are alive and not currently exploding.
-        let mut movable_aliens: Vec<&mut Alien> = self
+        let difficulty = self.options.difficulty;
+
+        // Horizontal movement: random aliens move towards the player.
+        // Collect the indices of aliens that are alive and not currently exploding.
+        let mut movable_aliens: Vec<usize> = self
             .aliens
-            .iter_mut()
-            .filter(|alien| alien.alive() && alien.explosion_frame() == 0)
+            .iter()
+            .enumerate()
+            .filter(|(_, alien)| alien.alive() && alien.explosion_frame() == 0)
+            .map(|(index, _)| index)
             .collect();
 
-        if !movable_aliens.is_empty() {
-            // Select a random alien from the filtered list.
-            let alien_to_move_idx = self.rng.gen_range(0..movable_aliens.len()); // Use self.rng
-            let alien_to_move = &mut movable_aliens[alien_to_move_idx];
+        for _ in 0..difficulty.aliens_moving_per_frame() {
+            if movable_aliens.is_empty() {
+                break;
+            }
+            // Select a random alien from the filtered list; each alien moves at most once.
+            let pick = self.rng.gen_range(0..movable_aliens.len()); // Use self.rng
+            let alien_to_move = &mut self.aliens[movable_aliens.swap_remove(pick)];
 
-            // Determine if the alien should move left or right to get closer to the player.
-            // Player's horizontal center is roughly `player.x()`.
-            let player_effective_x = self.player.x();
+            // Determine if the alien should move left or right to get closer to the nearest ship.
+            // Each ship's horizontal center is roughly `player.x()`.
+            let Some(player_effective_x) = self
+                .players
+                .iter()
+                .filter(|player| !player.destroyed())
+                .map(|player| player.x())
+                .min_by_key(|x| x.abs_diff(alien_to_move.x()))
+            else {
+                continue; // Every ship has been destroyed.
+            };
 
             if alien_to_move.x() < player_effective_x.saturating_sub(PLAYER_WIDTH / 2)
-                && alien_to_move.x() < GAME_WIDTH - ALIEN_WIDTH
+                && alien_to_move.x() + alien_to_move.width() < GAME_WIDTH
             {
                 // Move right if the alien is significantly to the left of the player.
                 alien_to_move.move_right();
@@ -345,7 +836,7 @@ impl Game {
         }
 
         // Vertical movement: All relevant aliens move down periodically.
-        if self.frame_counter % ALIEN_MOVE_DOWN_FREQ == 0 {
+        if self.frame_counter.is_multiple_of(self.descent_frequency) {
             // Iterate over all aliens and move down only those that are alive and not exploding.
             self.aliens
                 .iter_mut()
@@ -358,12 +849,14 @@ impl Game {
     ///
     /// This method performs the following checks in order:
     /// 1. **Win Condition**: If all aliens are no longer alive (either destroyed or fully exploded).
-    /// 2. **Lose Condition (Invasion)**: If any alive, non-exploding alien reaches or crosses the player's row.
-    /// 3. **Lose Condition (Collision)**: If the player's ship geometrically collides with any
-    ///    alive, non-exploding alien.
+    /// 2. **Lose Condition (Collision)**: If a ship geometrically collides with any
+    ///    alive, non-exploding alien, that ship is destroyed. The game is lost once
+    ///    every ship has been destroyed; in a co-op game the other ship plays on,
+    ///    and the alien is wrecked along with the ship it hit.
+    /// 3. **Lose Condition (Invasion)**: If any alive, non-exploding alien reaches or crosses the player's row.
     ///
     /// Aliens that have finished their explosion animation (`explosion_frame == 5`)
-    /// are filtered out before checking win conditions.
+    /// are filtered out before checking win conditions. An endless game skips the win condition.
     pub(crate) fn check_game_over_conditions(&mut self) {
         // First, clean up aliens that have completed their explosion animation.
         // An alien is fully gone when `alive` is false and its `explosion_frame` is 5 (or greater).
@@ -372,40 +865,73 @@ impl Game {
 
         // Win condition: Check if there are no more alive aliens.
         // An alien is considered "active" (not fully gone) if it's alive OR still exploding.
-        if self.aliens.iter().all(|alien| !alien.alive()) {
+        // An endless game is never won.
+        if !self.endless && self.aliens.iter().all(|alien| !alien.alive()) {
+            // Clearing the wave earns the accuracy and time bonuses.
+            let bonus = self.scoring.wave_clear_bonus(
+                self.stats.shots_fired,
+                self.stats.hits,
+                self.frame_counter,
+            );
+            self.score = self.score.saturating_add(bonus);
             self.game_state = GameState::Win;
             return; // Game has been won, no need for further checks.
         }
 
-        // Lose condition 1: Any active alien invades the player's space (reaches or crosses player's Y-position).
-        // The player is at `GAME_HEIGHT - 2`. Aliens are `ALIEN_HEIGHT` tall.
-        // A collision occurs if the alien's bottom edge (`a.y() + ALIEN_HEIGHT - 1`)
-        // is at or below the player's top edge (`self.player.y_pos()`).
-        if self.aliens.iter().any(|alien| {
+        // Lose condition 1: Ship-Alien direct collision.
+        // This checks for physical contact between each surviving ship and any active alien.
+        let mut rammed = Vec::new(); // The aliens that collided with a ship.
+        for player in self.players.iter_mut().filter(|p| !p.destroyed()) {
+            let ramming = self.aliens.iter().position(|alien| {
+                alien.alive() && alien.explosion_frame() == 0 && player.collides_with_alien(alien)
+            }); // Uses the player's collision method.
+            if let Some(index) = ramming {
+                if !self.invulnerable {
+                    player.destroy();
+                    self.effects.start_flash();
+                }
+                rammed.push(index);
+            }
+        }
+        if self.players.iter().all(Player::destroyed) {
+            self.game_state = GameState::GameOver; // Game is over once no ship is left.
+            return;
+        }
+        // Another ship flies on, or the ships are invulnerable, so an alien that
+        // rammed a ship is wrecked along with it.
+        // It earns nothing, and is removed like a fully exploded alien.
+        for index in rammed {
+            self.aliens[index].set_alive(false);
+            self.aliens[index].set_explosion_frame(5);
+        }
+        self.aliens
+            .retain(|alien| alien.alive() || alien.explosion_frame() < 5);
+
+        // Lose condition 2: Any active alien invades the player's space (reaches or crosses player's Y-position).
+        // The player is at `GAME_HEIGHT - 2`. Aliens are `alien.height()` tall.
+        // An invasion occurs if the alien's bottom edge (`a.y() + a.height() - 1`)
+        // is at or below the player's top edge (`self.player().y_pos()`).
+        let player_y = self.player().y_pos();
+        let invading = |alien: &Alien| {
             alien.alive()
                 && alien.explosion_frame() == 0
-                && (alien.y() + ALIEN_HEIGHT - 1) >= self.player.y_pos()
-        }) {
+                && (alien.y() + alien.height() - 1) >= player_y
+        };
+        if self.invulnerable {
+            // The invaders land harmlessly and leave the field.
+            self.aliens.retain(|alien| !invading(alien));
+        } else if self.aliens.iter().any(invading) {
+            // An invasion overruns every ship still flying.
+            self.players.iter_mut().for_each(Player::destroy);
             self.game_state = GameState::GameOver;
-            return; // Game is over due to invasion.
-        }
-
-        // Lose condition 2: Player-Alien direct collision.
-        // This checks for physical contact between the player's ship and any active alien.
-        if self.aliens.iter().any(
-            |alien| {
-                alien.alive()
-                    && alien.explosion_frame() == 0
-                    && self.player.collides_with_alien(alien)
-            }, // Uses the player's collision method.
-        ) {
-            self.game_state = GameState::GameOver; // Game is over due to player collision.
+            self.effects.start_flash();
         }
     }
 
     /// Draws the current game state to the provided `Write` target.
     ///
-    /// This function renders the player, blasts, aliens, and game status/score.
+    /// This function renders the player, blasts, aliens, and game status/score,
+    /// on top of the starfield if it is visible.
     /// It does not clear the screen; screen clearing is handled by the main loop
     /// before each draw call for a smooth update.
     ///
@@ -417,19 +943,71 @@ impl Game {
     pub fn draw<W: Write>(&self, stdout: &mut W) -> io::Result<()> {
         use crossterm::cursor::MoveTo;
         use crossterm::queue;
-        use crossterm::style::Print; // `queue!` is used for batching commands for efficiency.
+        use crossterm::style::{Attribute, Print, SetAttribute}; // `queue!` is used for batching commands for efficiency.
+
+        // Draw the starfield first, so every entity is drawn over it.
+        if self.starfield_visible {
+            for star in self.starfield.stars() {
+                queue!(stdout, MoveTo(star.x(), star.y()), Print(star.glyph()))?;
+            }
+        }
+
+        // Draw the ghost ship dimmed, beneath the live ships.
+        if let Some(ghost_x) = self.ghost_x {
+            queue!(
+                stdout,
+                MoveTo(
+                    ghost_x.saturating_sub(PLAYER_WIDTH / 2),
+                    self.player().y_pos()
+                ),
+                SetAttribute(Attribute::Dim),
+                Print(PLAYER_SHIP_ART),
+                SetAttribute(Attribute::NormalIntensity)
+            )?;
+        }
+
+        // Draw the turn preview dimmed, beneath the entities.
+        let preview = self
+            .preview_visible
+            .then(|| self.preview())
+            .filter(|_| self.game_state == GameState::Playing);
+        if let Some(preview) = &preview {
+            queue!(stdout, SetAttribute(Attribute::Dim))?;
+            for &(x, bottom, width) in preview.descending() {
+                let marks: String =
+                    std::iter::repeat_n(DESCENT_PREVIEW_CHAR, width as usize).collect();
+                queue!(stdout, MoveTo(x, bottom), Print(marks))?;
+            }
+            for &(x, y) in preview.blasts() {
+                queue!(stdout, MoveTo(x, y), Print(BLAST_CHAR))?;
+            }
+            queue!(stdout, SetAttribute(Attribute::NormalIntensity))?;
+        }
 
-        // Draw the player's ship.
-        // The player's `x` is its center, so `saturating_sub(PLAYER_WIDTH / 2)`
+        // Draw every ship.
+        // A ship's `x` is its center, so `saturating_sub(PLAYER_WIDTH / 2)`
         // gets the starting x-coordinate for drawing its full width.
-        queue!(
-            stdout,
-            MoveTo(
-                self.player.x().saturating_sub(PLAYER_WIDTH / 2),
-                self.player.y_pos()
-            ),
-            Print(self.player.display_string())
-        )?;
+        let flash_frames = self.effects.flash_frames();
+        for player in &self.players {
+            let player_x = player.x().saturating_sub(PLAYER_WIDTH / 2);
+            if !player.destroyed() {
+                queue!(
+                    stdout,
+                    MoveTo(player_x, player.y_pos()),
+                    Print(player.display_string())
+                )?;
+            } else if flash_frames > 0 && !self.reduced_motion {
+                // A destroyed ship flashes in reverse video, alternating between two images,
+                // and then disappears.
+                queue!(
+                    stdout,
+                    MoveTo(player_x, player.y_pos()),
+                    SetAttribute(Attribute::Reverse),
+                    Print(DEATH_FLASH_ART[flash_frames as usize % DEATH_FLASH_ART.len()]),
+                    SetAttribute(Attribute::NoReverse)
+                )?;
+            }
+        }
 
         // Draw all active blasts.
         for blast in self.blasts() {
@@ -444,14 +1022,31 @@ impl Game {
         for alien in self.aliens() {
             if alien.alive() || alien.explosion_frame() > 0 {
                 // Only draw if active in some way.
-                let (top_str, bottom_str) = alien.display_strings(); // Get 2-line alien art.
-                queue!(
-                    stdout,
-                    MoveTo(alien.x(), alien.y()), // Top row of alien.
-                    Print(top_str),
-                    MoveTo(alien.x(), alien.y() + 1), // Bottom row of alien.
-                    Print(bottom_str)
-                )?;
+                // Draw the alien's art row by row, from its top row down.
+                for (row, line) in alien.display_strings().into_iter().enumerate() {
+                    queue!(
+                        stdout,
+                        MoveTo(alien.x(), alien.y() + row as u16),
+                        Print(line)
+                    )?;
+                }
+            }
+        }
+
+        // Draw the cosmetic effects over the entities, unless motion is reduced.
+        let (particles, popups) = if self.reduced_motion {
+            (&[][..], &[][..])
+        } else {
+            (self.effects.particles(), self.effects.popups())
+        };
+        for particle in particles {
+            if let Some((x, y)) = particle.position() {
+                queue!(stdout, MoveTo(x, y), Print(particle.glyph()))?;
+            }
+        }
+        for popup in popups {
+            if let Some((x, y)) = popup.position() {
+                queue!(stdout, MoveTo(x, y), Print(popup.text()))?;
             }
         }
 
@@ -465,11 +1060,48 @@ impl Game {
 
         // Determine and draw the game status message.
         // This message provides feedback to the player (e.g., instructions, win/lose).
-        let game_status_message = match self.game_state {
-            GameState::Playing => "Press 'q' to quit, 'left/right' arrows to move, 'space' to fire. Hit any key to advance.",
-            GameState::Win => "YOU WON! :) ",
-            GameState::GameOver => "YOU LOST :( ",
-            GameState::Quit => "Quitting...",
+        // A rejected fire attempt takes precedence over the usual instructions.
+        // With the turn preview on, the countdown to the next descent is shown too.
+        let descent = preview.as_ref().map(TurnPreview::turns_to_descent);
+        let game_status_message = match (self.game_state, self.fire_rejection) {
+            (GameState::Playing, Some(FireRejection::LimitReached)) => {
+                "Blast limit reached! Wait for a blast to land before firing again.".to_string()
+            }
+            (GameState::Playing, Some(FireRejection::Recharging(frames))) => {
+                format!(
+                    "Blaster recharging... {} more turn(s) before you can fire.",
+                    frames
+                )
+            }
+            (GameState::Playing, None) if self.options.co_op => {
+                // Each ship's own points, in place of the key reminder.
+                let points: Vec<String> = self
+                    .players
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| format!("P{}: {}", i + 1, p.points()))
+                    .collect();
+                match descent {
+                    Some(turns) => format!(
+                        "{}  Descent in {}  'q' quit, 'p' pause",
+                        points.join("  "),
+                        turns
+                    ),
+                    None => format!("{}  'q' quit, 'p' pause", points.join("  ")),
+                }
+            }
+            (GameState::Playing, None) => match descent {
+                // The countdown takes the place of the movement keys.
+                Some(turns) => format!(
+                    "Aliens descend in {} turn(s). 'q' quit, 'p' pause, other keys wait.",
+                    turns
+                ),
+                None => "'q' quit, 'p' pause, arrows move, 'space' fire, other keys advance."
+                    .to_string(),
+            },
+            (GameState::Win, _) => "YOU WON! :) ".to_string(),
+            (GameState::GameOver, _) => "YOU LOST :( ".to_string(),
+            (GameState::Quit, _) => "Quitting...".to_string(),
         };
         queue!(
             stdout,
@@ -0,0 +1,46 @@
+// asciiliens/src/game/options.rs
+
+//! This module defines `GameOptions`, the choices made before a game starts
+//! that fully determine how it plays out for a given sequence of events.
+
+use super::difficulty::Difficulty;
+use super::rules::FireRule;
+use crate::util::constants::MAX_LEVEL;
+
+/// The options a `Game` is created with.
+///
+/// Two games created with the same options and a `Some` seed behave identically
+/// when fed the same `GameEvent`s, which is what replays rely on.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct GameOptions {
+    /// The seed for the game's random number generator, or `None` for a random seed.
+    pub seed: Option<u64>,
+    /// The difficulty preset.
+    pub difficulty: Difficulty,
+    /// The starting level, from 1 to `MAX_LEVEL`. Higher levels start the
+    /// alien formation closer to the player.
+    pub level: u16,
+    /// Whether two ships share the playfield, each flown by its own player.
+    pub co_op: bool,
+    /// How many blasts may be in flight and how often one may be unleashed.
+    pub fire_rule: FireRule,
+}
+
+impl Default for GameOptions {
+    fn default() -> Self {
+        Self {
+            seed: None,
+            difficulty: Difficulty::default(),
+            level: 1,
+            co_op: false,
+            fire_rule: FireRule::default(),
+        }
+    }
+}
+
+impl GameOptions {
+    /// Returns the number of rows the alien formation starts below its level 1 position.
+    pub fn formation_offset(&self) -> u16 {
+        self.level.clamp(1, MAX_LEVEL) - 1
+    }
+}
@@ -5,19 +5,23 @@
 
 use super::alien::Alien;
 use crate::util::constants::{
-    ALIEN_HEIGHT, ALIEN_WIDTH, GAME_HEIGHT, GAME_WIDTH, PLAYER_SHIP_ART, PLAYER_WIDTH,
-    PLAYER_Y_OFFSET,
+    GAME_HEIGHT, GAME_WIDTH, PLAYER_SHIP_ART, PLAYER_WIDTH, PLAYER_Y_OFFSET,
 }; // Import `Alien` from the parent `game` module.
 
 /// Represents the player's spaceship in the game.
 ///
 /// The `Player` has a horizontal position (`x`) and is always positioned
-/// at a fixed vertical level near the bottom of the screen.
+/// at a fixed vertical level near the bottom of the screen. In a co-op game
+/// there is one `Player` per ship, each keeping track of its own points.
 #[derive(Debug, Clone, Copy)]
 pub struct Player {
     /// The x-coordinate of the player's center.
     /// This value is clamped within the game boundaries.
     x: u16,
+    art: &'static str, // The ship's ASCII art, `PLAYER_WIDTH` characters wide.
+    destroyed: bool,   // Set once an alien has destroyed the ship.
+    last_fire_frame: Option<u64>, // The frame on which the ship last unleashed a blast.
+    points: i32,       // The points this ship has earned and spent.
 }
 
 impl Default for Player {
@@ -33,7 +37,25 @@ impl Player {
     /// # Returns
     /// A new `Player` instance ready for gameplay.
     pub fn new() -> Self {
-        Player { x: GAME_WIDTH / 2 }
+        Self::with_art(GAME_WIDTH / 2, PLAYER_SHIP_ART)
+    }
+
+    /// Creates a new `Player` instance at a given x-coordinate, drawn with the given art.
+    ///
+    /// # Arguments
+    /// * `x` - The x-coordinate of the player's center.
+    /// * `art` - The ship's ASCII art, `PLAYER_WIDTH` characters wide.
+    ///
+    /// # Returns
+    /// A new `Player` instance.
+    pub fn with_art(x: u16, art: &'static str) -> Self {
+        Player {
+            x,
+            art,
+            destroyed: false,
+            last_fire_frame: None,
+            points: 0,
+        }
     }
 
     /// Creates a new `Player` instance with a specific x-coordinate, primarily for testing.
@@ -48,7 +70,7 @@ impl Player {
     /// A new `Player` instance.
     #[cfg(test)] // This function is only compiled when running tests
     pub fn new_for_test(x: u16) -> Self {
-        Player { x }
+        Self::with_art(x, PLAYER_SHIP_ART)
     }
 
     /// Returns the player's current x-coordinate.
@@ -56,6 +78,38 @@ impl Player {
         self.x
     }
 
+    /// Returns whether an alien has destroyed the ship.
+    pub fn destroyed(&self) -> bool {
+        self.destroyed
+    }
+
+    /// Marks the ship as destroyed. A destroyed ship can no longer move or fire.
+    pub fn destroy(&mut self) {
+        self.destroyed = true;
+    }
+
+    /// Returns the frame on which the ship last unleashed a blast, if any.
+    pub fn last_fire_frame(&self) -> Option<u64> {
+        self.last_fire_frame
+    }
+
+    /// Records the frame on which the ship unleashed a blast.
+    pub fn set_last_fire_frame(&mut self, frame: u64) {
+        self.last_fire_frame = Some(frame);
+    }
+
+    /// Returns the points this ship has earned, minus what its moves and blasts cost.
+    ///
+    /// Unlike the game's score, this does not include the initial score or wave bonuses.
+    pub fn points(&self) -> i32 {
+        self.points
+    }
+
+    /// Adds points to (or, for a negative amount, takes them from) the ship's tally.
+    pub fn add_points(&mut self, points: i32) {
+        self.points = self.points.saturating_add(points);
+    }
+
     /// Moves the player's ship one unit to the left.
     ///
     /// The movement is constrained by the left edge of the game screen,
@@ -80,13 +134,12 @@ impl Player {
 
     /// Generates the string representation of the player's ship for rendering.
     ///
-    /// This string includes the `PLAYER_SHIP_ART` constant directly.
+    /// This is `PLAYER_SHIP_ART`, or the second ship's art in a co-op game.
     ///
     /// # Returns
-    /// A `String` containing the ASCII art representation of the player.
+    /// A `&str` containing the ASCII art representation of the player.
     pub fn display_string(&self) -> &str {
-        // Changed return type from String to &str
-        PLAYER_SHIP_ART
+        self.art
     }
 
     /// Returns the fixed y-coordinate of the player's ship.
@@ -119,7 +172,7 @@ impl Player {
 
         // Calculate the alien's horizontal bounding box.
         let alien_left = alien.x();
-        let alien_right = alien.x() + ALIEN_WIDTH - 1;
+        let alien_right = alien.x() + alien.width() - 1;
 
         // The player occupies a single row vertically.
         let player_top = self.y_pos();
@@ -127,7 +180,7 @@ impl Player {
 
         // Calculate the alien's vertical bounding box.
         let alien_top = alien.y();
-        let alien_bottom = alien.y() + ALIEN_HEIGHT - 1;
+        let alien_bottom = alien.y() + alien.height() - 1;
 
         // Check for horizontal overlap:
         // Player's left edge must be to the left of or inside alien's right edge, AND
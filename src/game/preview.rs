@@ -0,0 +1,74 @@
+// asciiliens/src/game/preview.rs
+
+//! This module looks ahead at what the next turns hold, to help plan a move
+//! in turn-based play.
+//!
+//! A `TurnPreview` is computed by playing a clone of the game forward, so the
+//! real game, including its random number generator, is never touched.
+
+use super::{Game, GameEvent, GameState};
+
+/// What happens next if the player lets the game advance without acting.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TurnPreview {
+    blasts: Vec<(u16, u16)>,          // Where every blast will be next frame.
+    descending: Vec<(u16, u16, u16)>, // The x, new bottom row and width of every alien that will descend.
+    turns_to_descent: u64,            // The number of turns until the aliens next descend.
+}
+
+impl TurnPreview {
+    /// Plays a clone of the game forward until the aliens next descend.
+    ///
+    /// # Arguments
+    /// * `game` - The game to look ahead in. It is left unchanged.
+    ///
+    /// # Returns
+    /// The preview, which is empty if the game is not being played.
+    pub fn of(game: &Game) -> Self {
+        if game.state() != GameState::Playing {
+            return Self::default();
+        }
+        let frequency = game.descent_frequency();
+        let turns_to_descent = frequency - game.frame_counter() % frequency;
+
+        let mut ahead = game.clone();
+        ahead.update(GameEvent::AdvanceFrame);
+        let blasts = ahead.blasts().iter().map(|b| (b.x(), b.y())).collect();
+        let descent_frame = game.frame_counter() + turns_to_descent;
+        while ahead.frame_counter() < descent_frame && ahead.state() == GameState::Playing {
+            ahead.update(GameEvent::AdvanceFrame);
+        }
+        let descending = if ahead.frame_counter() == descent_frame {
+            ahead
+                .aliens()
+                .iter()
+                .filter(|alien| alien.alive() && alien.explosion_frame() == 0)
+                .map(|alien| (alien.x(), alien.y() + alien.height() - 1, alien.width()))
+                .collect()
+        } else {
+            Vec::new() // The game ends first.
+        };
+
+        Self {
+            blasts,
+            descending,
+            turns_to_descent,
+        }
+    }
+
+    /// Returns where every blast will be on the next frame, as x and y coordinates.
+    pub fn blasts(&self) -> &[(u16, u16)] {
+        &self.blasts
+    }
+
+    /// Returns the aliens that will move down on the next descent, as the x
+    /// coordinate, the row their bottom edge will then be on, and their width.
+    pub fn descending(&self) -> &[(u16, u16, u16)] {
+        &self.descending
+    }
+
+    /// Returns the number of turns until the aliens next descend; 1 means on the next turn.
+    pub fn turns_to_descent(&self) -> u64 {
+        self.turns_to_descent
+    }
+}
@@ -0,0 +1,130 @@
+// asciiliens/src/game/rules.rs
+
+//! This module defines the configurable rule set that governs how the player
+//! may unleash blasts, and the reasons a fire attempt can be rejected.
+//!
+//! A rule is written as `unlimited`, `single-shot`, `max:N` or `cooldown:N`
+//! on the command line, in the config file and in replay files.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Determines how many blasts the player may have in flight and how often
+/// a new one may be unleashed.
+///
+/// The rule is enforced by `Game::update` whenever a `GameEvent::Fire` is received.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum FireRule {
+    /// No restriction: a blast may be unleashed on every turn.
+    #[default]
+    Unlimited,
+    /// Classic arcade mode: only one blast may be on screen at a time.
+    SingleShot,
+    /// At most the given number of blasts may be on screen at a time.
+    MaxOnScreen(usize),
+    /// After unleashing a blast, the given number of frames must pass
+    /// before the next one can be unleashed.
+    Cooldown(u64),
+}
+
+/// The reason a `GameEvent::Fire` was rejected by the active `FireRule`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FireRejection {
+    /// The maximum number of blasts allowed on screen has been reached.
+    LimitReached,
+    /// The blaster is still recharging; the value is the number of frames remaining.
+    Recharging(u64),
+}
+
+impl FireRule {
+    /// The rules the options screen cycles through.
+    pub const PRESETS: [FireRule; 4] = [
+        FireRule::Unlimited,
+        FireRule::SingleShot,
+        FireRule::MaxOnScreen(3),
+        FireRule::Cooldown(3),
+    ];
+
+    /// Returns the preset after this rule, wrapping around, for cycling
+    /// through them on the options screen. A rule that is not a preset moves
+    /// on from the preset of the same kind.
+    pub fn next(&self) -> FireRule {
+        let index = FireRule::PRESETS
+            .iter()
+            .position(|p| std::mem::discriminant(p) == std::mem::discriminant(self))
+            .unwrap_or(0);
+        FireRule::PRESETS[(index + 1) % FireRule::PRESETS.len()]
+    }
+
+    /// Checks whether a new blast may be unleashed under this rule.
+    ///
+    /// # Arguments
+    /// * `blasts_on_screen` - The number of blasts currently in flight.
+    /// * `frame` - The current frame counter.
+    /// * `last_fire_frame` - The frame on which the previous blast was unleashed, if any.
+    ///
+    /// # Returns
+    /// `Ok(())` if the blast may be unleashed, or the `FireRejection` explaining why not.
+    pub fn check(
+        &self,
+        blasts_on_screen: usize,
+        frame: u64,
+        last_fire_frame: Option<u64>,
+    ) -> Result<(), FireRejection> {
+        match *self {
+            FireRule::Unlimited => Ok(()),
+            FireRule::SingleShot => FireRule::MaxOnScreen(1).check(blasts_on_screen, frame, None),
+            FireRule::MaxOnScreen(max) => {
+                if blasts_on_screen >= max {
+                    Err(FireRejection::LimitReached)
+                } else {
+                    Ok(())
+                }
+            }
+            FireRule::Cooldown(frames) => match last_fire_frame {
+                // Frames elapsed since the last shot must reach the cooldown length.
+                Some(last) if frame.saturating_sub(last) < frames => Err(
+                    FireRejection::Recharging(frames - frame.saturating_sub(last)),
+                ),
+                _ => Ok(()),
+            },
+        }
+    }
+}
+
+impl fmt::Display for FireRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FireRule::Unlimited => f.write_str("unlimited"),
+            FireRule::SingleShot => f.write_str("single-shot"),
+            FireRule::MaxOnScreen(max) => write!(f, "max:{}", max),
+            FireRule::Cooldown(frames) => write!(f, "cooldown:{}", frames),
+        }
+    }
+}
+
+impl FromStr for FireRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || {
+            format!(
+                "unknown fire rule '{}' (expected unlimited, single-shot, max:N or cooldown:N)",
+                s
+            )
+        };
+        let lower = s.to_ascii_lowercase();
+        match lower.split_once(':') {
+            None if lower == "unlimited" => Ok(FireRule::Unlimited),
+            None if lower == "single-shot" => Ok(FireRule::SingleShot),
+            Some(("max", max)) => match max.parse() {
+                Ok(max) if max > 0 => Ok(FireRule::MaxOnScreen(max)),
+                _ => Err(error()),
+            },
+            Some(("cooldown", frames)) => {
+                frames.parse().map(FireRule::Cooldown).map_err(|_| error())
+            }
+            _ => Err(error()),
+        }
+    }
+}
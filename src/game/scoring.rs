@@ -0,0 +1,170 @@
+// asciiliens/src/game/scoring.rs
+
+//! This module defines `ScoringRules`, the single source of truth for how points
+//! are gained and lost. The same rules drive `Game::update` and the scoring text
+//! shown on the intro screen, so the two can never drift apart.
+
+use crate::util::constants::{ALIEN_DESIGNS, INITIAL_SCORE};
+
+/// The set of values that determine a player's score.
+///
+/// The default rules reproduce the classic game: start with 100 points, pay one
+/// point per move or blast, and earn 250 points per alien, with no bonuses.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ScoringRules {
+    /// The score a new game starts with.
+    pub initial_score: i32,
+    /// Points deducted for each movement (left/right).
+    pub move_cost: i32,
+    /// Points deducted for each blast unleashed.
+    pub fire_cost: i32,
+    /// Points deducted for a fire attempt rejected by the active `FireRule`.
+    pub rejected_fire_cost: i32,
+    /// Points awarded for destroying an alien, indexed by its kind
+    /// (the index of its design in `ALIEN_DESIGNS`).
+    pub kill_rewards: [i32; ALIEN_DESIGNS.len()],
+    /// Extra percentage of the kill reward granted for each consecutive hit
+    /// in a streak beyond the first. A miss resets the streak.
+    pub streak_bonus_percent: i32,
+    /// The cap on the total streak bonus percentage.
+    pub max_streak_bonus_percent: i32,
+    /// Points awarded when a wave is cleared with 100% accuracy,
+    /// scaled down proportionally for lower accuracy.
+    pub accuracy_bonus: i32,
+    /// The number of frames under which clearing a wave earns a time bonus.
+    pub time_bonus_par_frames: u64,
+    /// Points awarded at wave end for each frame the wave was cleared under par.
+    pub time_bonus_per_frame: i32,
+}
+
+impl Default for ScoringRules {
+    fn default() -> Self {
+        Self {
+            initial_score: INITIAL_SCORE,
+            move_cost: 1,
+            fire_cost: 1,
+            rejected_fire_cost: 0,
+            kill_rewards: [250; ALIEN_DESIGNS.len()],
+            streak_bonus_percent: 0,
+            max_streak_bonus_percent: 0,
+            accuracy_bonus: 0,
+            time_bonus_par_frames: 0,
+            time_bonus_per_frame: 0,
+        }
+    }
+}
+
+impl ScoringRules {
+    /// Calculates the reward for destroying an alien.
+    ///
+    /// # Arguments
+    /// * `kind` - The alien's kind (its index in `ALIEN_DESIGNS`).
+    /// * `streak` - The number of consecutive hits, including this one, when the alien was hit.
+    ///
+    /// # Returns
+    /// The base reward for the kind, increased by the streak bonus.
+    pub fn kill_reward(&self, kind: usize, streak: u32) -> i32 {
+        let base = self.kill_rewards.get(kind).copied().unwrap_or(0);
+        let extra_hits = streak.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let bonus_percent = self
+            .streak_bonus_percent
+            .saturating_mul(extra_hits)
+            .min(self.max_streak_bonus_percent);
+        base.saturating_add(base.saturating_mul(bonus_percent) / 100)
+    }
+
+    /// Calculates the bonus awarded when a wave is cleared.
+    ///
+    /// # Arguments
+    /// * `shots_fired` - The number of blasts unleashed during the wave.
+    /// * `hits` - The number of those blasts that hit an alien.
+    /// * `frames` - The number of frames it took to clear the wave.
+    ///
+    /// # Returns
+    /// The sum of the accuracy bonus and the time bonus.
+    pub fn wave_clear_bonus(&self, shots_fired: u32, hits: u32, frames: u64) -> i32 {
+        let accuracy_bonus = if shots_fired == 0 {
+            0
+        } else {
+            (self.accuracy_bonus as i64 * hits.min(shots_fired) as i64 / shots_fired as i64) as i32
+        };
+        let frames_under_par = self.time_bonus_par_frames.saturating_sub(frames);
+        let time_bonus = self
+            .time_bonus_per_frame
+            .saturating_mul(frames_under_par.min(i32::MAX as u64) as i32);
+        accuracy_bonus.saturating_add(time_bonus)
+    }
+
+    /// Generates the scoring lines shown on the intro screen.
+    ///
+    /// Only rules that actually affect the score are listed, so the text always
+    /// matches what the game does.
+    ///
+    /// # Returns
+    /// A `Vec<String>` of lines, starting with a "Scoring:" header.
+    pub fn describe(&self) -> Vec<String> {
+        let mut lines = vec![
+            "Scoring:".to_string(),
+            format!("- Start with {}.", points(self.initial_score)),
+            format!(
+                "-{} for each movement (left/right), -{} for each blast fired.",
+                points(self.move_cost),
+                points(self.fire_cost)
+            ),
+        ];
+
+        if self.rejected_fire_cost != 0 {
+            lines.push(format!(
+                "-{} for trying to fire while the blaster is unavailable.",
+                points(self.rejected_fire_cost)
+            ));
+        }
+
+        // A single line covers every kind when the rewards are all the same.
+        if self.kill_rewards.iter().all(|r| *r == self.kill_rewards[0]) {
+            lines.push(format!(
+                "+{} for destroying an ASCIIlien.",
+                points(self.kill_rewards[0])
+            ));
+        } else {
+            let rewards: Vec<String> = ALIEN_DESIGNS
+                .iter()
+                .zip(self.kill_rewards.iter())
+                .map(|(design, reward)| format!("{}{} +{}", design[0], design[1], reward))
+                .collect();
+            lines.push(format!("Destroying an ASCIIlien: {}", rewards.join("  ")));
+        }
+
+        if self.streak_bonus_percent > 0 && self.max_streak_bonus_percent > 0 {
+            lines.push(format!(
+                "+{}% per consecutive hit in a streak (up to +{}%); a miss resets it.",
+                self.streak_bonus_percent, self.max_streak_bonus_percent
+            ));
+        }
+
+        let mut wave_bonuses = Vec::new();
+        if self.accuracy_bonus > 0 {
+            wave_bonuses.push(format!("up to +{} for accuracy", self.accuracy_bonus));
+        }
+        if self.time_bonus_per_frame > 0 && self.time_bonus_par_frames > 0 {
+            wave_bonuses.push(format!(
+                "+{} per turn under {}",
+                self.time_bonus_per_frame, self.time_bonus_par_frames
+            ));
+        }
+        if !wave_bonuses.is_empty() {
+            lines.push(format!("Wave clear: {}.", wave_bonuses.join(", ")));
+        }
+
+        lines
+    }
+}
+
+/// Formats a point value with the correct singular or plural noun.
+fn points(value: i32) -> String {
+    if value == 1 {
+        "1 point".to_string()
+    } else {
+        format!("{} points", value)
+    }
+}
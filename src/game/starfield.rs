@@ -0,0 +1,109 @@
+// asciiliens/src/game/starfield.rs
+
+//! This module defines the optional starfield drawn behind the playfield.
+//!
+//! Stars belong to one of three depth layers. Nearer layers scroll downward
+//! faster and are drawn brighter, which gives a parallax effect.
+
+use crate::util::constants::{GAME_HEIGHT, GAME_WIDTH, STAR_COUNT};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// The depth layers of the starfield, from farthest to nearest:
+/// (frames per row scrolled, glyph).
+const STAR_LAYERS: [(u64, char); 3] = [(4, '.'), (2, '.'), (1, '·')];
+
+/// Mixed into the game's seed, so the starfield draws different numbers from the game.
+const STARFIELD_SEED_SALT: u64 = 0x5354_4152_4649_454c;
+
+/// A single star in the starfield.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Star {
+    x: u16,
+    y: u16,
+    layer: usize, // Index into `STAR_LAYERS`.
+}
+
+impl Star {
+    /// Returns the star's column.
+    pub fn x(&self) -> u16 {
+        self.x
+    }
+
+    /// Returns the star's row.
+    pub fn y(&self) -> u16 {
+        self.y
+    }
+
+    /// Returns the star's depth layer: 0 is the farthest.
+    pub fn layer(&self) -> usize {
+        self.layer
+    }
+
+    /// Returns the character the star is drawn with.
+    pub fn glyph(&self) -> char {
+        STAR_LAYERS[self.layer].1
+    }
+}
+
+/// The stars behind the playfield, scrolling downward a little each frame.
+///
+/// The starfield has a random number generator of its own, seeded from the
+/// game's seed: it is reproducible along with the rest of the game, yet never
+/// changes the numbers the game itself draws.
+#[derive(Debug, Clone)]
+pub struct Starfield {
+    stars: Vec<Star>,
+    rng: StdRng,
+}
+
+impl Starfield {
+    /// Scatters `STAR_COUNT` stars over the playfield, spread evenly across the layers.
+    ///
+    /// # Arguments
+    /// * `seed` - The seed of the game the starfield belongs to.
+    ///
+    /// # Returns
+    /// A new `Starfield`.
+    pub fn new(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed ^ STARFIELD_SEED_SALT);
+        let stars = (0..STAR_COUNT)
+            .map(|i| Star {
+                x: rng.gen_range(0..GAME_WIDTH),
+                y: rng.gen_range(0..Self::bottom()),
+                layer: i % STAR_LAYERS.len(),
+            })
+            .collect();
+        Self { stars, rng }
+    }
+
+    /// Returns the row below the last one stars are drawn on, which is kept free for the score.
+    fn bottom() -> u16 {
+        GAME_HEIGHT - 1
+    }
+
+    /// Returns every star.
+    pub fn stars(&self) -> &[Star] {
+        &self.stars
+    }
+
+    /// Scrolls the stars for the given frame.
+    ///
+    /// Each layer moves down one row every few frames. A star scrolling past
+    /// the bottom reappears on the top row at a new random column.
+    ///
+    /// # Arguments
+    /// * `frame` - The number of the frame being played.
+    pub fn scroll(&mut self, frame: u64) {
+        for star in self.stars.iter_mut() {
+            if !frame.is_multiple_of(STAR_LAYERS[star.layer].0) {
+                continue;
+            }
+            star.y += 1;
+            if star.y >= Self::bottom() {
+                star.y = 0;
+                star.x = self.rng.gen_range(0..GAME_WIDTH);
+            }
+        }
+    }
+}
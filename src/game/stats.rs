@@ -0,0 +1,47 @@
+// asciiliens/src/game/stats.rs
+
+//! This module defines `SessionStats`, the per-session statistics gathered by
+//! `Game` while it is played. They are presented on the game end screen and
+//! are available to headless users of the library through `Game::stats`.
+
+/// Statistics describing how a single game session was played.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct SessionStats {
+    /// The number of blasts unleashed.
+    pub shots_fired: u32,
+    /// The number of blasts that hit an alien.
+    pub hits: u32,
+    /// The number of aliens destroyed, counted once their explosion completes.
+    pub kills: u32,
+    /// The longest run of consecutive hits without a miss.
+    pub longest_streak: u32,
+    /// The number of frames played before the game ended.
+    pub frames_survived: u64,
+    /// The number of movements (left/right) made.
+    pub moves_made: u32,
+    /// The smallest number of empty rows ever left between an active alien and
+    /// the player's row, or `None` if no alien was ever on the field.
+    pub closest_approach: Option<u16>,
+}
+
+impl SessionStats {
+    /// Returns the percentage of blasts that hit an alien.
+    ///
+    /// # Returns
+    /// A value between 0.0 and 100.0, or 0.0 if no blasts were unleashed.
+    pub fn accuracy_percent(&self) -> f64 {
+        if self.shots_fired == 0 {
+            0.0
+        } else {
+            f64::from(self.hits) * 100.0 / f64::from(self.shots_fired)
+        }
+    }
+
+    /// Records how close the nearest alien came this frame, keeping the minimum.
+    ///
+    /// # Arguments
+    /// * `rows` - The number of empty rows between the nearest alien and the player.
+    pub fn record_approach(&mut self, rows: u16) {
+        self.closest_approach = Some(self.closest_approach.map_or(rows, |c| c.min(rows)));
+    }
+}
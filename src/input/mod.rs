@@ -0,0 +1,120 @@
+// asciiliens/src/input/mod.rs
+
+//! This module abstracts where key presses come from.
+//!
+//! The game reads keys through the `InputSource` trait: `CrosstermInput`
+//! reads the real keyboard, while `ScriptedInput` replays a fixed script, so
+//! whole screen flows can be driven in tests without a terminal.
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use std::collections::VecDeque;
+use std::io;
+use std::time::{Duration, Instant};
+
+/// A source of key presses.
+pub trait InputSource {
+    /// Waits for the next key press.
+    ///
+    /// # Arguments
+    /// * `timeout` - How long to wait, or `None` to wait until a key arrives.
+    ///
+    /// # Returns
+    /// `Ok(Some(key))` for a key press, `Ok(None)` if the timeout passed without
+    /// one, or an I/O error if no more input can be read.
+    fn next_key(&mut self, timeout: Option<Duration>) -> io::Result<Option<KeyEvent>>;
+}
+
+/// Reads key presses from the terminal through crossterm.
+#[derive(Debug, Default)]
+pub struct CrosstermInput;
+
+impl InputSource for CrosstermInput {
+    /// Non-key events, such as resizes, are skipped without restarting the timeout.
+    fn next_key(&mut self, timeout: Option<Duration>) -> io::Result<Option<KeyEvent>> {
+        let deadline = timeout.map(|t| Instant::now() + t);
+        loop {
+            if let Some(deadline) = deadline {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if !event::poll(remaining)? {
+                    return Ok(None); // The timeout passed.
+                }
+            }
+            if let Event::Key(key) = event::read()? {
+                return Ok(Some(key));
+            }
+        }
+    }
+}
+
+/// One step of an input script.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ScriptStep {
+    /// A key press.
+    Key(KeyEvent),
+    /// A timeout passing without a key press. Skipped when waiting without a timeout.
+    Tick,
+}
+
+/// Replays a fixed script of key presses and timeouts.
+///
+/// Once the script runs out, `next_key` returns an `UnexpectedEof` error, so a
+/// flow that waits for more input than scripted fails instead of hanging.
+#[derive(Debug, Default, Clone)]
+pub struct ScriptedInput {
+    steps: VecDeque<ScriptStep>,
+}
+
+impl ScriptedInput {
+    /// Creates a script from the given steps.
+    pub fn new(steps: impl IntoIterator<Item = ScriptStep>) -> Self {
+        Self {
+            steps: steps.into_iter().collect(),
+        }
+    }
+
+    /// Creates a script of key presses without modifiers.
+    ///
+    /// # Arguments
+    /// * `codes` - The keys to press, in order.
+    pub fn from_keys(codes: impl IntoIterator<Item = KeyCode>) -> Self {
+        Self::new(
+            codes
+                .into_iter()
+                .map(|code| ScriptStep::Key(KeyEvent::new(code, KeyModifiers::NONE))),
+        )
+    }
+
+    /// Creates a script that types each character of `text` as a key press.
+    ///
+    /// # Arguments
+    /// * `text` - The characters to type, e.g. `"nny"`.
+    pub fn from_text(text: &str) -> Self {
+        Self::from_keys(text.chars().map(KeyCode::Char))
+    }
+
+    /// Appends a step to the end of the script.
+    pub fn push(&mut self, step: ScriptStep) {
+        self.steps.push_back(step);
+    }
+
+    /// Returns the number of steps not yet replayed.
+    pub fn remaining(&self) -> usize {
+        self.steps.len()
+    }
+}
+
+impl InputSource for ScriptedInput {
+    fn next_key(&mut self, timeout: Option<Duration>) -> io::Result<Option<KeyEvent>> {
+        while let Some(step) = self.steps.pop_front() {
+            match step {
+                ScriptStep::Key(key) => return Ok(Some(key)),
+                ScriptStep::Tick if timeout.is_some() => return Ok(None),
+                ScriptStep::Tick => { /* Nothing times out without a timeout. */ }
+            }
+        }
+        Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "the input script ran out",
+        ))
+    }
+}
@@ -13,21 +13,55 @@
 //! - `game`: Contains the core game logic, including the `Game` struct and its entities (`Player`, `Blast`, `Alien`).
 //! - `util`: Provides utility functions and global constants used throughout the game.
 //! - `display`: Handles all aspects of rendering game elements and screens to the terminal.
+//! - `profile`: Persists lifetime statistics and achievements across sessions.
+//! - `replay`: Records games as event logs and plays them back.
+//! - `scene`: Runs the interactive screens as a stack of scenes driven by a scene manager.
+//! - `scores`: Maintains the persistent high score table.
+//! - `sim`: Plays games headlessly with an autopilot.
+//! - `cli`: Parses the command line of the `asciiliens` binary.
+//! - `config`: Holds the player's settings and persists them to a config file.
+//! - `input`: Abstracts where key presses come from, so screen flows can be scripted in tests.
+//! - `net`: Plays games between two players over TCP, in lockstep.
+//! - `daily`: Derives the daily challenge from the date and keeps its leaderboard.
 
 // Declare modules to be part of this crate.
 // These `mod` declarations make the sub-modules available to the rest of the crate.
+pub mod cli;
+pub mod config;
+pub mod daily;
 pub mod display;
 pub mod game;
+pub mod input;
+pub mod net;
+pub mod profile;
+pub mod replay;
+pub mod scene;
+pub mod scores;
+pub mod sim;
 pub mod util;
 
 // Re-export key types and functions from sub-modules for easier access
 // by the `main.rs` binary crate and other parts of the library.
-/// Re-exports `show_intro_screen` and `show_game_end_screen` functions
-/// from the `display` module for convenient access.
-pub use display::{show_game_end_screen, show_intro_screen};
+/// Re-exports the `Settings` struct from the `config` module for convenient access.
+pub use config::Settings;
+/// Re-exports the screen drawing functions and `results_table` from the
+/// `display` module for convenient access.
+pub use display::{
+    draw_game_end_screen, draw_high_scores_screen, draw_intro_screen, draw_profile_screen,
+    results_table,
+};
+/// Re-exports the `ScoringRules` struct from the `game::scoring` module for convenient access.
+pub use game::scoring::ScoringRules;
+/// Re-exports the `SessionStats` struct from the `game::stats` module, so headless
+/// users of the library can read the statistics of a finished game.
+pub use game::stats::SessionStats;
 /// Re-exports the `Game` struct, `GameEvent` enum, and `GameState` enum
 /// from the `game` module for convenient access.
 pub use game::{Game, GameEvent, GameState};
+/// Re-exports the `Profile` struct and `Achievement` enum from the `profile` module.
+pub use profile::{achievements::Achievement, Profile};
+/// Re-exports the `Scene` trait, `SceneContext` and `SceneManager` from the `scene` module.
+pub use scene::{Scene, SceneContext, SceneManager, Transition};
 /// Re-exports `GAME_HEIGHT` and `GAME_WIDTH` constants from `util::constants`
 /// for convenient access.
 pub use util::constants::{GAME_HEIGHT, GAME_WIDTH};
@@ -37,13 +71,47 @@ pub use util::constants::{GAME_HEIGHT, GAME_WIDTH};
 #[cfg(test)]
 mod tests {
     // Import necessary items from the parent crate and local modules for testing.
-    use crate::game::alien::Alien;
+    use crate::cli::{
+        parse_args, parse_server_args, Command, PlayArgs, PlayMode, ServerArgs, ServerCommand,
+    };
+    use crate::config::keys::KeyAction;
+    use crate::config::Settings;
+    use crate::daily::{DailyChallenge, DailyEntry, DailyLeaderboard};
+    use crate::display::theme::{GlyphSet, GlyphWriter, Theme};
+    use crate::game::alien::{Alien, AlienSprite};
     use crate::game::blast::Blast;
+    use crate::game::difficulty::Difficulty;
+    use crate::game::options::GameOptions;
     use crate::game::player::Player;
+    use crate::game::rules::{FireRejection, FireRule};
+    use crate::game::scoring::ScoringRules;
+    use crate::game::stats::SessionStats;
     use crate::game::{Game, GameEvent, GameState};
+    use crate::input::{InputSource, ScriptStep, ScriptedInput};
+    use crate::net::arcade::ArcadeServer;
+    use crate::net::spectator::SpectatorServer;
+    use crate::net::telnet::{TelnetDecoder, TelnetEvent, NEGOTIATION};
+    use crate::net::{self, Connection, Lockstep, Message, NetError, NetMode, Side};
+    use crate::profile::achievements::Achievement;
+    use crate::profile::Profile;
+    use crate::replay::ghost::Ghost;
+    use crate::replay::Replay;
+    use crate::scene::game::GameScene;
+    use crate::scene::hot_seat::HotSeatMatch;
+    use crate::scene::sandbox::SandboxScene;
+    use crate::scene::title::TitleScene;
+    use crate::scene::{Scene, SceneContext, SceneManager, Transition};
+    use crate::scores::{HighScoreEntry, HighScores, MAX_HIGH_SCORES};
     use crate::util::constants::{
-        ALIEN_DESIGNS, ALIEN_HEIGHT, ALIEN_MOVE_DOWN_FREQ, GAME_WIDTH, INITIAL_SCORE, PLAYER_WIDTH,
+        ALIEN_DESIGNS, ALIEN_HEIGHT, ALIEN_MOVE_DOWN_FREQ, ALIEN_POSES, ALIEN_WIDTH,
+        ARCADE_FULL_MESSAGE, DEATH_FLASH_ART, DEATH_FLASH_FRAMES, DEBRIS_PARTICLES, GAME_HEIGHT,
+        GAME_WIDTH, HOT_SEAT_ROUNDS, INITIAL_SCORE, NET_DEFAULT_PORT, PLAYER_SHIP_ART,
+        PLAYER_TWO_SHIP_ART, PLAYER_WIDTH, PLAY_AGAIN_PROMPT, READY_PROMPT, SCORE_POPUP_FRAMES,
+        TAUNT_PHRASES, TITLE_BLINK_FRAMES,
     };
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::path::PathBuf;
+    use std::time::Duration;
     // Specifically import `StepRng` for predictable random number generation in tests.
     use rand::rngs::mock::StepRng;
     // The `Rng` trait is implicitly used by `StepRng` methods, so an explicit `use rand::Rng;` is not required here.
@@ -162,6 +230,59 @@ mod tests {
         assert_eq!(alien.y(), 6);
     }
 
+    /// Tests that an alien alternates between its poses each time it steps,
+    /// and keeps its pose when blocked by the edge of the screen.
+    #[test]
+    fn test_alien_poses_alternate_on_each_step() {
+        let rows = |pose: &[char]| -> Vec<String> {
+            pose.chunks(ALIEN_WIDTH as usize)
+                .map(|row| row.iter().collect())
+                .collect()
+        };
+        let mut alien = Alien::new_for_test(0, 5, true, ALIEN_DESIGNS[1], 0);
+        assert_eq!(alien.pose(), 0);
+        assert_eq!(alien.display_strings(), rows(&ALIEN_DESIGNS[1]));
+
+        alien.move_down(); // A formation step.
+        assert_eq!(alien.pose(), 1);
+        assert_eq!(alien.display_strings(), rows(ALIEN_POSES[1][1]));
+
+        alien.move_left(); // Blocked by the left edge.
+        assert_eq!(alien.pose(), 1);
+        alien.move_right();
+        assert_eq!(alien.pose(), 0, "The poses should wrap around.");
+    }
+
+    /// Tests that aliens may be larger than the standard sprites: they collide,
+    /// stop at the screen edge, animate and explode across their whole size.
+    #[test]
+    fn test_alien_custom_sprite_size() {
+        static BIG_POSES: [&[char]; 2] = [
+            &['/', 'o', '\\', '|', '-', '|'],
+            &['\\', 'o', '/', '/', '-', '\\'],
+        ];
+        let sprite = AlienSprite::new(3, 2, &BIG_POSES);
+        let mut alien = Alien::with_sprite(GAME_WIDTH - 4, 5, 0, sprite);
+        assert_eq!((alien.width(), alien.height()), (3, 2));
+        assert_eq!(alien.display_strings(), vec!["/o\\", "|-|"]);
+
+        // The rightmost column and the bottom row are part of the alien.
+        assert!(alien.collides_with_blast(&Blast::new(GAME_WIDTH - 2, 6)));
+        assert!(!alien.collides_with_blast(&Blast::new(GAME_WIDTH - 1, 6)));
+
+        alien.move_right();
+        assert_eq!(alien.x(), GAME_WIDTH - 3);
+        assert_eq!(alien.display_strings(), vec!["\\o/", "/-\\"]);
+        alien.move_right(); // Blocked by the right edge.
+        assert_eq!(alien.x(), GAME_WIDTH - 3);
+
+        // The first stage explodes a quarter of the characters, rounded up.
+        alien.set_explosion_frame(1);
+        assert_eq!(alien.display_strings(), vec!["**/", "/-\\"]);
+        alien.set_explosion_frame(4);
+        assert_eq!(alien.display_strings(), vec!["***", "***"]);
+    }
+
     /// Tests various scenarios for blast-alien collision detection.
     #[test]
     fn test_alien_collides_with_blast() {
@@ -327,6 +448,60 @@ mod tests {
         );
     }
 
+    /// Tests that the single-shot rule rejects a second blast while the first is in flight,
+    /// without deducting a point for the rejected attempt.
+    #[test]
+    fn test_game_fire_rule_single_shot() {
+        let mut game = new_test_game();
+        game.set_fire_rule(FireRule::SingleShot);
+
+        game.update(GameEvent::Fire);
+        assert_eq!(game.blasts().len(), 1);
+        assert_eq!(game.fire_rejection(), None);
+        let score_after_first_shot = game.score();
+
+        game.update(GameEvent::Fire);
+        assert_eq!(game.blasts().len(), 1, "Second blast should be rejected.");
+        assert_eq!(game.fire_rejection(), Some(FireRejection::LimitReached));
+        assert_eq!(
+            game.score(),
+            score_after_first_shot,
+            "A rejected fire attempt should not deduct a point."
+        );
+
+        // The rejection only applies to the frame in which it happened.
+        game.update(GameEvent::AdvanceFrame);
+        assert_eq!(game.fire_rejection(), None);
+    }
+
+    /// Tests that the max-on-screen rule allows exactly N blasts in flight.
+    #[test]
+    fn test_game_fire_rule_max_on_screen() {
+        let mut game = new_test_game();
+        game.set_fire_rule(FireRule::MaxOnScreen(3));
+
+        for _ in 0..4 {
+            game.update(GameEvent::Fire);
+        }
+        assert_eq!(game.blasts().len(), 3);
+        assert_eq!(game.fire_rejection(), Some(FireRejection::LimitReached));
+    }
+
+    /// Tests that the cooldown rule rejects fire attempts until enough frames have passed.
+    #[test]
+    fn test_game_fire_rule_cooldown() {
+        let mut game = new_test_game();
+        game.set_fire_rule(FireRule::Cooldown(3));
+
+        game.update(GameEvent::Fire); // Frame 1: fires.
+        game.update(GameEvent::Fire); // Frame 2: one frame elapsed, two remaining.
+        assert_eq!(game.fire_rejection(), Some(FireRejection::Recharging(2)));
+        game.update(GameEvent::AdvanceFrame); // Frame 3.
+        game.update(GameEvent::Fire); // Frame 4: three frames elapsed, fires.
+        assert_eq!(game.fire_rejection(), None);
+        assert_eq!(game.blasts().len(), 2);
+    }
+
     /// Tests that moving the player ship correctly deducts score.
     #[test]
     fn test_game_movement_score_deduction() {
@@ -402,6 +577,92 @@ mod tests {
         );
     }
 
+    /// Tests that a fast blast hits a descending alien even though the two
+    /// swap rows during the frame and never share a cell at the end of it.
+    #[test]
+    fn test_game_handle_collisions_swept_descending_alien() {
+        let mut game = new_test_game();
+        game.aliens_mut().clear(); // Clear initial aliens to control the test.
+        game.blasts_mut().clear(); // Clear initial blasts.
+
+        // The alien descends from rows 5-6 to rows 6-7 during the frame.
+        let mut alien = Alien::new_for_test(10, 5, true, ALIEN_DESIGNS[0], 0);
+        alien.begin_frame();
+        alien.move_down();
+        game.aliens_mut().push(alien);
+
+        // The blast travels four rows, from row 9 to row 5, passing through the alien.
+        let mut blast = Blast::with_speed(10, 9, 4);
+        blast.move_up();
+        assert!(
+            !game.aliens()[0].collides_with_blast(&blast),
+            "Final positions alone should not overlap."
+        );
+        game.blasts_mut().push(blast);
+
+        game.handle_collisions(); // Directly call the internal method.
+
+        assert_eq!(
+            game.aliens()[0].explosion_frame(),
+            1,
+            "Alien should be hit by the blast that swept through it."
+        );
+        assert!(
+            game.blasts().is_empty(),
+            "Blast should be removed after hitting."
+        );
+    }
+
+    /// Tests that an alien stepping sideways into a blast's column is hit,
+    /// rather than the blast slipping past once the alien has moved.
+    #[test]
+    fn test_game_update_alien_steps_into_blast() {
+        let mut game = new_test_game();
+        game.aliens_mut().clear(); // Clear initial aliens to control the test.
+        game.blasts_mut().clear(); // Clear initial blasts.
+
+        // The only alien sits right of the player, so this frame it steps left to column 44.
+        game.aliens_mut()
+            .push(Alien::new_for_test(45, 5, true, ALIEN_DESIGNS[0], 0));
+        // The blast rises from row 6 into row 5 of column 44 in the same frame.
+        game.blasts_mut().push(Blast::new(44, 6));
+
+        game.update(GameEvent::AdvanceFrame);
+
+        assert_eq!(game.aliens()[0].x(), 44, "Alien should have stepped left.");
+        assert!(
+            game.aliens()[0].explosion_frame() > 0,
+            "Alien should be hit by the blast it stepped into."
+        );
+        assert!(game.blasts().is_empty());
+    }
+
+    /// Tests that a blast whose path crosses two aliens only hits the lower one,
+    /// since that is the one it reaches first.
+    #[test]
+    fn test_game_handle_collisions_hits_lowest_alien_on_path() {
+        let mut game = new_test_game();
+        game.aliens_mut().clear(); // Clear initial aliens to control the test.
+        game.blasts_mut().clear(); // Clear initial blasts.
+
+        game.aliens_mut()
+            .push(Alien::new_for_test(10, 3, true, ALIEN_DESIGNS[0], 0));
+        game.aliens_mut()
+            .push(Alien::new_for_test(10, 6, true, ALIEN_DESIGNS[0], 0));
+        let mut blast = Blast::with_speed(10, 9, 6);
+        blast.move_up();
+        game.blasts_mut().push(blast);
+
+        game.handle_collisions(); // Directly call the internal method.
+
+        assert_eq!(
+            game.aliens()[0].explosion_frame(),
+            0,
+            "Upper alien is shielded."
+        );
+        assert_eq!(game.aliens()[1].explosion_frame(), 1, "Lower alien is hit.");
+    }
+
     /// Tests that the explosion animation progresses correctly through its stages
     /// and that score is awarded upon completion.
     #[test]
@@ -448,6 +709,2386 @@ mod tests {
             initial_score + 250,
             "Score should increase by 250 after explosion completes."
         );
+        assert_eq!(game.stats().kills, 1, "The kill counts once it completes.");
+    }
+
+    /// Tests that the default scoring rules generate intro text covering every
+    /// non-zero rule, including the fire penalty, within the screen width.
+    #[test]
+    fn test_scoring_rules_describe_default() {
+        let lines = ScoringRules::default().describe();
+        assert_eq!(lines[0], "Scoring:");
+        assert!(lines.iter().any(|l| l.contains("Start with 100 points")));
+        assert!(lines
+            .iter()
+            .any(|l| l.contains("-1 point for each blast fired")));
+        assert!(lines.iter().any(|l| l.contains("+250 points")));
+        assert!(
+            !lines
+                .iter()
+                .any(|l| l.contains("streak") || l.contains("Wave clear")),
+            "Disabled bonuses should not be described."
+        );
+        assert!(lines
+            .iter()
+            .all(|l| l.chars().count() <= GAME_WIDTH as usize));
+    }
+
+    /// Tests that custom scoring rules drive movement, fire and kill scoring in the game.
+    #[test]
+    fn test_game_custom_scoring_rules() {
+        let rules = ScoringRules {
+            initial_score: 500,
+            move_cost: 5,
+            fire_cost: 7,
+            kill_rewards: [100, 200, 300, 400],
+            ..ScoringRules::default()
+        };
+        let mut game = Game::with_scoring_rules(rules);
+        assert_eq!(game.score(), 500);
+
+        game.update(GameEvent::MoveLeft);
+        assert_eq!(game.score(), 495);
+        game.update(GameEvent::Fire);
+        assert_eq!(game.score(), 488);
+
+        // A kind-2 alien finishing its explosion awards that kind's reward.
+        game.aliens_mut().clear();
+        game.aliens_mut()
+            .push(Alien::new_for_test(10, 5, true, ALIEN_DESIGNS[2], 4));
+        game.update_explosions();
+        assert_eq!(game.score(), 488 + 300);
+    }
+
+    /// Tests that consecutive hits earn the streak bonus, capped at the maximum.
+    #[test]
+    fn test_scoring_rules_streak_bonus() {
+        let rules = ScoringRules {
+            streak_bonus_percent: 20,
+            max_streak_bonus_percent: 50,
+            ..ScoringRules::default()
+        };
+        assert_eq!(rules.kill_reward(0, 1), 250);
+        assert_eq!(rules.kill_reward(0, 2), 300);
+        assert_eq!(rules.kill_reward(0, 3), 350);
+        assert_eq!(rules.kill_reward(0, 10), 375, "Bonus is capped at +50%.");
+        assert!(rules.describe().iter().any(|l| l.contains("streak")));
+    }
+
+    /// Tests that a fast blast reaching the top edge still hits an alien on
+    /// the rows it flew through that frame, without counting as a miss.
+    #[test]
+    fn test_game_fast_blast_hits_alien_near_top() {
+        let mut game = new_test_game();
+        game.aliens_mut().clear();
+        game.aliens_mut()
+            .push(Alien::new_for_test(10, 1, true, ALIEN_DESIGNS[0], 0));
+        game.aliens_mut()
+            .push(Alien::new_for_test(40, 10, true, ALIEN_DESIGNS[0], 0));
+        game.set_descent_frequency(1000); // Keep the aliens on their rows.
+        game.blasts_mut().push(Blast::with_speed(10, 2, 3));
+        game.update(GameEvent::AdvanceFrame);
+
+        assert!(
+            game.aliens()[0].explosion_frame() > 0,
+            "The blast flew through the alien."
+        );
+        assert_eq!(game.stats().hits, 1);
+        assert_eq!(game.streak(), 1, "A hit is not a miss.");
+        assert!(game.blasts().is_empty());
+    }
+
+    /// Tests that a miss resets the hit streak tracked by the game.
+    #[test]
+    fn test_game_streak_resets_on_miss() {
+        let mut game = new_test_game();
+        game.aliens_mut().clear();
+        game.aliens_mut()
+            .push(Alien::new_for_test(10, 5, true, ALIEN_DESIGNS[0], 0));
+        game.blasts_mut().push(Blast::new(10, 5));
+        game.handle_collisions();
+        assert_eq!(game.streak(), 1);
+        assert_eq!(game.aliens()[0].hit_streak(), 1);
+
+        game.blasts_mut().push(Blast::new(30, 1));
+        game.update_blasts(); // The blast leaves the screen: a miss.
+        assert_eq!(game.streak(), 0);
+    }
+
+    /// Tests that clearing a wave awards the accuracy and time bonuses.
+    #[test]
+    fn test_game_wave_clear_bonus() {
+        let rules = ScoringRules {
+            accuracy_bonus: 1000,
+            time_bonus_par_frames: 100,
+            time_bonus_per_frame: 2,
+            ..ScoringRules::default()
+        };
+        // Half the shots hit, and the wave took 40 frames: 500 + 60 * 2.
+        assert_eq!(rules.wave_clear_bonus(4, 2, 40), 620);
+        assert_eq!(rules.wave_clear_bonus(0, 0, 200), 0);
+
+        let mut game = Game::with_scoring_rules(rules);
+        game.aliens_mut().clear(); // No aliens left: the wave is cleared immediately.
+        game.check_game_over_conditions();
+        assert_eq!(game.state(), GameState::Win);
+        assert_eq!(
+            game.score(),
+            INITIAL_SCORE + 200,
+            "Only the time bonus applies."
+        );
+    }
+
+    /// Tests that session stats track shots, hits, streaks, moves and frames.
+    #[test]
+    fn test_game_session_stats() {
+        let mut game = new_test_game();
+        game.aliens_mut().clear();
+        // An alien directly above the player; it is too close to the player's
+        // column to step sideways, so the blasts fired below will reach it.
+        game.aliens_mut().push(Alien::new_for_test(
+            GAME_WIDTH / 2,
+            15,
+            true,
+            ALIEN_DESIGNS[0],
+            0,
+        ));
+        game.aliens_mut()
+            .push(Alien::new_for_test(0, 3, true, ALIEN_DESIGNS[0], 0));
+
+        game.update(GameEvent::MoveLeft);
+        game.update(GameEvent::MoveRight);
+        game.update(GameEvent::Fire);
+        for _ in 0..5 {
+            game.update(GameEvent::AdvanceFrame);
+        }
+
+        let stats = *game.stats();
+        assert_eq!(stats.moves_made, 2);
+        assert_eq!(stats.shots_fired, 1);
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.longest_streak, 1);
+        assert_eq!(stats.frames_survived, 8);
+        assert_eq!(stats.accuracy_percent(), 100.0);
+        // The alien's bottom edge is at row 16 and the player at row 22: 5 empty rows.
+        assert_eq!(stats.closest_approach, Some(5));
+
+        // Quitting does not count as a survived frame.
+        game.update(GameEvent::Quit);
+        assert_eq!(game.stats().frames_survived, 8);
+    }
+
+    /// Tests that the results table lists every statistic in equally wide rows.
+    #[test]
+    fn test_results_table() {
+        let stats = SessionStats {
+            shots_fired: 4,
+            hits: 3,
+            ..SessionStats::default()
+        };
+        let table = crate::display::results_table(&stats);
+        assert_eq!(table.len(), 9, "Seven rows plus two borders.");
+        assert!(table.iter().any(|l| l.contains("75.0%")));
+        assert!(table.iter().all(|l| l.len() == table[0].len()));
+    }
+
+    /// Tests that achievements unlock once, when their condition is first met.
+    #[test]
+    fn test_profile_check_achievements() {
+        let mut profile = Profile::default();
+        let mut game = new_test_game();
+        assert!(profile.check_achievements(&game).is_empty());
+
+        // Winning without moving or firing, with the starting score intact.
+        game.aliens_mut().clear();
+        game.check_game_over_conditions();
+        let unlocked = profile.check_achievements(&game);
+        assert_eq!(unlocked, vec![Achievement::Immovable]);
+        assert!(
+            profile.check_achievements(&game).is_empty(),
+            "Achievements should only unlock once."
+        );
+    }
+
+    /// Tests that an alien counts as destroyed, for First Blood and the profile,
+    /// only once its explosion completes.
+    #[test]
+    fn test_profile_counts_completed_kills() {
+        let mut profile = Profile::default();
+        let mut game = new_test_game();
+        game.aliens_mut().truncate(1);
+        game.aliens_mut()
+            .push(Alien::new_for_test(10, 5, true, ALIEN_DESIGNS[0], 1));
+
+        game.update_explosions();
+        assert_eq!(game.stats().kills, 0);
+        assert!(!Achievement::FirstBlood.is_met_by(&game));
+
+        for _ in 0..3 {
+            game.update_explosions();
+        }
+        assert_eq!(game.stats().kills, 1);
+        assert!(Achievement::FirstBlood.is_met_by(&game));
+        game.update(GameEvent::Quit);
+        profile.record_game(&game);
+        assert_eq!(profile.aliens_destroyed, 1);
+    }
+
+    /// Tests that finished games are aggregated into the lifetime profile.
+    #[test]
+    fn test_profile_record_game() {
+        let mut profile = Profile::default();
+        let mut game = new_test_game();
+        game.aliens_mut().clear();
+        game.check_game_over_conditions();
+        profile.record_game(&game);
+
+        let mut quit_game = new_test_game();
+        quit_game.update(GameEvent::Quit);
+        profile.record_game(&quit_game);
+
+        assert_eq!(profile.games_played, 1, "Quit games are not counted.");
+        assert_eq!(profile.wins, 1);
+        assert_eq!(profile.best_accuracy, None, "No blasts, no accuracy.");
+    }
+
+    /// Tests that a profile survives a save/load round trip and that
+    /// a missing file loads as a fresh profile.
+    #[test]
+    fn test_profile_save_and_load() {
+        let dir = std::env::temp_dir().join(format!("asciiliens-profile-{}", std::process::id()));
+        let path = dir.join("profile.txt");
+        assert_eq!(Profile::load_from(&path), Profile::default());
+
+        let profile = Profile {
+            games_played: 7,
+            wins: 3,
+            aliens_destroyed: 42,
+            best_accuracy: Some(87.5),
+            achievements: vec![Achievement::PerfectAim, Achievement::FirstBlood],
+        };
+        profile.save_to(&path).expect("profile should save");
+        assert_eq!(Profile::load_from(&path), profile);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// Helper function to parse a command line given as a single string.
+    fn parse(line: &str) -> Result<Command, crate::cli::CliError> {
+        parse_args(line.split_whitespace().map(String::from))
+    }
+
+    /// Tests parsing of valid command lines.
+    #[test]
+    fn test_cli_parse_commands() {
+        assert_eq!(parse(""), Ok(Command::Play(PlayArgs::default())));
+        assert_eq!(parse("scores"), Ok(Command::Scores));
+        assert_eq!(parse("play --level 3 --help"), Ok(Command::Help));
+        assert_eq!(parse("-V"), Ok(Command::Version));
+        assert_eq!(
+            parse("replay runs/best.replay"),
+            Ok(Command::Replay("runs/best.replay".into()))
+        );
+
+        let expected = PlayArgs {
+            options: GameOptions {
+                seed: Some(42),
+                difficulty: Difficulty::Hard,
+                level: 3,
+                co_op: false,
+                fire_rule: FireRule::MaxOnScreen(3),
+            },
+            mode: PlayMode::Realtime,
+            spectate: None,
+            spectate_public: false,
+        };
+        assert_eq!(
+            parse("play --seed 42 --mode=realtime --difficulty HARD --level 3 --fire-rule MAX:3"),
+            Ok(Command::Play(expected))
+        );
+        // Options without a command are options for `play`.
+        assert_eq!(
+            parse("--seed=42 --mode realtime --difficulty hard --level=3 --fire-rule=max:3"),
+            Ok(Command::Play(expected))
+        );
+
+        assert_eq!(
+            parse("simulate --games 5 --seed 7"),
+            Ok(Command::Simulate {
+                games: 5,
+                options: GameOptions {
+                    seed: Some(7),
+                    ..GameOptions::default()
+                },
+            })
+        );
+    }
+
+    /// Tests that invalid command lines are rejected with a helpful message.
+    #[test]
+    fn test_cli_parse_errors() {
+        let error = |line: &str| parse(line).unwrap_err().0;
+        assert!(error("fly").contains("unknown command 'fly'"));
+        assert!(error("play --seed").contains("requires a value"));
+        assert!(error("play --seed abc").contains("invalid value 'abc'"));
+        assert!(error("play --mode fast").contains("unknown mode 'fast'"));
+        assert!(error("play --difficulty brutal").contains("unknown difficulty"));
+        assert!(error("play --level 0").contains("level must be between"));
+        assert!(error("play --fire-rule rapid").contains("unknown fire rule 'rapid'"));
+        assert!(error("play --fire-rule max:0").contains("unknown fire rule 'max:0'"));
+        assert!(error("play --games 3").contains("unknown option '--games'"));
+        assert!(error("replay").contains("requires a replay file"));
+        assert!(error("scores extra").contains("unexpected argument 'extra'"));
+    }
+
+    /// Tests that two games with the same seed play out identically.
+    #[test]
+    fn test_game_seed_is_deterministic() {
+        let options = GameOptions {
+            seed: Some(1234),
+            ..GameOptions::default()
+        };
+        let mut first = Game::with_options(options);
+        let mut second = Game::with_options(options);
+        assert_eq!(first.seed(), 1234);
+        for frame in 0..60 {
+            let event = if frame % 3 == 0 {
+                GameEvent::Fire
+            } else {
+                GameEvent::MoveLeft
+            };
+            first.update(event);
+            second.update(event);
+        }
+        let positions = |game: &Game| -> Vec<(u16, u16, bool)> {
+            game.aliens()
+                .iter()
+                .map(|a| (a.x(), a.y(), a.alive()))
+                .collect()
+        };
+        assert_eq!(positions(&first), positions(&second));
+        assert_eq!(first.score(), second.score());
+    }
+
+    /// Tests that the level moves the alien formation down and the difficulty sets the score.
+    #[test]
+    fn test_game_options_level_and_difficulty() {
+        let level_one = Game::with_options(GameOptions {
+            seed: Some(1),
+            ..GameOptions::default()
+        });
+        let level_four = Game::with_options(GameOptions {
+            seed: Some(1),
+            difficulty: Difficulty::Easy,
+            level: 4,
+            co_op: false,
+            fire_rule: FireRule::Unlimited,
+        });
+        assert_eq!(level_four.aliens()[0].y(), level_one.aliens()[0].y() + 3);
+        assert_eq!(
+            level_four.score(),
+            Difficulty::Easy.scoring_rules().initial_score
+        );
+    }
+
+    /// Tests that a replay survives serialization and reproduces the recorded game.
+    #[test]
+    fn test_replay_round_trip_and_play_out() {
+        let mut game = Game::with_options(GameOptions {
+            seed: Some(99),
+            difficulty: Difficulty::Nightmare,
+            level: 2,
+            co_op: false,
+            fire_rule: FireRule::SingleShot,
+        });
+        let mut replay = Replay::record(&game);
+        for event in [
+            GameEvent::Fire,
+            GameEvent::MoveLeft,
+            GameEvent::AdvanceFrame,
+            GameEvent::MoveRight,
+            GameEvent::Fire,
+            GameEvent::Quit,
+        ] {
+            game.update(event);
+            replay.events.push(event);
+        }
+
+        let parsed = Replay::parse(&replay.serialize()).expect("replay should parse");
+        assert_eq!(parsed, replay);
+        let replayed = parsed.play_out();
+        assert_eq!(replayed.score(), game.score());
+        assert_eq!(replayed.state(), GameState::Quit);
+        assert_eq!(replayed.player().x(), game.player().x());
+        assert_eq!(replayed.fire_rule(), FireRule::SingleShot);
+        assert_eq!(
+            Replay::ghost_file_name(&replay.options),
+            "99-nightmare-2-single-shot.replay"
+        );
+
+        assert!(Replay::parse("not a replay").is_err());
+        assert!(
+            Replay::parse("asciiliens-replay 1\nevents=F").is_err(),
+            "Seed is required."
+        );
+        assert!(Replay::parse("asciiliens-replay 1\nseed=1\nevents=FX").is_err());
+    }
+
+    /// Tests that the high score table keeps the best scores in order and persists.
+    #[test]
+    fn test_high_scores_add_and_persist() {
+        let entry = |score: i32| HighScoreEntry {
+            score,
+            won: score > 0,
+            difficulty: Difficulty::Normal,
+            level: 1,
+            seed: 5,
+        };
+        let mut scores = HighScores::default();
+        assert_eq!(scores.add(entry(100)), Some(1));
+        assert_eq!(scores.add(entry(300)), Some(1));
+        assert_eq!(scores.add(entry(200)), Some(2));
+        for _ in 0..MAX_HIGH_SCORES {
+            scores.add(entry(500));
+        }
+        assert_eq!(scores.entries().len(), MAX_HIGH_SCORES);
+        assert_eq!(scores.add(entry(1)), None, "Too low to make the table.");
+
+        let dir = std::env::temp_dir().join(format!("asciiliens-scores-{}", std::process::id()));
+        let path = dir.join("highscores.txt");
+        scores.save_to(&path).expect("high scores should save");
+        assert_eq!(HighScores::load_from(&path), scores);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// Tests that a seeded simulation runs every game to completion reproducibly.
+    #[test]
+    fn test_simulate_is_reproducible() {
+        let options = GameOptions {
+            seed: Some(2024),
+            ..GameOptions::default()
+        };
+        let first = crate::sim::simulate(3, options);
+        assert_eq!(first.games, 3);
+        assert_eq!(first, crate::sim::simulate(3, options));
+        assert!(first.shots_fired > 0, "The autopilot should fire.");
+    }
+
+    /// Tests that cycling the difficulty visits every preset before wrapping around.
+    #[test]
+    fn test_difficulty_next_cycles_all_presets() {
+        let mut difficulty = Difficulty::default();
+        let mut seen = Vec::new();
+        for _ in 0..Difficulty::ALL.len() {
+            seen.push(difficulty);
+            difficulty = difficulty.next();
+        }
+        assert_eq!(
+            difficulty,
+            Difficulty::default(),
+            "The cycle should wrap around."
+        );
+        for preset in Difficulty::ALL {
+            assert!(seen.contains(&preset), "{} should be reachable.", preset);
+        }
+    }
+
+    /// Tests that harder presets move more aliens towards the player each frame.
+    #[test]
+    fn test_difficulty_aliens_moving_per_frame() {
+        for difficulty in Difficulty::ALL {
+            let mut game = Game::with_options(GameOptions {
+                seed: Some(5),
+                difficulty,
+                ..GameOptions::default()
+            });
+            // Line up aliens at the left edge, far from the centred player.
+            *game.aliens_mut() = (0..4)
+                .map(|row| Alien::new_for_test(0, row * ALIEN_HEIGHT, true, ALIEN_DESIGNS[0], 0))
+                .collect();
+            game.update(GameEvent::AdvanceFrame);
+            let moved = game.aliens().iter().filter(|alien| alien.x() > 0).count();
+            assert_eq!(
+                moved,
+                difficulty.aliens_moving_per_frame(),
+                "{}",
+                difficulty
+            );
+        }
+    }
+
+    /// Tests that the formation descends at the rate set by the difficulty.
+    #[test]
+    fn test_difficulty_descent_frequency() {
+        let advance = |difficulty: Difficulty, frames: u64| {
+            let mut game = Game::with_options(GameOptions {
+                seed: Some(5),
+                difficulty,
+                ..GameOptions::default()
+            });
+            *game.aliens_mut() = vec![Alien::new_for_test(0, 0, true, ALIEN_DESIGNS[0], 0)];
+            for _ in 0..frames {
+                game.update(GameEvent::AdvanceFrame);
+            }
+            game.aliens()[0].y()
+        };
+        assert_eq!(
+            advance(Difficulty::Nightmare, 5),
+            1,
+            "Nightmare descends every 5 frames."
+        );
+        assert_eq!(
+            advance(Difficulty::Easy, 10),
+            0,
+            "Easy has not descended after 10 frames."
+        );
+        assert_eq!(
+            advance(Difficulty::Easy, 14),
+            1,
+            "Easy descends every 14 frames."
+        );
+    }
+
+    /// Tests that only Ctrl-C is treated as an interrupt key press.
+    #[test]
+    fn test_is_interrupt_detects_ctrl_c() {
+        use crate::display::terminal::is_interrupt;
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert!(is_interrupt(&ctrl_c));
+        let plain_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE);
+        assert!(!is_interrupt(&plain_c), "A plain 'c' is not an interrupt.");
+        let ctrl_d = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL);
+        assert!(!is_interrupt(&ctrl_d), "Only Ctrl-C interrupts.");
+    }
+
+    /// Tests that restoring the terminal is a no-op when no session was entered.
+    #[test]
+    fn test_terminal_restore_without_session_is_noop() {
+        // Must not panic or touch terminal modes when nothing was changed.
+        crate::display::terminal::restore();
+        crate::display::terminal::restore();
+    }
+
+    /// Creates a key press without modifiers, for driving scenes in tests.
+    fn key(code: crossterm::event::KeyCode) -> crossterm::event::KeyEvent {
+        crossterm::event::KeyEvent::new(code, crossterm::event::KeyModifiers::NONE)
+    }
+
+    /// Creates a scene manager on the title screen that persists nothing.
+    fn new_test_manager() -> SceneManager {
+        let args = PlayArgs {
+            options: GameOptions {
+                seed: Some(99),
+                ..GameOptions::default()
+            },
+            mode: PlayMode::Turn,
+            spectate: None,
+            spectate_public: false,
+        };
+        SceneManager::new(
+            Box::new(TitleScene::new()),
+            SceneContext::new(args, Settings::default()),
+        )
+    }
+
+    /// Tests that menu screens open on top of the title screen and return to it.
+    #[test]
+    fn test_scene_manager_menu_screens_return_to_title() {
+        use crossterm::event::KeyCode;
+
+        let mut manager = new_test_manager();
+        for (menu_key, scene_name) in [('p', "profile"), ('h', "high_scores"), ('o', "options")] {
+            manager.handle_key(key(KeyCode::Char(menu_key)));
+            assert_eq!(manager.active_scene_name(), Some(scene_name));
+            manager.handle_key(key(KeyCode::Esc));
+            assert_eq!(manager.active_scene_name(), Some("title"));
+        }
+        manager.handle_key(key(KeyCode::Char('d')));
+        assert_eq!(manager.context().settings.difficulty, Difficulty::Hard);
+    }
+
+    /// Tests that the options screen changes the difficulty and play mode in the shared context.
+    #[test]
+    fn test_scene_manager_options_change_settings() {
+        use crossterm::event::KeyCode;
+
+        let mut manager = new_test_manager();
+        manager.handle_key(key(KeyCode::Char('o')));
+        manager.handle_key(key(KeyCode::Enter)); // Play mode: Turn -> Realtime.
+        manager.handle_key(key(KeyCode::Down));
+        manager.handle_key(key(KeyCode::Right)); // Difficulty: Normal -> Hard.
+        assert_eq!(manager.context().settings.difficulty, Difficulty::Hard);
+        assert_eq!(manager.context().settings.mode, PlayMode::Realtime);
+    }
+
+    /// Tests that no key is bound to both ships, whether it is rebound on the
+    /// options screen or in the config file, and that the second ship's keys
+    /// are listed on the scrolling options screen.
+    #[test]
+    fn test_co_op_keys_never_conflict() {
+        use crossterm::event::KeyCode;
+
+        let settings = Settings::parse("[keys]\nfire = \"w\"\n[co_op_keys]\nleft = \"Left\"\n");
+        assert_eq!(settings.keys.key(KeyAction::Fire), KeyCode::Char('w'));
+        assert_eq!(settings.co_op_keys.key(KeyAction::Fire), KeyCode::Char(' '));
+        assert_eq!(settings.co_op_keys.key(KeyAction::MoveLeft), KeyCode::Left);
+        assert_eq!(settings.keys.key(KeyAction::MoveLeft), KeyCode::Char('a'));
+        assert_eq!(Settings::parse(&settings.serialize()), settings);
+
+        let mut manager = new_test_manager();
+        manager.handle_key(key(KeyCode::Char('o')));
+        for _ in 0..18 {
+            manager.handle_key(key(KeyCode::Down));
+        }
+        let mut screen = Vec::new();
+        manager.draw(&mut screen).unwrap();
+        let screen = String::from_utf8_lossy(&screen);
+        assert!(screen.contains("> P2 key: fire"));
+        assert!(screen.contains("(more above)"));
+        assert!(!screen.contains("Play mode"), "The list scrolls.");
+
+        manager.handle_key(key(KeyCode::Enter));
+        manager.handle_key(key(KeyCode::Char(' ')));
+        let settings = &manager.context().settings;
+        assert_eq!(settings.co_op_keys.key(KeyAction::Fire), KeyCode::Char(' '));
+        assert_eq!(settings.keys.key(KeyAction::Fire), KeyCode::Char('w'));
+    }
+
+    /// Tests a full run: title, gameplay, pause, quitting from the pause screen, and results.
+    #[test]
+    fn test_scene_manager_game_flow() {
+        use crossterm::event::KeyCode;
+
+        let mut manager = new_test_manager();
+        manager.handle_key(key(KeyCode::Char('y')));
+        assert_eq!(manager.active_scene_name(), Some("game"));
+
+        manager.handle_key(key(KeyCode::Char('p')));
+        assert_eq!(manager.active_scene_name(), Some("pause"));
+        let mut screen = Vec::new();
+        manager
+            .draw(&mut screen)
+            .expect("drawing to memory should succeed");
+        let screen = String::from_utf8_lossy(&screen);
+        assert!(
+            screen.contains("PAUSED"),
+            "The pause banner should be drawn."
+        );
+        assert!(
+            screen.contains("Score:"),
+            "The paused game should be drawn beneath it."
+        );
+
+        // Quitting from the pause screen ends the game and shows the results.
+        manager.handle_key(key(KeyCode::Char('q')));
+        assert_eq!(manager.active_scene_name(), Some("results"));
+        assert_eq!(
+            manager.context().profile.games_played,
+            0,
+            "A quit game does not count as played."
+        );
+
+        manager.handle_key(key(KeyCode::Char('y')));
+        assert_eq!(manager.active_scene_name(), Some("title"));
+        manager.handle_key(key(KeyCode::Char('y')));
+        manager.handle_key(key(KeyCode::Esc));
+        manager.handle_key(key(KeyCode::Char('n')));
+        assert!(
+            manager.is_finished(),
+            "Declining to play again ends the run."
+        );
+    }
+
+    /// Tests that settings survive a save and load through the config file.
+    #[test]
+    fn test_settings_round_trip() {
+        let mut settings = Settings {
+            mode: PlayMode::Realtime,
+            difficulty: Difficulty::Nightmare,
+            fire_rule: FireRule::MaxOnScreen(5),
+            theme: Theme::Amber,
+            glyphs: GlyphSet::Ascii,
+            sound: true,
+            reduced_motion: true,
+            starfield: true,
+            ghost: true,
+            preview: true,
+            ..Settings::default()
+        };
+        settings
+            .keys
+            .bind(KeyAction::Fire, crossterm::event::KeyCode::Up);
+        assert_eq!(Settings::parse(&settings.serialize()), settings);
+
+        let dir = std::env::temp_dir().join(format!("asciiliens-config-{}", std::process::id()));
+        let path = dir.join("config.toml");
+        settings.save_to(&path).expect("settings should save");
+        assert_eq!(Settings::load_from(&path), settings);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// Tests that missing, unknown and invalid config entries fall back to the defaults.
+    #[test]
+    fn test_settings_invalid_values_use_defaults() {
+        let missing = std::env::temp_dir().join("asciiliens-no-such-dir/config.toml");
+        assert_eq!(Settings::load_from(&missing), Settings::default());
+
+        let settings = Settings::parse(
+            "theme = \"plaid\"\nsound = maybe\nvolume = 11\ndifficulty = \"hard\"\n[keys]\nfire = \"F13\"\n",
+        );
+        assert_eq!(
+            settings,
+            Settings {
+                difficulty: Difficulty::Hard,
+                ..Settings::default()
+            },
+            "Only the valid difficulty should be applied."
+        );
+    }
+
+    /// Tests that binding a key already in use swaps it with the other action.
+    #[test]
+    fn test_key_bindings_swap_on_conflict() {
+        use crossterm::event::KeyCode;
+
+        let mut settings = Settings::default();
+        settings.keys.bind(KeyAction::Fire, KeyCode::Char('Q'));
+        assert_eq!(settings.keys.key(KeyAction::Fire), KeyCode::Char('q'));
+        assert_eq!(settings.keys.key(KeyAction::Quit), KeyCode::Char(' '));
+        assert_eq!(
+            settings.keys.action_for(KeyCode::Char('Q')),
+            Some(KeyAction::Fire),
+            "Letters should match in either case."
+        );
+    }
+
+    /// Tests that command-line flags override the saved play settings.
+    #[test]
+    fn test_cli_flags_override_saved_settings() {
+        let defaults = Settings {
+            mode: PlayMode::Realtime,
+            difficulty: Difficulty::Hard,
+            ..Settings::default()
+        }
+        .play_args();
+        let parse_with = |line: &str| {
+            crate::cli::parse_args_with_defaults(
+                line.split_whitespace().map(String::from),
+                defaults,
+            )
+        };
+        assert_eq!(parse_with(""), Ok(Command::Play(defaults)));
+        let Ok(Command::Play(args)) = parse_with("play --difficulty normal") else {
+            panic!("play should parse");
+        };
+        assert_eq!(args.options.difficulty, Difficulty::Normal);
+        assert_eq!(
+            args.mode,
+            PlayMode::Realtime,
+            "Unset flags keep the saved value."
+        );
+        let Ok(Command::Host { options, .. }) = parse_with("host --level 2") else {
+            panic!("host should parse");
+        };
+        assert_eq!(options.difficulty, Difficulty::Hard, "Hosts keep it too.");
+        assert_eq!(options.level, 2);
+    }
+
+    /// Tests that the ASCII glyph set converts extended characters, even when split across writes.
+    #[test]
+    fn test_glyph_writer_converts_to_ascii() {
+        let mut output = Vec::new();
+        {
+            let mut writer = GlyphWriter::new(&mut output, GlyphSet::Ascii);
+            let text = "╔═╗ ║_||_║ \u{1b}[0m".as_bytes();
+            let (first, second) = text.split_at(1); // Split inside the first character.
+            std::io::Write::write_all(&mut writer, first).unwrap();
+            std::io::Write::write_all(&mut writer, second).unwrap();
+        }
+        assert_eq!(String::from_utf8(output).unwrap(), "+=+ |_||_| \u{1b}[0m");
+    }
+
+    /// Tests that a scripted input source replays its keys in order, skips
+    /// ticks when nothing is waiting on a timeout, and fails once exhausted.
+    #[test]
+    fn test_scripted_input_replays_script() {
+        let mut input = ScriptedInput::from_text("ab");
+        input.push(ScriptStep::Tick);
+        input.push(ScriptStep::Key(key(KeyCode::Enter)));
+        let timeout = Some(std::time::Duration::from_millis(10));
+
+        assert_eq!(input.next_key(None).unwrap(), Some(key(KeyCode::Char('a'))));
+        assert_eq!(
+            input.next_key(timeout).unwrap(),
+            Some(key(KeyCode::Char('b')))
+        );
+        assert_eq!(input.remaining(), 2);
+        // Without a timeout the tick is skipped and the next key is returned.
+        assert_eq!(input.next_key(None).unwrap(), Some(key(KeyCode::Enter)));
+        let error = input.next_key(timeout).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    /// Tests the intro taunt cycle end to end: each 'n' shows the next taunt,
+    /// wrapping around `TAUNT_PHRASES`, and nothing is printed before the first 'n'.
+    #[test]
+    fn test_intro_taunt_cycle_is_scripted() {
+        let mut manager = new_test_manager();
+        let mut out = Vec::new();
+        let presses = TAUNT_PHRASES.len() + 1;
+        let mut script = ScriptedInput::from_keys(vec![KeyCode::Char('n'); presses]);
+        script.push(ScriptStep::Key(KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL,
+        )));
+        manager.run_with(&mut out, &mut script).unwrap();
+        assert!(manager.is_finished());
+        let output = String::from_utf8_lossy(&out);
+
+        // Taunts appear in order, starting from the second phrase and wrapping around.
+        let mut position = 0;
+        let first_frame_end = output.find(TAUNT_PHRASES[1]).unwrap();
+        assert!(TAUNT_PHRASES
+            .iter()
+            .all(|phrase| !output[..first_frame_end].contains(phrase)));
+        for press in 1..=presses {
+            let phrase = TAUNT_PHRASES[press % TAUNT_PHRASES.len()];
+            let found = output[position..]
+                .find(phrase)
+                .unwrap_or_else(|| panic!("taunt {} was not shown after press {}", phrase, press));
+            position += found + phrase.len();
+        }
+    }
+
+    /// Tests the play-again prompt end to end: 'y' returns to the title for
+    /// another game, and 'n' ends the run.
+    #[test]
+    fn test_play_again_prompt_is_scripted() {
+        let mut manager = new_test_manager();
+        let mut out = Vec::new();
+        // Start a game, quit it, play again, quit the second game, then decline.
+        let mut script = ScriptedInput::from_keys([
+            KeyCode::Char('y'),
+            KeyCode::Esc,
+            KeyCode::Char('y'),
+            KeyCode::Char('y'),
+            KeyCode::Esc,
+        ]);
+        manager.run_with(&mut out, &mut script).unwrap_err();
+        assert_eq!(manager.active_scene_name(), Some("results"));
+        let output = String::from_utf8_lossy(&out);
+        assert_eq!(output.matches(PLAY_AGAIN_PROMPT).count(), 2);
+
+        script.push(ScriptStep::Key(key(KeyCode::Char('n'))));
+        manager.run_with(&mut out, &mut script).unwrap();
+        assert!(manager.is_finished());
+        assert_eq!(script.remaining(), 0);
+    }
+
+    /// Tests that the title screen animates on a timer: aliens march across and
+    /// the prompt blinks, hidden for `TITLE_BLINK_FRAMES` frames at a time.
+    #[test]
+    fn test_title_screen_animates() {
+        let mut manager = new_test_manager();
+        let mut out = Vec::new();
+        let ticks = vec![ScriptStep::Tick; TITLE_BLINK_FRAMES as usize + 1];
+        let mut script = ScriptedInput::new(ticks);
+        script.push(ScriptStep::Key(KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL,
+        )));
+        manager.run_with(&mut out, &mut script).unwrap();
+        let output = String::from_utf8_lossy(&out);
+
+        // Frames 0 to TITLE_BLINK_FRAMES - 1 show the prompt; the next two hide it.
+        assert_eq!(
+            output.matches(READY_PROMPT).count(),
+            TITLE_BLINK_FRAMES as usize
+        );
+        assert!(output.contains(ALIEN_DESIGNS[0][0]));
+        assert!(output.contains(ALIEN_DESIGNS[0][2]));
+    }
+
+    /// Tests that with reduced motion the title screen is static: it does not
+    /// tick, always shows the prompt and has no parade.
+    #[test]
+    fn test_title_screen_reduced_motion_is_static() {
+        let settings = Settings {
+            reduced_motion: true,
+            ..Settings::default()
+        };
+        let args = PlayArgs {
+            options: GameOptions {
+                seed: Some(99),
+                ..GameOptions::default()
+            },
+            mode: PlayMode::Turn,
+            spectate: None,
+            spectate_public: false,
+        };
+        let mut manager = SceneManager::new(
+            Box::new(TitleScene::new()),
+            SceneContext::new(args, settings),
+        );
+        let mut out = Vec::new();
+        // Ticks are skipped, since nothing waits with a timeout.
+        let mut script = ScriptedInput::new(vec![ScriptStep::Tick; 3]);
+        script.push(ScriptStep::Key(key(KeyCode::Char('o'))));
+        script.push(ScriptStep::Key(key(KeyCode::Esc)));
+        script.push(ScriptStep::Key(KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL,
+        )));
+        manager.run_with(&mut out, &mut script).unwrap();
+        let output = String::from_utf8_lossy(&out);
+
+        assert_eq!(output.matches(READY_PROMPT).count(), 2); // Before and after the options screen.
+        assert!(!output.contains(ALIEN_DESIGNS[0][0]));
+    }
+
+    /// Tests that the starfield is reproducible from the game's seed, scrolls
+    /// its near layer every frame and never changes how the game plays out.
+    #[test]
+    fn test_starfield_is_deterministic_and_independent() {
+        let options = GameOptions {
+            seed: Some(7),
+            ..GameOptions::default()
+        };
+        let mut plain = Game::with_options(options);
+        let mut starry = Game::with_options(options);
+        starry.set_starfield_visible(true);
+        assert_eq!(plain.starfield().stars(), starry.starfield().stars());
+
+        let before = starry.starfield().stars().to_vec();
+        for event in [
+            GameEvent::MoveLeft,
+            GameEvent::Fire,
+            GameEvent::AdvanceFrame,
+        ] {
+            plain.update(event);
+            starry.update(event);
+        }
+        assert_eq!(plain.score(), starry.score());
+        let positions = |game: &Game| -> Vec<(u16, u16, bool)> {
+            game.aliens()
+                .iter()
+                .map(|a| (a.x(), a.y(), a.alive()))
+                .collect()
+        };
+        assert_eq!(positions(&plain), positions(&starry));
+
+        // After three frames the nearest layer has moved three rows, the farthest none.
+        for (old, new) in before.iter().zip(starry.starfield().stars()) {
+            let moved = (new.y() + GAME_HEIGHT - 1 - old.y()) % (GAME_HEIGHT - 1);
+            let expected = match old.layer() {
+                0 => 0,
+                1 => 1,
+                _ => 3,
+            };
+            assert_eq!(moved, expected, "Star {:?} scrolled wrongly.", old);
+        }
+    }
+
+    /// Tests that stars are only drawn when the starfield is visible, and
+    /// before the entities, so the entities are drawn over them.
+    #[test]
+    fn test_starfield_drawn_beneath_entities() {
+        let mut game = new_test_game();
+        let mut hidden = Vec::new();
+        game.draw(&mut hidden).unwrap();
+        assert!(!String::from_utf8_lossy(&hidden).contains('·'));
+
+        game.set_starfield_visible(true);
+        let mut shown = Vec::new();
+        game.draw(&mut shown).unwrap();
+        let shown = String::from_utf8_lossy(&shown);
+        let ship = shown.find(PLAYER_SHIP_ART).unwrap();
+        let stars_before_ship = shown[..ship].matches(['.', '·']).count();
+        assert_eq!(
+            stars_before_ship,
+            game.starfield().stars().len(),
+            "Every star should be drawn before the ship."
+        );
+    }
+
+    /// Tests that a destroyed alien throws out debris and a score popup, which
+    /// move each frame and fade without changing the score.
+    #[test]
+    fn test_game_destroyed_alien_throws_debris_and_popup() {
+        let mut game = new_test_game();
+        game.aliens_mut().clear();
+        game.aliens_mut()
+            .push(Alien::new_for_test(10, 5, true, ALIEN_DESIGNS[0], 4));
+        let initial_score = game.score();
+
+        game.update_explosions(); // The explosion completes.
+        let effects = game.effects();
+        assert_eq!(effects.particles().len(), DEBRIS_PARTICLES.len());
+        assert_eq!(effects.popups().len(), 1);
+        assert_eq!(effects.popups()[0].text(), "+250");
+        assert_eq!(effects.popups()[0].position(), Some((10, 4)));
+        assert_eq!(effects.flash_frames(), 0);
+
+        game.animate_effects();
+        assert_eq!(game.effects().popups()[0].position(), Some((10, 3)));
+        let (x, y) = game.effects().particles()[0].position().unwrap();
+        let (dx, dy, _) = DEBRIS_PARTICLES[0];
+        assert_eq!((x as i16, y as i16), (11 + dx, 6 + dy));
+
+        for _ in 0..SCORE_POPUP_FRAMES {
+            game.animate_effects();
+        }
+        assert!(
+            !game.effects().is_active(),
+            "Every effect should have faded."
+        );
+        assert_eq!(game.score(), initial_score + 250);
+    }
+
+    /// Tests that a lost game flashes the player's ship, and that the flash is
+    /// drawn before the results are shown.
+    #[test]
+    fn test_game_over_flashes_before_results() {
+        let mut game = new_test_game();
+        let player_y_pos = game.player().y_pos();
+        game.aliens_mut()[0].set_y(player_y_pos - ALIEN_HEIGHT + 1);
+        game.check_game_over_conditions();
+        assert_eq!(game.effects().flash_frames(), DEATH_FLASH_FRAMES);
+        let mut screen = Vec::new();
+        game.draw(&mut screen).unwrap();
+        let screen = String::from_utf8_lossy(&screen);
+        assert!(screen.contains(DEATH_FLASH_ART[0]));
+        assert!(!screen.contains(PLAYER_SHIP_ART));
+
+        // Played through the scenes, the game is lost by never firing. The
+        // flash is drawn, then the next key skips to the results.
+        let mut manager = new_test_manager();
+        let mut out = Vec::new();
+        let mut script = ScriptedInput::from_keys([KeyCode::Char('y')]);
+        for _ in 0..400 {
+            script.push(ScriptStep::Key(key(KeyCode::Char('x'))));
+        }
+        manager.run_with(&mut out, &mut script).unwrap_err();
+        assert_eq!(manager.active_scene_name(), Some("results"));
+        let output = String::from_utf8_lossy(&out);
+        let flash = output
+            .find(DEATH_FLASH_ART[0])
+            .expect("the ship should flash");
+        let prompt = output.find(PLAY_AGAIN_PROMPT).unwrap();
+        assert!(flash < prompt);
+    }
+
+    /// Tests that with reduced motion the debris, popups and death flash still
+    /// run but are not drawn.
+    #[test]
+    fn test_reduced_motion_hides_effects() {
+        let screen = |game: &Game| {
+            let mut screen = Vec::new();
+            game.draw(&mut screen).unwrap();
+            String::from_utf8_lossy(&screen).into_owned()
+        };
+        let mut game = new_test_game();
+        game.aliens_mut()
+            .push(Alien::new_for_test(10, 5, true, ALIEN_DESIGNS[0], 4));
+        game.update_explosions();
+        assert!(screen(&game).contains("+250"));
+        game.set_reduced_motion(true);
+        assert!(game.effects().is_active());
+        assert!(!screen(&game).contains("+250"));
+
+        let player_y_pos = game.player().y_pos();
+        game.aliens_mut()[0].set_y(player_y_pos - ALIEN_HEIGHT + 1);
+        game.check_game_over_conditions();
+        assert!(game.effects().flash_frames() > 0);
+        assert!(!screen(&game).contains(DEATH_FLASH_ART[0]));
+    }
+
+    /// Tests the score keeping of a hot-seat match: players alternate, share
+    /// each round's seed, and the highest total wins.
+    #[test]
+    fn test_hot_seat_match_turns_and_winner() {
+        let options = GameOptions {
+            seed: Some(40),
+            ..GameOptions::default()
+        };
+        let mut hot_seat = HotSeatMatch::new(options, 2, 2);
+        assert_eq!((hot_seat.current_player(), hot_seat.round()), (0, 0));
+        let first_seed = hot_seat.turn_options().seed;
+
+        hot_seat.record_turn(300);
+        assert_eq!((hot_seat.current_player(), hot_seat.round()), (1, 0));
+        assert_eq!(
+            hot_seat.turn_options().seed,
+            first_seed,
+            "Both players should face the same seed in a round."
+        );
+
+        hot_seat.record_turn(500);
+        assert_eq!((hot_seat.current_player(), hot_seat.round()), (0, 1));
+        assert_ne!(hot_seat.turn_options().seed, first_seed);
+        assert_eq!(hot_seat.winner(), Some(1));
+
+        hot_seat.record_turn(400);
+        assert!(!hot_seat.is_finished());
+        hot_seat.record_turn(200);
+        assert!(hot_seat.is_finished());
+        assert_eq!(hot_seat.totals(), vec![700, 700]);
+        assert_eq!(hot_seat.winner(), None, "Equal totals should be a draw.");
+
+        hot_seat.record_turn(1000); // Ignored once the match is over.
+        assert_eq!(hot_seat.scores(), &[vec![300, 400], vec![500, 200]]);
+    }
+
+    /// Tests a whole hot-seat match end to end: every turn is announced, and
+    /// two players pressing the same keys on the same seeds draw.
+    #[test]
+    fn test_hot_seat_scene_plays_full_match() {
+        let mut manager = new_test_manager();
+        let mut out = Vec::new();
+        let mut script = ScriptedInput::from_keys([KeyCode::Char('2')]);
+        for _ in 0..3000 {
+            script.push(ScriptStep::Key(key(KeyCode::Char('x'))));
+        }
+        manager.run_with(&mut out, &mut script).unwrap_err();
+        assert_eq!(manager.active_scene_name(), Some("hot_seat"));
+        let output = String::from_utf8_lossy(&out);
+        for round in 1..=HOT_SEAT_ROUNDS {
+            assert!(output.contains(&format!("PLAYER 1 - ROUND {}/{}", round, HOT_SEAT_ROUNDS)));
+            assert!(output.contains(&format!("PLAYER 2 - ROUND {}/{}", round, HOT_SEAT_ROUNDS)));
+        }
+        assert_eq!(
+            output.matches("PLAYER 2, GET READY!").count(),
+            HOT_SEAT_ROUNDS
+        );
+        assert!(output.contains("THE MATCH IS A DRAW!"));
+
+        script.push(ScriptStep::Key(key(KeyCode::Char('n'))));
+        manager.run_with(&mut out, &mut script).unwrap();
+        assert!(manager.is_finished());
+    }
+
+    /// Returns a seeded co-op game.
+    fn new_co_op_game() -> Game {
+        Game::with_options(GameOptions {
+            seed: Some(21),
+            co_op: true,
+            ..GameOptions::default()
+        })
+    }
+
+    /// Tests that a co-op game has two distinct ships, each flown by its own events and keys.
+    #[test]
+    fn test_co_op_second_ship_events_and_keys() {
+        let mut game = new_co_op_game();
+        let [first, second] = game.players() else {
+            panic!("a co-op game should have two ships");
+        };
+        assert_eq!(first.display_string(), PLAYER_SHIP_ART);
+        assert_eq!(second.display_string(), PLAYER_TWO_SHIP_ART);
+        let (first_x, second_x) = (first.x(), second.x());
+
+        game.update(GameEvent::SecondMoveLeft);
+        assert_eq!(game.players()[0].x(), first_x);
+        assert_eq!(game.players()[1].x(), second_x - 1);
+        assert_eq!(game.players()[1].points(), -game.scoring().move_cost);
+        game.update(GameEvent::SecondFire);
+        assert_eq!(game.blasts()[0].owner(), 1);
+        assert_eq!(game.blasts()[0].x(), second_x - 1);
+
+        // Without a second ship, its events only advance the frame.
+        let mut solo = new_test_game();
+        let x = solo.player().x();
+        solo.update(GameEvent::SecondMoveRight);
+        assert_eq!((solo.player().x(), solo.frame_counter()), (x, 1));
+        assert_eq!(solo.score(), INITIAL_SCORE);
+
+        // In a co-op game scene, the co-op keys fly the second ship.
+        let args = PlayArgs {
+            options: GameOptions {
+                seed: Some(21),
+                ..GameOptions::default()
+            },
+            mode: PlayMode::Turn,
+            spectate: None,
+            spectate_public: false,
+        };
+        let mut context = SceneContext::new(args, Settings::default());
+        let mut scene = GameScene::co_op(&context);
+        scene.handle_key(key(KeyCode::Char('d')), &mut context);
+        scene.handle_key(key(KeyCode::Char(' ')), &mut context);
+        assert_eq!(scene.game().players()[1].x(), second_x + 1);
+        assert_eq!(scene.game().blasts()[0].owner(), 0);
+    }
+
+    /// Tests that a kill is credited to the ship whose blast hit the alien.
+    #[test]
+    fn test_co_op_kill_credited_to_shooter() {
+        let mut game = new_co_op_game();
+        game.aliens_mut().clear();
+        game.aliens_mut()
+            .push(Alien::new_for_test(10, 5, true, ALIEN_DESIGNS[0], 0));
+        game.aliens_mut()
+            .push(Alien::new_for_test(40, 5, true, ALIEN_DESIGNS[0], 0));
+        game.blasts_mut().push(Blast::new(10, 5).owned_by(1));
+        game.handle_collisions();
+        assert_eq!(game.aliens()[0].hit_by(), 1);
+
+        for _ in 0..4 {
+            game.update_explosions();
+        }
+        let reward = game.scoring().kill_reward(0, 1);
+        assert_eq!(game.players()[1].points(), reward);
+        assert_eq!(game.players()[0].points(), 0);
+    }
+
+    /// Tests that in a co-op game, an alien hitting one ship only destroys that
+    /// ship, and the game is lost once both are gone.
+    #[test]
+    fn test_co_op_collision_destroys_one_ship() {
+        let mut game = new_co_op_game();
+        let ship_y = game.player().y_pos();
+        let first_x = game.players()[0].x();
+        let second_x = game.players()[1].x();
+        game.aliens_mut().clear();
+        game.aliens_mut().push(Alien::new_for_test(
+            first_x,
+            ship_y - 1,
+            true,
+            ALIEN_DESIGNS[0],
+            0,
+        ));
+        game.aliens_mut()
+            .push(Alien::new_for_test(2, 3, true, ALIEN_DESIGNS[0], 0));
+        game.check_game_over_conditions();
+        assert_eq!(game.state(), GameState::Playing);
+        assert!(game.players()[0].destroyed());
+        assert!(!game.players()[1].destroyed());
+        assert_eq!(game.aliens().len(), 1, "The ramming alien is wrecked too.");
+
+        // The destroyed ship no longer moves.
+        game.update(GameEvent::MoveRight);
+        assert_eq!(game.players()[0].x(), first_x);
+
+        game.aliens_mut().push(Alien::new_for_test(
+            second_x,
+            ship_y - 1,
+            true,
+            ALIEN_DESIGNS[0],
+            0,
+        ));
+        game.check_game_over_conditions();
+        assert_eq!(game.state(), GameState::GameOver);
+        assert!(game.players().iter().all(Player::destroyed));
+    }
+
+    /// Tests that a co-op replay keeps its mode and the second ship's events.
+    #[test]
+    fn test_co_op_replay_round_trip() {
+        let mut game = new_co_op_game();
+        let mut replay = Replay::record(&game);
+        for event in [
+            GameEvent::SecondFire,
+            GameEvent::MoveLeft,
+            GameEvent::SecondMoveRight,
+            GameEvent::SecondMoveLeft,
+            GameEvent::Fire,
+        ] {
+            game.update(event);
+            replay.events.push(event);
+        }
+        let text = replay.serialize();
+        assert!(text.contains("co_op=true\nfire_rule=unlimited\nevents=fLrlF\n"));
+        let parsed = Replay::parse(&text).expect("co-op replay should parse");
+        assert_eq!(parsed, replay);
+        let replayed = parsed.play_out();
+        assert_eq!(replayed.players()[1].x(), game.players()[1].x());
+        assert_eq!(replayed.score(), game.score());
+    }
+
+    /// Plays one networked turn: submits the event, sends it, and waits for the other player's.
+    fn play_net_turn(
+        connection: &mut Connection,
+        lockstep: &mut Lockstep,
+        event: GameEvent,
+    ) -> Result<(), NetError> {
+        if let Some(message) = lockstep.submit(event) {
+            connection.send(&message)?;
+        }
+        while lockstep.is_waiting() {
+            let message = connection.receive(Duration::from_secs(5))?;
+            lockstep.receive(message)?;
+        }
+        Ok(())
+    }
+
+    /// Tests that every protocol message survives encoding, and that garbage is rejected.
+    #[test]
+    fn test_net_message_round_trip() {
+        let messages = [
+            Message::Hello,
+            Message::Start {
+                options: GameOptions {
+                    seed: Some(77),
+                    difficulty: Difficulty::Hard,
+                    level: 2,
+                    co_op: false,
+                    fire_rule: FireRule::Cooldown(3),
+                },
+                mode: NetMode::Versus,
+            },
+            Message::Turn {
+                turn: 12,
+                event: GameEvent::Fire,
+                hash: 0x00ab_cdef_0123_4567,
+            },
+            Message::Bye,
+        ];
+        for message in messages {
+            assert_eq!(Message::parse(&message.encode()), Ok(message));
+        }
+        assert!(Message::parse("turn 1 X 00").is_err());
+        assert!(Message::parse("start difficulty=easy").is_err());
+        assert!(Message::parse("hello there").is_err());
+    }
+
+    /// Tests that the `host` and `join` commands parse, filling in the default port.
+    #[test]
+    fn test_cli_parse_net_commands() {
+        let parse = |line: &str| parse_args(line.split_whitespace().map(String::from));
+        assert_eq!(
+            parse("host --versus --port 5000 --seed 3"),
+            Ok(Command::Host {
+                options: GameOptions {
+                    seed: Some(3),
+                    ..GameOptions::default()
+                },
+                mode: NetMode::Versus,
+                port: 5000,
+            })
+        );
+        assert!(matches!(
+            parse("host"),
+            Ok(Command::Host {
+                mode: NetMode::CoOp,
+                port: NET_DEFAULT_PORT,
+                ..
+            })
+        ));
+        assert_eq!(
+            parse("join example.org"),
+            Ok(Command::Join(format!("example.org:{}", NET_DEFAULT_PORT)))
+        );
+        assert_eq!(
+            parse("join 10.0.0.2:6000"),
+            Ok(Command::Join("10.0.0.2:6000".to_string()))
+        );
+        assert!(parse("join").is_err());
+        assert!(parse("host --port many").is_err());
+    }
+
+    /// Tests that a co-op game played over localhost stays in sync on both
+    /// peers, and that the host notices when the guest disconnects.
+    #[test]
+    fn test_net_co_op_over_localhost() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+        let address = listener.local_addr().expect("address");
+        let options = GameOptions {
+            seed: Some(5),
+            ..GameOptions::default()
+        };
+        let host = std::thread::spawn(move || {
+            let (mut connection, mut lockstep) =
+                net::host(&listener, options, NetMode::CoOp).expect("host handshake");
+            for event in [
+                GameEvent::Fire,
+                GameEvent::MoveLeft,
+                GameEvent::AdvanceFrame,
+            ] {
+                play_net_turn(&mut connection, &mut lockstep, event).expect("host turn");
+            }
+            let after_turns = lockstep.state_hash();
+            let disconnect = connection.receive(Duration::from_secs(5));
+            (after_turns, lockstep.local_game().player().x(), disconnect)
+        });
+
+        let (mut connection, mut lockstep) = net::join(address).expect("guest handshake");
+        assert_eq!(lockstep.side(), Side::Guest);
+        assert_eq!(lockstep.options().seed, Some(5));
+        let start_x = lockstep.local_game().players()[1].x();
+        for event in [GameEvent::MoveRight, GameEvent::MoveRight, GameEvent::Fire] {
+            play_net_turn(&mut connection, &mut lockstep, event).expect("guest turn");
+        }
+        assert_eq!(lockstep.turn(), 3);
+        let game = lockstep.local_game();
+        assert_eq!(
+            game.players()[1].x(),
+            start_x + 2,
+            "The guest flies ship 2."
+        );
+        assert_eq!(
+            game.frame_counter(),
+            3,
+            "Each turn plays both players' events in one frame."
+        );
+        let guest_hash = lockstep.state_hash();
+        let host_x = game.player().x();
+        drop(connection);
+
+        let (host_hash, host_view_x, disconnect) = host.join().expect("host thread");
+        assert_eq!(host_hash, guest_hash);
+        assert_eq!(host_view_x, host_x);
+        assert!(matches!(disconnect, Err(NetError::Disconnected)));
+    }
+
+    /// Tests that a networked co-op turn lasts one frame, as a key press does
+    /// in a local co-op game, with both ships acting in it.
+    #[test]
+    fn test_net_co_op_turn_matches_local_co_op() {
+        let options = GameOptions {
+            seed: Some(21),
+            ..GameOptions::default()
+        };
+        let mut host = Lockstep::new(options, NetMode::CoOp, Side::Host);
+        let mut guest = Lockstep::new(options, NetMode::CoOp, Side::Guest);
+        let host_turn = host.submit(GameEvent::Fire).expect("host turn");
+        let guest_turn = guest.submit(GameEvent::MoveLeft).expect("guest turn");
+        host.receive(guest_turn).expect("in sync");
+        guest.receive(host_turn).expect("in sync");
+
+        let mut local = Game::with_options(GameOptions {
+            co_op: true,
+            ..options
+        });
+        local.update(GameEvent::Fire);
+        let net = host.local_game();
+        assert_eq!(net.frame_counter(), local.frame_counter());
+        assert_eq!(net.blasts().len(), 1);
+        assert_eq!(net.players()[1].x(), local.players()[1].x() - 1);
+    }
+
+    /// Tests that once the local field of a versus game is over, the net scene
+    /// keeps submitting turns on its ticks, so the opponent can finish without
+    /// waiting for key presses.
+    #[test]
+    fn test_net_scene_advances_after_local_field_ends() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+        let address = listener.local_addr().expect("address");
+        let options = GameOptions {
+            seed: Some(13),
+            ..GameOptions::default()
+        };
+        let host = std::thread::spawn(move || {
+            let (mut connection, mut lockstep) =
+                net::host(&listener, options, NetMode::Versus).expect("host handshake");
+            for _ in 0..4 {
+                play_net_turn(&mut connection, &mut lockstep, GameEvent::Fire).expect("host turn");
+            }
+            lockstep.turn()
+        });
+
+        let (mut connection, mut lockstep) = net::join(address).expect("guest handshake");
+        play_net_turn(&mut connection, &mut lockstep, GameEvent::Quit).expect("guest turn");
+        assert_eq!(lockstep.local_game().state(), GameState::Quit);
+        let mut scene = crate::scene::net::NetScene::new(connection, lockstep);
+        let mut context = SceneContext::new(PlayArgs::default(), Settings::default());
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while scene.lockstep().turn() < 4 && std::time::Instant::now() < deadline {
+            scene.tick(&mut context);
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(scene.lockstep().turn(), 4);
+        assert_eq!(host.join().expect("host thread"), 4);
+        let mut screen = Vec::new();
+        scene.draw(&mut screen, &context).unwrap();
+        assert!(String::from_utf8_lossy(&screen).contains("OPPONENT FINISHING"));
+    }
+
+    /// Tests versus lockstep on separate fields, and that desyncs, turns out
+    /// of order and goodbyes end the session.
+    #[test]
+    fn test_net_lockstep_versus_and_desync() {
+        let options = GameOptions {
+            seed: Some(8),
+            ..GameOptions::default()
+        };
+        let mut host = Lockstep::new(options, NetMode::Versus, Side::Host);
+        let mut guest = Lockstep::new(options, NetMode::Versus, Side::Guest);
+        let host_turn = host.submit(GameEvent::MoveLeft).expect("host turn");
+        assert!(
+            host.submit(GameEvent::Fire).is_none(),
+            "One event per turn."
+        );
+        let guest_turn = guest.submit(GameEvent::Fire).expect("guest turn");
+        host.receive(guest_turn).expect("in sync");
+        guest.receive(host_turn).expect("in sync");
+        assert_eq!(host.state_hash(), guest.state_hash());
+        assert_eq!(host.local_game().blasts().len(), 0);
+        assert_eq!(host.opponent_game().map(|g| g.blasts().len()), Some(1));
+        assert_eq!(guest.local_game().blasts().len(), 1);
+
+        // Tamper with the state the guest reports: the host detects the desync.
+        let Some(Message::Turn { turn, event, hash }) = guest.submit(GameEvent::AdvanceFrame)
+        else {
+            panic!("guest should submit a turn");
+        };
+        let tampered = Message::Turn {
+            turn,
+            event,
+            hash: hash ^ 1,
+        };
+        assert!(matches!(
+            host.receive(tampered),
+            Err(NetError::Desync { turn: 2, .. })
+        ));
+        let early = Message::Turn {
+            turn: 5,
+            event,
+            hash,
+        };
+        assert!(matches!(host.receive(early), Err(NetError::Protocol(_))));
+        assert!(matches!(
+            host.receive(Message::Bye),
+            Err(NetError::Disconnected)
+        ));
+    }
+
+    /// Tests that `--spectate` parses for `play` and needs a port number.
+    #[test]
+    fn test_cli_parse_spectate() {
+        let parse = |line: &str| parse_args(line.split_whitespace().map(String::from));
+        let Ok(Command::Play(args)) = parse("play --spectate 5000") else {
+            panic!("expected a play command");
+        };
+        assert_eq!(args.spectate, Some(5000));
+        assert!(parse("play --spectate").is_err());
+        assert!(parse("play --spectate everyone").is_err());
+        assert!(parse("simulate --spectate 5000").is_err());
+        assert!(
+            !args.spectate_public,
+            "Spectators are local unless asked otherwise."
+        );
+        let Ok(Command::Play(args)) = parse("play --spectate-public --spectate 5000") else {
+            panic!("expected a play command");
+        };
+        assert!(args.spectate_public);
+        assert!(parse("play --spectate-public").is_err());
+    }
+
+    /// Tests that a spectator connected while the scene manager runs receives
+    /// the same frames as the terminal.
+    #[test]
+    fn test_spectators_receive_frames() {
+        use std::io::Read;
+        let server = SpectatorServer::bind("127.0.0.1:0").expect("bind");
+        let mut client =
+            std::net::TcpStream::connect(server.local_addr().expect("address")).expect("connect");
+        client
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .expect("timeout");
+        let mut manager = new_test_manager();
+        manager.set_spectators(server);
+        let mut out = Vec::new();
+        let mut script = ScriptedInput::new(vec![ScriptStep::Key(key(KeyCode::Char('h')))]);
+        script.push(ScriptStep::Key(key(KeyCode::Esc)));
+        script.push(ScriptStep::Key(KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL,
+        )));
+        manager.run_with(&mut out, &mut script).unwrap();
+        drop(manager); // Closes the spectator's connection.
+        let mut received = Vec::new();
+        client.read_to_end(&mut received).expect("read");
+
+        assert!(!out.is_empty());
+        assert!(
+            received.starts_with(b"\x1b[?25l"),
+            "The cursor is hidden first."
+        );
+        let watched = String::from_utf8_lossy(&received);
+        let shown = String::from_utf8_lossy(&out);
+        assert!(watched.contains(READY_PROMPT));
+        assert_eq!(
+            watched.matches(READY_PROMPT).count(),
+            shown.matches(READY_PROMPT).count(),
+            "The spectator sees every frame."
+        );
+    }
+
+    /// Tests that a late spectator is shown the latest frame at once, and that
+    /// a spectator who disconnects is dropped without disturbing the others.
+    #[test]
+    fn test_spectators_join_late_and_leave() {
+        use std::io::Read;
+        let mut server = SpectatorServer::bind("127.0.0.1:0").expect("bind");
+        let address = server.local_addr().expect("address");
+        server.broadcast(b"first frame");
+        assert_eq!(server.spectator_count(), 0);
+
+        let mut staying = std::net::TcpStream::connect(address).expect("connect");
+        staying
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .expect("timeout");
+        let leaving = std::net::TcpStream::connect(address).expect("connect");
+        std::thread::sleep(Duration::from_millis(50)); // Let both connections land.
+        server.accept_pending();
+        assert_eq!(server.spectator_count(), 2);
+        let mut greeting = vec![0; b"\x1b[?25lfirst frame".len()];
+        staying.read_exact(&mut greeting).expect("read");
+        assert_eq!(greeting, b"\x1b[?25lfirst frame");
+
+        drop(leaving);
+        std::thread::sleep(Duration::from_millis(50));
+        for _ in 0..10 {
+            // The first write after a disconnect may still succeed.
+            server.broadcast(b"frame");
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(server.spectator_count(), 1);
+        let mut frame = [0; 5];
+        staying.read_exact(&mut frame).expect("read");
+        assert_eq!(&frame, b"frame");
+    }
+
+    /// Tests that a spectator who stops reading is dropped once it falls
+    /// behind, without broadcasting ever blocking the game.
+    #[test]
+    fn test_spectators_stalled_reader_does_not_block() {
+        let mut server = SpectatorServer::bind("127.0.0.1:0").expect("bind");
+        let stalled =
+            std::net::TcpStream::connect(server.local_addr().expect("address")).expect("connect");
+        std::thread::sleep(Duration::from_millis(50)); // Let the connection land.
+        server.accept_pending();
+        assert_eq!(server.spectator_count(), 1);
+
+        let frame = vec![b'#'; 64 * 1024];
+        let started = std::time::Instant::now();
+        for _ in 0..200 {
+            server.broadcast(&frame);
+        }
+        assert!(
+            started.elapsed() < Duration::from_millis(100),
+            "Broadcasting only queues frames."
+        );
+        assert_eq!(
+            server.spectator_count(),
+            0,
+            "The stalled spectator is dropped."
+        );
+        drop(stalled);
+    }
+
+    /// Tests that the telnet decoder strips negotiation, reports the window
+    /// size and turns key bytes, arrows and line endings into key presses.
+    #[test]
+    fn test_telnet_decoder() {
+        let mut decoder = TelnetDecoder::new();
+        let mut bytes = vec![255, 251, 31]; // IAC WILL NAWS
+        bytes.extend([255, 250, 31, 0, 100, 0, 30, 255, 240]); // IAC SB NAWS 100x30 IAC SE
+        bytes.extend(b"a\x1b[D\r\0y\r\n\x03");
+        let events = decoder.feed(&bytes);
+        let key_event = |code, modifiers| TelnetEvent::Key(KeyEvent::new(code, modifiers));
+        assert_eq!(
+            events,
+            vec![
+                TelnetEvent::Resize {
+                    width: 100,
+                    height: 30
+                },
+                key_event(KeyCode::Char('a'), KeyModifiers::NONE),
+                key_event(KeyCode::Left, KeyModifiers::NONE),
+                key_event(KeyCode::Enter, KeyModifiers::NONE),
+                key_event(KeyCode::Char('y'), KeyModifiers::NONE),
+                key_event(KeyCode::Enter, KeyModifiers::NONE),
+                key_event(KeyCode::Char('c'), KeyModifiers::CONTROL),
+            ]
+        );
+        // A lone escape is the Esc key; a sequence split across reads still decodes.
+        assert_eq!(
+            decoder.feed(b"\x1b"),
+            vec![key_event(KeyCode::Esc, KeyModifiers::NONE)]
+        );
+        assert_eq!(decoder.feed(&[255, 250, 31, 0]), vec![]);
+        assert_eq!(
+            decoder.feed(&[80, 0, 24, 255, 240, b' ']),
+            vec![
+                TelnetEvent::Resize {
+                    width: 80,
+                    height: 24
+                },
+                key_event(KeyCode::Char(' '), KeyModifiers::NONE),
+            ]
+        );
+    }
+
+    /// Tests that the arcade server's command line parses, with its defaults.
+    #[test]
+    fn test_cli_parse_server_args() {
+        let parse = |line: &str| parse_server_args(line.split_whitespace().map(String::from));
+        assert_eq!(parse(""), Ok(ServerCommand::Serve(ServerArgs::default())));
+        assert_eq!(
+            parse("--port 2424 --max-players 3 --scores /tmp/office.txt"),
+            Ok(ServerCommand::Serve(ServerArgs {
+                port: 2424,
+                max_players: 3,
+                scores: Some(PathBuf::from("/tmp/office.txt")),
+            }))
+        );
+        assert_eq!(parse("--port 1 --help"), Ok(ServerCommand::Help));
+        assert!(parse("--max-players 0").is_err());
+        assert!(parse("--seed 4").is_err());
+        assert!(parse("serve").is_err());
+    }
+
+    /// Tests that games recorded from many threads at once all reach the high score file.
+    #[test]
+    fn test_high_scores_recorded_concurrently() {
+        let dir = std::env::temp_dir().join(format!("asciiliens-shared-{}", std::process::id()));
+        let path = dir.join("highscores.txt");
+        let _ = std::fs::remove_dir_all(&dir);
+        let threads: Vec<_> = (0..8)
+            .map(|i| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    let entry = HighScoreEntry {
+                        score: 1000 + i,
+                        won: true,
+                        difficulty: Difficulty::Normal,
+                        level: 1,
+                        seed: i as u64,
+                    };
+                    HighScores::record_in(&path, entry).expect("record")
+                })
+            })
+            .collect();
+        for thread in threads {
+            assert!(thread.join().expect("thread").is_some());
+        }
+        let scores = HighScores::load_from(&path);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(scores.entries().len(), 8);
+        assert_eq!(scores.entries()[0].score, 1007);
+    }
+
+    /// Tests that the arcade server plays a session over telnet with the shared
+    /// high scores, and turns players away once it is full.
+    #[test]
+    fn test_arcade_server_session() {
+        use std::io::{Read, Write};
+        let dir = std::env::temp_dir().join(format!("asciiliens-arcade-{}", std::process::id()));
+        let path = dir.join("highscores.txt");
+        let _ = std::fs::remove_dir_all(&dir);
+        let mut shared = HighScores::default();
+        shared.add(HighScoreEntry {
+            score: 4321,
+            won: true,
+            difficulty: Difficulty::Hard,
+            level: 2,
+            seed: 77,
+        });
+        shared.save_to(&path).expect("save");
+
+        let server = ArcadeServer::bind("127.0.0.1:0", 1, Some(path)).expect("bind");
+        let address = server.local_addr().expect("address");
+        let mut player = std::net::TcpStream::connect(address).expect("connect");
+        player
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .expect("timeout");
+        let session = server.accept().expect("accept").expect("room");
+        let mut negotiation = [0; 9];
+        player.read_exact(&mut negotiation).expect("read");
+        assert_eq!(negotiation, NEGOTIATION);
+        assert_eq!(server.player_count(), 1);
+
+        let mut latecomer = std::net::TcpStream::connect(address).expect("connect");
+        assert!(server.accept().expect("accept").is_none());
+        let mut refusal = String::new();
+        latecomer.read_to_string(&mut refusal).expect("read");
+        assert!(refusal.contains(ARCADE_FULL_MESSAGE));
+
+        // The window size, the high score table, then back and quit.
+        player
+            .write_all(&[255, 250, 31, 0, 80, 0, 24, 255, 240])
+            .expect("write");
+        player.write_all(b"h").expect("write");
+        std::thread::sleep(Duration::from_millis(100));
+        player.write_all(b"\x1b").expect("write");
+        std::thread::sleep(Duration::from_millis(100));
+        player.write_all(b"\x03").expect("write");
+        let mut screen = Vec::new();
+        player.read_to_end(&mut screen).expect("read");
+        session.join().expect("session").expect("session result");
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let screen = String::from_utf8_lossy(&screen);
+        assert!(screen.contains(READY_PROMPT));
+        assert!(screen.contains("4321"), "The shared high scores are shown.");
+        assert_eq!(server.player_count(), 0);
+    }
+
+    /// Tests that daily challenges follow the calendar: dates, weekly waves
+    /// and a seed of their own for every day.
+    #[test]
+    fn test_daily_challenge_dates() {
+        let epoch = DailyChallenge::from_days_since_epoch(0);
+        assert_eq!(epoch.to_string(), "1970-01-01");
+        assert_eq!(epoch.wave(), 4, "1970-01-01 was a Thursday.");
+
+        let sunday: DailyChallenge = "2026-10-18".parse().unwrap();
+        assert_eq!(sunday.days_since_epoch(), 20744);
+        assert_eq!(sunday.wave(), 7);
+        let monday = DailyChallenge::from_days_since_epoch(20745);
+        assert_eq!(
+            (monday.to_string().as_str(), monday.wave()),
+            ("2026-10-19", 1)
+        );
+        assert_eq!(
+            "2024-02-29".parse::<DailyChallenge>().unwrap().date(),
+            (2024, 2, 29)
+        );
+        for invalid in ["2023-02-29", "2026-13-01", "2026-10", "yesterday"] {
+            assert!(invalid.parse::<DailyChallenge>().is_err(), "{}", invalid);
+        }
+
+        let options = sunday.options();
+        assert_eq!(options.seed, Some(sunday.seed()));
+        assert_eq!((options.difficulty, options.level), (Difficulty::Normal, 7));
+        assert_eq!(
+            sunday.seed(),
+            "2026-10-18".parse::<DailyChallenge>().unwrap().seed()
+        );
+        assert_ne!(sunday.seed(), monday.seed());
+
+        let parse = |line: &str| parse_args(line.split_whitespace().map(String::from));
+        assert_eq!(parse("daily"), Ok(Command::Daily));
+        assert_eq!(parse("daily --scores"), Ok(Command::DailyScores));
+        assert!(parse("daily --seed 3").is_err());
+    }
+
+    /// Tests that the daily leaderboard ranks runs within their day, forgets
+    /// old days, and survives a round trip through its file.
+    #[test]
+    fn test_daily_leaderboard() {
+        let today = DailyChallenge::from_days_since_epoch(20744);
+        let yesterday = DailyChallenge::from_days_since_epoch(20743);
+        let entry = |challenge, score| DailyEntry {
+            challenge,
+            score,
+            won: false,
+            hits: 3,
+            shots_fired: 4,
+        };
+        let mut leaderboard = DailyLeaderboard::default();
+        assert_eq!(leaderboard.add(entry(today, 500)), 1);
+        assert_eq!(leaderboard.add(entry(yesterday, 900)), 1);
+        assert_eq!(leaderboard.add(entry(today, 700)), 1);
+        assert_eq!(
+            leaderboard.add(entry(today, 500)),
+            3,
+            "Ties keep the earlier run ahead."
+        );
+        let scores: Vec<i32> = leaderboard
+            .entries_for(today)
+            .iter()
+            .map(|e| e.score)
+            .collect();
+        assert_eq!(scores, vec![700, 500, 500]);
+
+        let next_month = DailyChallenge::from_days_since_epoch(20744 + 30);
+        assert_eq!(leaderboard.add(entry(next_month, 100)), 1);
+        assert!(
+            leaderboard.entries_for(today).is_empty(),
+            "Old days are dropped."
+        );
+
+        let dir = std::env::temp_dir().join(format!("asciiliens-daily-{}", std::process::id()));
+        let path = dir.join("daily.txt");
+        let _ = std::fs::remove_dir_all(&dir);
+        assert_eq!(
+            DailyLeaderboard::record_in(&path, entry(today, 200)).unwrap(),
+            (1, 1)
+        );
+        let ranked = DailyLeaderboard::record_in(&path, entry(today, 300)).unwrap();
+        let loaded = DailyLeaderboard::load_from(&path);
+        let _ = std::fs::remove_dir_all(&dir);
+        assert_eq!(ranked, (1, 2));
+        assert_eq!(loaded.entries_for(today).len(), 2);
+        assert!(loaded.table_lines(today)[0].contains("2026-10-18"));
+
+        let card = entry(today, 300).card(Some(ranked));
+        assert_eq!(
+            card,
+            vec![
+                "ASCIIliens daily challenge 2026-10-18",
+                "Wave 7 | Invaded | Score 300",
+                "Accuracy 75% (3 of 4 shots)",
+                "#1 of 2 runs today",
+            ]
+        );
+        assert_eq!(entry(today, 300).card(None).len(), 3);
+    }
+
+    /// Tests that a daily run played through the scenes uses the day's seed,
+    /// enters the daily leaderboard instead of the high scores, and leaves a
+    /// result card to print.
+    #[test]
+    fn test_daily_run_through_scenes() {
+        let today = DailyChallenge::from_days_since_epoch(20744);
+        let dir = std::env::temp_dir().join(format!("asciiliens-daily-run-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let mut context = SceneContext::new(PlayArgs::default(), Settings::default());
+        context.daily = Some(today);
+        context.daily_path = Some(dir.join("daily.txt"));
+        context.high_scores_path = Some(dir.join("highscores.txt"));
+        assert_eq!(context.game_options(), today.options());
+        let mut manager = SceneManager::new(Box::new(TitleScene::new()), context);
+
+        let mut out = Vec::new();
+        let mut script = ScriptedInput::from_keys([KeyCode::Char('y')]);
+        for _ in 0..400 {
+            script.push(ScriptStep::Key(key(KeyCode::Char('x'))));
+        }
+        manager.run_with(&mut out, &mut script).unwrap_err();
+        assert_eq!(manager.active_scene_name(), Some("results"));
+        let leaderboard = DailyLeaderboard::load_from(&dir.join("daily.txt"));
+        let high_scores_saved = dir.join("highscores.txt").exists();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(leaderboard.entries_for(today).len(), 1);
+        assert!(
+            !high_scores_saved,
+            "Daily runs stay out of the high scores."
+        );
+        let cards = &manager.context().result_cards;
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0][0], "ASCIIliens daily challenge 2026-10-18");
+        let output = String::from_utf8_lossy(&out);
+        assert!(output.contains("DAILY CHALLENGE 2026-10-18  WAVE 7"));
+        assert!(output.contains("DAILY 2026-10-18: #1 OF 1"));
+    }
+
+    /// Tests that a ghost replays a previous run in step with the live game,
+    /// and is drawn dimmed beneath the live ship.
+    #[test]
+    fn test_ghost_follows_previous_run() {
+        let options = GameOptions {
+            seed: Some(31),
+            ..GameOptions::default()
+        };
+        let mut previous = Replay::record(&Game::with_options(options));
+        previous.events = vec![GameEvent::MoveLeft; 6];
+        let mut ghost = Ghost::new(previous.clone());
+        let mut live = Game::with_options(options);
+        assert_eq!(ghost.ship_x(), Some(live.player().x()));
+
+        live.update(GameEvent::MoveRight);
+        live.update(GameEvent::AdvanceFrame);
+        ghost.catch_up(live.frame_counter());
+        assert_eq!(ghost.game().frame_counter(), 2);
+        let mut expected = Game::with_options(options);
+        expected.update(GameEvent::MoveLeft);
+        expected.update(GameEvent::MoveLeft);
+        assert_eq!(ghost.ship_x(), Some(expected.player().x()));
+        ghost.catch_up(100);
+        assert_eq!(
+            ghost.game().frame_counter(),
+            6,
+            "The ghost stops when the run does."
+        );
+
+        live.set_ghost_x(ghost.ship_x());
+        let mut screen = Vec::new();
+        live.draw(&mut screen).unwrap();
+        let screen = String::from_utf8_lossy(&screen);
+        let dim = "\u{1b}[2m";
+        let ghost_at = screen.find(dim).expect("the ghost should be drawn dimmed");
+        assert!(screen[ghost_at..].starts_with(&format!("{}{}", dim, PLAYER_SHIP_ART)));
+        let live_at = screen.rfind(PLAYER_SHIP_ART).unwrap();
+        assert!(ghost_at < live_at, "The live ship is drawn over the ghost.");
+
+        live.set_ghost_x(None);
+        let mut screen = Vec::new();
+        live.draw(&mut screen).unwrap();
+        assert!(!String::from_utf8_lossy(&screen).contains(dim));
+    }
+
+    /// Tests that a finished seeded game is kept as its level's ghost, that a
+    /// later attempt races against it with the setting on, and that a worse
+    /// run does not replace it.
+    #[test]
+    fn test_ghost_run_kept_and_raced() {
+        let dir = std::env::temp_dir().join(format!("asciiliens-ghosts-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let args = PlayArgs {
+            options: GameOptions {
+                seed: Some(12),
+                level: 9,
+                ..GameOptions::default()
+            },
+            ..PlayArgs::default()
+        };
+        let mut context = SceneContext::new(args, Settings::default());
+        context.ghosts_dir = Some(dir.clone());
+        let path = dir.join(Replay::ghost_file_name(&context.game_options()));
+
+        // A run that only moves left until the invasion lands is the first best run.
+        let mut first = GameScene::new(&context);
+        assert_eq!(first.game().ghost_x(), None);
+        for _ in 0..600 {
+            first.handle_key(key(KeyCode::Left), &mut context);
+            if path.exists() {
+                break;
+            }
+        }
+        let kept = Replay::load_from(&path).expect("the run should be kept");
+        assert_eq!(kept.play_out().score(), first.game().score());
+
+        // With the setting off there is no ghost; with it on, the ghost drifts left.
+        assert_eq!(GameScene::new(&context).game().ghost_x(), None);
+        context.settings.ghost = true;
+        let mut second = GameScene::new(&context);
+        let start_x = second.game().player().x();
+        assert_eq!(second.game().ghost_x(), Some(start_x));
+        for _ in 0..3 {
+            second.handle_key(key(KeyCode::Char('x')), &mut context);
+        }
+        assert!(second.game().ghost_x().unwrap() < start_x);
+        assert_eq!(second.game().player().x(), start_x);
+
+        // Shooting back scores better than the first run, so it becomes the ghost.
+        let finish = |scene: &mut GameScene, code: KeyCode, context: &mut SceneContext| {
+            while scene.game().state() == GameState::Playing {
+                scene.handle_key(key(code), context);
+            }
+            for _ in 0..10 {
+                scene.handle_key(key(KeyCode::Char('x')), context); // Let the effects settle.
+            }
+        };
+        finish(&mut second, KeyCode::Char(' '), &mut context);
+        assert!(second.game().score() > kept.play_out().score());
+        let best = Replay::load_from(&path).expect("the better run should be kept");
+        assert_eq!(best.play_out().score(), second.game().score());
+
+        // Repeating the first, worse run leaves the better one in place.
+        let mut third = GameScene::new(&context);
+        finish(&mut third, KeyCode::Left, &mut context);
+        assert!(third.game().score() < best.play_out().score());
+        let still_best = Replay::load_from(&path).expect("the ghost should remain");
+        let _ = std::fs::remove_dir_all(&dir);
+        assert_eq!(still_best, best);
+    }
+
+    /// Tests that the rewind key steps back turns in turn-based mode, even past
+    /// the move that lost the game, and that a rewound game is kept as a
+    /// consistent replay but not entered into the high scores.
+    #[test]
+    fn test_rewind_steps_back_turns() {
+        let dir = std::env::temp_dir().join(format!("asciiliens-rewind-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let args = PlayArgs {
+            options: GameOptions {
+                seed: Some(12),
+                level: 9,
+                ..GameOptions::default()
+            },
+            ..PlayArgs::default()
+        };
+        let mut context = SceneContext::new(args, Settings::default());
+        context.high_scores_path = Some(dir.join("scores.txt"));
+        context.replay_path = Some(dir.join("last.replay"));
+
+        let mut scene = GameScene::new(&context);
+        scene.handle_key(key(KeyCode::Char('u')), &mut context);
+        assert!(!scene.is_rewound(), "There is nothing to rewind yet.");
+        scene.handle_key(key(KeyCode::Left), &mut context);
+        let after_one = (scene.game().frame_counter(), scene.game().player().x());
+        scene.handle_key(key(KeyCode::Left), &mut context);
+        scene.handle_key(key(KeyCode::Char(' ')), &mut context);
+        scene.handle_key(key(KeyCode::Char('u')), &mut context);
+        scene.handle_key(key(KeyCode::Char('U')), &mut context);
+        assert!(scene.is_rewound());
+        assert_eq!(
+            (scene.game().frame_counter(), scene.game().player().x()),
+            after_one
+        );
+
+        // The move that lost the game can be taken back too.
+        while scene.game().state() == GameState::Playing {
+            scene.handle_key(key(KeyCode::Left), &mut context);
+        }
+        let lost_on = scene.game().frame_counter();
+        scene.handle_key(key(KeyCode::Char('u')), &mut context);
+        assert_eq!(scene.game().state(), GameState::Playing);
+        assert!(scene.game().frame_counter() < lost_on);
+        while scene.game().state() == GameState::Playing {
+            scene.handle_key(key(KeyCode::Left), &mut context);
+        }
+        for _ in 0..10 {
+            scene.handle_key(key(KeyCode::Char('x')), &mut context); // Let the effects settle.
+        }
+
+        let replay =
+            Replay::load_from(&dir.join("last.replay")).expect("the replay should be saved");
+        assert_eq!(replay.play_out().score(), scene.game().score());
+        let scores_saved = dir.join("scores.txt").exists();
+        let _ = std::fs::remove_dir_all(&dir);
+        assert!(!scores_saved, "A rewound game is practice.");
+        assert_eq!(
+            context.profile,
+            Profile::default(),
+            "Nor does it count towards the profile."
+        );
+    }
+
+    /// Tests that display settings and the play mode changed from the pause
+    /// menu's options screen apply to the game being played.
+    #[test]
+    fn test_settings_changed_while_paused_apply_on_resume() {
+        // Switching to real-time while paused turns off rewinding, which is
+        // only for turn-based play.
+        let mut manager = new_test_manager();
+        manager.handle_key(key(KeyCode::Char('y')));
+        manager.handle_key(key(KeyCode::Left));
+        manager.handle_key(key(KeyCode::Char('p')));
+        manager.handle_key(key(KeyCode::Char('o')));
+        assert_eq!(manager.active_scene_name(), Some("options"));
+        manager.handle_key(key(KeyCode::Enter)); // Play mode: Turn -> Realtime.
+        manager.handle_key(key(KeyCode::Esc));
+        manager.handle_key(key(KeyCode::Char('p')));
+        assert_eq!(manager.active_scene_name(), Some("game"));
+        manager.handle_key(key(KeyCode::Char('u')));
+        let mut screen = Vec::new();
+        manager.draw(&mut screen).unwrap();
+        assert!(!String::from_utf8_lossy(&screen).contains("PRACTICE"));
+
+        let mut context = SceneContext::new(PlayArgs::default(), Settings::default());
+        let mut scene = GameScene::new(&context);
+        assert!(scene.tick_interval(&context).is_none());
+        context.settings.mode = PlayMode::Realtime;
+        context.settings.preview = true;
+        context.settings.reduced_motion = true;
+        scene.resume(&mut context);
+        assert!(scene.tick_interval(&context).is_some());
+        assert!(scene.game().preview_visible());
+        assert!(scene.game().reduced_motion());
+    }
+
+    /// Tests that the rewind key only rewinds in turn-based mode.
+    #[test]
+    fn test_rewind_only_in_turn_mode() {
+        let mut context = SceneContext::new(PlayArgs::default(), Settings::default());
+        context.settings.mode = PlayMode::Realtime;
+        let mut scene = GameScene::new(&context);
+        scene.handle_key(key(KeyCode::Left), &mut context);
+        scene.handle_key(key(KeyCode::Char('u')), &mut context);
+        assert!(!scene.is_rewound());
+        assert_eq!(scene.game().frame_counter(), 2);
+
+        let mut settings = Settings::default();
+        settings.keys.bind(KeyAction::Rewind, KeyCode::Backspace);
+        let loaded = Settings::parse(&settings.serialize());
+        assert_eq!(loaded.keys.key(KeyAction::Rewind), KeyCode::Backspace);
+    }
+
+    /// Tests that the turn preview foresees the next frame's blasts and the
+    /// next descent without changing the game, and is drawn when enabled.
+    #[test]
+    fn test_turn_preview_matches_what_happens() {
+        let mut game = Game::with_options(GameOptions {
+            seed: Some(8),
+            ..GameOptions::default()
+        });
+        game.update(GameEvent::Fire);
+        game.update(GameEvent::MoveLeft);
+        let hash = game.state_hash();
+        let preview = game.preview();
+        assert_eq!(
+            game.state_hash(),
+            hash,
+            "The preview leaves the game unchanged."
+        );
+        assert_eq!(preview.turns_to_descent(), ALIEN_MOVE_DOWN_FREQ - 2);
+
+        let mut actual = game.clone();
+        actual.update(GameEvent::AdvanceFrame);
+        let blasts: Vec<(u16, u16)> = actual.blasts().iter().map(|b| (b.x(), b.y())).collect();
+        assert!(!blasts.is_empty());
+        assert_eq!(preview.blasts(), &blasts[..]);
+        for _ in 1..preview.turns_to_descent() {
+            actual.update(GameEvent::AdvanceFrame);
+        }
+        let landed: Vec<(u16, u16, u16)> = actual
+            .aliens()
+            .iter()
+            .filter(|a| a.alive() && a.explosion_frame() == 0)
+            .map(|a| (a.x(), a.y() + a.height() - 1, a.width()))
+            .collect();
+        assert_eq!(preview.descending(), &landed[..]);
+
+        let mut screen = Vec::new();
+        game.draw(&mut screen).unwrap();
+        assert!(!String::from_utf8_lossy(&screen).contains("Aliens descend in"));
+        game.set_preview_visible(true);
+        let mut screen = Vec::new();
+        game.draw(&mut screen).unwrap();
+        let screen = String::from_utf8_lossy(&screen);
+        assert!(screen.contains(&format!(
+            "Aliens descend in {} turn(s).",
+            ALIEN_MOVE_DOWN_FREQ - 2
+        )));
+        assert!(screen.contains("\u{1b}[2m"));
+        assert_eq!(game.state_hash(), hash);
+    }
+
+    /// Tests that the sandbox's tweaks change the game: the descent frequency
+    /// sets when aliens descend, and invulnerable ships survive rams and invasions.
+    #[test]
+    fn test_sandbox_rule_tweaks() {
+        let mut game = Game::with_options(GameOptions {
+            seed: Some(4),
+            ..GameOptions::default()
+        });
+        game.set_descent_frequency(3);
+        let top = game.aliens()[0].y();
+        game.update(GameEvent::AdvanceFrame);
+        game.update(GameEvent::AdvanceFrame);
+        assert_eq!(game.aliens()[0].y(), top);
+        game.update(GameEvent::AdvanceFrame);
+        assert_eq!(game.aliens()[0].y(), top + 1);
+
+        game.set_invulnerable(true);
+        let ship_x = game.player().x();
+        let ship_y = game.player().y_pos();
+        let aliens = game.aliens().len();
+        let rammer = Alien::with_sprite(ship_x - 1, ship_y - 1, 0, AlienSprite::for_kind(0));
+        let invader = Alien::with_sprite(0, ship_y - 1, 1, AlienSprite::for_kind(1));
+        game.aliens_mut().push(rammer);
+        game.aliens_mut().push(invader);
+        game.update(GameEvent::AdvanceFrame);
+        assert_eq!(game.state(), GameState::Playing);
+        assert!(!game.player().destroyed());
+        assert_eq!(game.aliens().len(), aliens);
+
+        game.set_invulnerable(false);
+        game.aliens_mut().push(rammer);
+        game.update(GameEvent::AdvanceFrame);
+        assert_eq!(game.state(), GameState::GameOver);
+    }
+
+    /// Tests editing a game in the sandbox: placing aliens and blasts at the
+    /// cursor, stepping frames, and keeping the tweaks across a reset.
+    #[test]
+    fn test_sandbox_scene_edits_game() {
+        let mut context = SceneContext::new(PlayArgs::default(), Settings::default());
+        let options = GameOptions {
+            seed: Some(6),
+            ..GameOptions::default()
+        };
+        let mut sandbox = SandboxScene::new(options, &context);
+        let press = |sandbox: &mut SandboxScene, c: char, context: &mut SceneContext| {
+            sandbox.handle_key(key(KeyCode::Char(c)), context)
+        };
+        let start = sandbox.cursor();
+        for c in "clllk".chars() {
+            press(&mut sandbox, c, &mut context);
+        }
+        assert!(sandbox.game().aliens().is_empty());
+        assert_eq!(sandbox.cursor(), (start.0 + 3, start.1 - 1));
+        press(&mut sandbox, 'a', &mut context);
+        press(&mut sandbox, 'b', &mut context);
+        let alien = sandbox.game().aliens()[0];
+        assert_eq!((alien.x(), alien.y()), sandbox.cursor());
+        assert_eq!(sandbox.game().blasts().len(), 1);
+        assert_eq!(
+            sandbox.game().frame_counter(),
+            0,
+            "Editing does not advance the game."
+        );
+
+        press(&mut sandbox, '+', &mut context);
+        press(&mut sandbox, 'i', &mut context);
+        press(&mut sandbox, '.', &mut context);
+        assert_eq!(sandbox.game().frame_counter(), 1);
+        assert_eq!(sandbox.game().descent_frequency(), ALIEN_MOVE_DOWN_FREQ + 1);
+        let mut screen = Vec::new();
+        sandbox.draw(&mut screen, &context).unwrap();
+        assert!(String::from_utf8_lossy(&screen).contains(&format!(
+            "SANDBOX  Descent every {} frame(s)  Invulnerable: ON  Frame 1",
+            ALIEN_MOVE_DOWN_FREQ + 1
+        )));
+
+        // Aliens placed at the bottom-right corner are kept on the field, above the ship.
+        press(&mut sandbox, 'c', &mut context);
+        for _ in 0..GAME_WIDTH {
+            press(&mut sandbox, 'l', &mut context);
+            press(&mut sandbox, 'j', &mut context);
+        }
+        press(&mut sandbox, 'a', &mut context);
+        let alien = sandbox.game().aliens()[0];
+        assert_eq!(alien.x() + alien.width(), GAME_WIDTH);
+        assert!(alien.y() + alien.height() <= sandbox.game().player().y_pos());
+        press(&mut sandbox, '.', &mut context);
+        assert_eq!(sandbox.game().state(), GameState::Playing);
+        assert_eq!(
+            sandbox.game().aliens().len(),
+            1,
+            "The alien has not invaded, even with invulnerability on."
+        );
+
+        press(&mut sandbox, 'r', &mut context);
+        assert_eq!(sandbox.game().frame_counter(), 0);
+        assert_eq!(sandbox.game().aliens().len(), 30);
+        assert_eq!(sandbox.game().descent_frequency(), ALIEN_MOVE_DOWN_FREQ + 1);
+        assert!(sandbox.game().invulnerable());
+        press(&mut sandbox, '>', &mut context);
+        assert_eq!(sandbox.game().frame_counter(), 10);
+        assert!(matches!(
+            press(&mut sandbox, 'q', &mut context),
+            Transition::Pop
+        ));
+
+        let parse = |line: &str| parse_args(line.split_whitespace().map(String::from));
+        assert_eq!(parse("sandbox --seed 6"), Ok(Command::Sandbox(options)));
+        assert!(parse("sandbox --mode realtime").is_err());
+    }
+
+    /// Tests that an empty sandbox field does not win the game, whether it is
+    /// cleared or shot empty, and that aliens placed afterwards still move.
+    #[test]
+    fn test_sandbox_empty_field_keeps_playing() {
+        let mut context = SceneContext::new(PlayArgs::default(), Settings::default());
+        let mut sandbox = SandboxScene::new(GameOptions::default(), &context);
+        let press = |sandbox: &mut SandboxScene, c: char, context: &mut SceneContext| {
+            sandbox.handle_key(key(KeyCode::Char(c)), context)
+        };
+        press(&mut sandbox, 'c', &mut context);
+        press(&mut sandbox, '.', &mut context);
+        assert_eq!(sandbox.game().state(), GameState::Playing);
+        assert_eq!(sandbox.game().frame_counter(), 1);
+
+        press(&mut sandbox, 'a', &mut context);
+        let placed = sandbox.game().aliens()[0];
+        press(&mut sandbox, '>', &mut context);
+        assert_eq!(sandbox.game().state(), GameState::Playing);
+        assert_eq!(sandbox.game().frame_counter(), 11);
+        let moved = sandbox.game().aliens()[0];
+        assert_ne!(
+            (moved.x(), moved.y()),
+            (placed.x(), placed.y()),
+            "The placed alien moves."
+        );
+
+        // Shooting the last alien does not win the game either.
+        press(&mut sandbox, 'c', &mut context);
+        press(&mut sandbox, 'a', &mut context);
+        press(&mut sandbox, 'b', &mut context);
+        press(&mut sandbox, '>', &mut context);
+        assert_eq!(sandbox.game().stats().hits, 1);
+        assert!(sandbox.game().aliens().is_empty());
+        assert_eq!(sandbox.game().state(), GameState::Playing);
+        assert_eq!(sandbox.game().frame_counter(), 21);
     }
 
     /// Tests that aliens correctly move downwards after a certain number of frames.
@@ -3,126 +3,283 @@
 //! # ASCIIliens Game Executable
 //!
 //! This is the main entry point for the ASCIIliens game. It is responsible for:
-//! - Initializing the terminal for raw mode and alternate screen display.
-//! - Managing the main game loop, including handling user input.
-//! - Drawing the game state on each frame.
-//! - Handling game session flow, including the intro screen and play-again prompts.
-//! - Cleaning up the terminal state upon exiting.
-
-use asciiliens::{show_game_end_screen, show_intro_screen, Game, GameEvent, GameState};
-use crossterm::{
-    cursor::{self, MoveTo}, // `cursor` module for cursor visibility and positioning.
-    event::{self, Event, KeyCode}, // `event` module for reading keyboard input.
-    execute,
-    queue,             // `execute` and `queue` for sending commands to the terminal.
-    style::ResetColor, // `style` for terminal styling, like resetting colors.
-    terminal::{self, EnterAlternateScreen, LeaveAlternateScreen}, // `terminal` for screen control.
-};
-use std::io::{self, Write}; // Standard I/O traits for interacting with the terminal. // Import core game logic and display functions from the library.
+//! - Parsing the command line and reporting argument errors before touching the terminal.
+//! - Handing interactive play and replay playback to the scene manager, which
+//!   owns the terminal, the screens and the game loop.
+//! - Running the non-interactive commands.
+//! - Restoring the terminal state upon exiting, including on panics and signals.
+
+use asciiliens::cli::{self, Command, PlayArgs};
+use asciiliens::config::Settings;
+use asciiliens::daily::{DailyChallenge, DailyLeaderboard};
+use asciiliens::display::terminal as term;
+use asciiliens::game::options::GameOptions;
+use asciiliens::net::spectator::SpectatorServer;
+use asciiliens::net::{self, Connection, Lockstep, NetError, NetMode};
+use asciiliens::replay::Replay;
+use asciiliens::scene::net::NetScene;
+use asciiliens::scene::replay::ReplayScene;
+use asciiliens::scene::sandbox::SandboxScene;
+use asciiliens::scene::title::TitleScene;
+use asciiliens::scores::HighScores;
+use asciiliens::sim;
+use asciiliens::{Scene, SceneContext, SceneManager};
+use std::io; // Standard I/O for reporting results and errors.
+use std::net::TcpListener;
+use std::path::Path;
+use std::process;
 
 /// The main function, serving as the entry point of the ASCIIliens game application.
 ///
-/// This function sets up the game's execution environment, runs the main game loop,
-/// and ensures proper cleanup of the terminal state.
+/// This function parses the command line and dispatches to the requested command.
+/// Argument errors are reported with the usage text and exit code 2, before the
+/// terminal is switched to raw mode; other errors exit with code 1.
 ///
-/// # Returns
-/// An `io::Result<()>` indicating whether the program executed successfully or encountered an I/O error.
-fn main() -> io::Result<()> {
-    let mut stdout = io::stdout(); // Obtain a mutable handle to the standard output.
-
-    // The outer loop allows the player to start a new game session after one ends.
-    'game_loop: loop {
-        // Display the introductory screen, which includes game instructions and a "Ready?" prompt.
-        // This function handles its own terminal setup and input for the intro sequence.
-        show_intro_screen(&mut stdout)?;
-
-        // After the intro, prepare the terminal for the main game.
-        // - `EnterAlternateScreen`: Switches to a fresh, clear terminal buffer.
-        // - `Hide`: Hides the cursor to provide a cleaner game display.
-        execute!(stdout, EnterAlternateScreen, cursor::Hide,)?;
-        // Enable raw mode, which allows direct, unbuffered input from the keyboard
-        // and bypasses line buffering, critical for real-time game input.
-        terminal::enable_raw_mode()?;
-        // Clear the entire alternate screen to ensure a clean game area.
-        execute!(stdout, terminal::Clear(terminal::ClearType::All))?;
-
-        // Create a new instance of the game, resetting its state for a fresh session.
-        let mut game = Game::new();
-
-        // The inner loop represents a single game session.
-        'session_loop: loop {
-            // Clear the screen at the beginning of each frame to draw the updated game state.
-            // Move the cursor to (0,0) to start drawing from the top-left.
-            queue!(
-                stdout,
-                terminal::Clear(terminal::ClearType::All),
-                MoveTo(0, 0)
-            )?;
-
-            // Draw all game entities (player, blasts, aliens) to the buffered output.
-            game.draw(&mut stdout)?;
-            // Flush the buffer to send all drawing commands to the terminal for immediate display.
-            stdout.flush()?;
-
-            // Read player input (or detect other events) to determine the next `GameEvent`.
-            let event = match event::read() {
-                Ok(Event::Key(key_event)) => {
-                    // Map keyboard input to specific `GameEvent`s.
-                    match key_event.code {
-                        KeyCode::Left => GameEvent::MoveLeft,
-                        KeyCode::Right => GameEvent::MoveRight,
-                        KeyCode::Char(' ') => GameEvent::Fire,
-                        KeyCode::Char('q') | KeyCode::Esc => GameEvent::Quit, // 'q' or Esc key to quit.
-                        _ => GameEvent::AdvanceFrame, // Any other key simply advances the game frame.
-                    }
-                }
-                Ok(_) => {
-                    // If an event other than a key press occurs (e.g., mouse event, resize),
-                    // treat it as an `AdvanceFrame` to keep the game progressing.
-                    GameEvent::AdvanceFrame
-                }
-                Err(e) => {
-                    // If there's an error reading an event, print it and signal a quit event.
-                    eprintln!("Error reading event: {:?}", e);
-                    GameEvent::Quit
-                }
-            };
+/// Before anything else runs, a panic hook and SIGINT/SIGTERM handlers are
+/// installed so the terminal is restored however the game ends.
+fn main() {
+    term::install_panic_hook();
+    if let Err(e) = term::install_signal_handlers() {
+        eprintln!("warning: could not install signal handlers: {}", e);
+    }
 
-            // Update the game state based on the processed event.
-            game.update(event);
+    // Saved settings supply the defaults that command-line flags override.
+    let settings = Settings::default_path()
+        .map(|path| Settings::load_from(&path))
+        .unwrap_or_default();
 
-            // Check the game's current state to decide if the session loop should end.
-            match game.state() {
-                GameState::Win | GameState::GameOver | GameState::Quit => {
-                    // If the game is won, lost, or quit, break out of the current session loop.
-                    break 'session_loop;
-                }
-                GameState::Playing => { /* Game is ongoing, continue the session loop. */ }
+    let command =
+        match cli::parse_args_with_defaults(std::env::args().skip(1), settings.play_args()) {
+            Ok(command) => command,
+            Err(e) => {
+                eprintln!("error: {}\n\n{}", e, cli::usage());
+                process::exit(2);
             }
+        };
+
+    let result = match command {
+        Command::Play(args) => run_play(args, settings),
+        Command::Replay(path) => run_replay(&path, settings),
+        Command::Scores => print_scores(),
+        Command::Simulate { games, options } => run_simulation(games, options),
+        Command::Host {
+            options,
+            mode,
+            port,
+        } => run_host(options, mode, port, settings),
+        Command::Join(address) => run_join(&address, settings),
+        Command::Daily => run_daily(settings),
+        Command::DailyScores => print_daily_scores(),
+        Command::Sandbox(options) => run_sandbox(options, settings),
+        Command::Help => {
+            println!("{}", cli::usage());
+            Ok(())
+        }
+        Command::Version => {
+            println!("asciiliens {}", env!("CARGO_PKG_VERSION"));
+            Ok(())
         }
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
 
-        // After a game session concludes, clean up the terminal state.
-        // - `Show`: Makes the cursor visible again.
-        // - `disable_raw_mode`: Restores standard terminal input buffering.
-        // - `LeaveAlternateScreen`: Returns to the original terminal buffer.
-        // - `ResetColor`: Resets any applied terminal colors.
-        execute!(stdout, cursor::Show,)?;
-        terminal::disable_raw_mode()?;
-        execute!(stdout, LeaveAlternateScreen, ResetColor)?;
-
-        // Display the game end screen and ask the player if they want to play again.
-        // `show_game_end_screen` handles its own input for the play-again prompt.
-        let play_again_prompt_result =
-            show_game_end_screen(&mut stdout, game.state(), game.score())?;
-
-        // If the player chooses not to play again, exit the outer game loop, ending the application.
-        if !play_again_prompt_result {
-            break 'game_loop;
+/// Runs interactive games on the title screen until the player quits.
+///
+/// Every game is recorded as the last replay, finished games are entered into
+/// the high score table, and the profile is saved after each game. Playing
+/// again keeps the same options (including a difficulty picked on the title
+/// screen), so a fixed `--seed` lets the player retry the same run.
+///
+/// With `--spectate`, the screen is also streamed to anyone connecting to the
+/// port on this host, or from any host with `--spectate-public`.
+///
+/// # Arguments
+/// * `args` - The `PlayArgs` parsed from the command line.
+/// * `settings` - The settings loaded from the config file.
+///
+/// # Returns
+/// An `io::Result<()>` indicating whether the session ran successfully.
+fn run_play(args: PlayArgs, settings: Settings) -> io::Result<()> {
+    let spectators = match args.spectate {
+        Some(port) => {
+            // Spectators are only accepted from this host unless asked otherwise.
+            let host = if args.spectate_public {
+                "0.0.0.0"
+            } else {
+                "127.0.0.1"
+            };
+            let server = SpectatorServer::bind((host, port))?;
+            if args.spectate_public {
+                println!("Spectators can watch with: telnet <this host> {}", port);
+            } else {
+                println!(
+                    "Spectators on this host can watch with: telnet localhost {}",
+                    port
+                );
+            }
+            Some(server)
         }
+        None => None,
+    };
+    let context = SceneContext::with_default_paths(args, settings);
+    run_scenes(Box::new(TitleScene::new()), context, spectators)
+}
+
+/// Runs today's daily challenge from the title screen.
+///
+/// Every game started is the day's run, entered into the daily leaderboard
+/// once finished; the result card of each run is printed on exit, ready to share.
+///
+/// # Arguments
+/// * `settings` - The settings loaded from the config file.
+///
+/// # Returns
+/// An `io::Result<()>` indicating whether the session ran successfully.
+fn run_daily(settings: Settings) -> io::Result<()> {
+    let mut context = SceneContext::with_default_paths(settings.play_args(), settings);
+    context.daily = Some(DailyChallenge::today());
+    run_scenes(Box::new(TitleScene::new()), context, None)
+}
 
-        // If `play_again_prompt_result` is true, the 'game_loop' continues,
-        // starting a new game session from the top.
+/// Opens the practice sandbox until the player leaves it.
+///
+/// Nothing played in the sandbox is saved.
+///
+/// # Arguments
+/// * `options` - The options of the sandbox's game.
+/// * `settings` - The settings loaded from the config file, for the keys and display.
+///
+/// # Returns
+/// An `io::Result<()>` indicating whether the session ran successfully.
+fn run_sandbox(options: GameOptions, settings: Settings) -> io::Result<()> {
+    let context = SceneContext::new(settings.play_args(), settings);
+    run_scenes(
+        Box::new(SandboxScene::new(options, &context)),
+        context,
+        None,
+    )
+}
+
+/// Plays back a recorded game in the terminal, one frame at a time.
+///
+/// Pressing 'q', Esc or Ctrl-C stops the playback early. Once the recording
+/// ends, the final frame stays on screen until a key is pressed.
+///
+/// # Arguments
+/// * `path` - The replay file to play back.
+/// * `settings` - The settings loaded from the config file, for the theme and glyph set.
+///
+/// # Returns
+/// An `io::Result<()>`; loading errors are reported before the terminal is touched.
+fn run_replay(path: &Path, settings: Settings) -> io::Result<()> {
+    let replay = Replay::load_from(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    let context = SceneContext::new(settings.play_args(), settings);
+    run_scenes(Box::new(ReplayScene::new(replay, &context)), context, None)
+}
+
+/// Hosts a networked game: waits for another player to join, then plays it.
+///
+/// Waiting happens before the terminal is switched to raw mode, so Ctrl-C
+/// cancels it as usual.
+///
+/// # Arguments
+/// * `options` - The options of the game.
+/// * `mode` - How the two players play together.
+/// * `port` - The TCP port to listen on.
+/// * `settings` - The settings loaded from the config file.
+///
+/// # Returns
+/// An `io::Result<()>`; connection errors are reported before the terminal is touched.
+fn run_host(options: GameOptions, mode: NetMode, port: u16, settings: Settings) -> io::Result<()> {
+    let listener = TcpListener::bind(("0.0.0.0", port))?;
+    println!(
+        "Hosting a {} game on port {}. Waiting for a player to join...",
+        mode, port
+    );
+    run_net_game(net::host(&listener, options, mode), settings)
+}
+
+/// Joins a networked game hosted by another player, then plays it.
+///
+/// # Arguments
+/// * `address` - The host's address, as `host:port`.
+/// * `settings` - The settings loaded from the config file.
+///
+/// # Returns
+/// An `io::Result<()>`; connection errors are reported before the terminal is touched.
+fn run_join(address: &str, settings: Settings) -> io::Result<()> {
+    println!("Joining the game at {}...", address);
+    run_net_game(net::join(address), settings)
+}
+
+/// Plays a networked game once its handshake has completed.
+fn run_net_game(
+    handshake: Result<(Connection, Lockstep), NetError>,
+    settings: Settings,
+) -> io::Result<()> {
+    let (connection, lockstep) = handshake?;
+    let context = SceneContext::new(settings.play_args(), settings);
+    run_scenes(Box::new(NetScene::new(connection, lockstep)), context, None)
+}
+
+/// Runs the scene manager from the given scene, streaming to the spectators
+/// if there are any, then prints the result cards and reports any warnings
+/// collected while the terminal was in game mode.
+fn run_scenes(
+    first: Box<dyn Scene>,
+    context: SceneContext,
+    spectators: Option<SpectatorServer>,
+) -> io::Result<()> {
+    let mut manager = SceneManager::new(first, context);
+    if let Some(server) = spectators {
+        manager.set_spectators(server);
+    }
+    let result = manager.run(&mut io::stdout());
+    for card in &manager.context().result_cards {
+        println!("{}\n", card.join("\n"));
+    }
+    for warning in &manager.context().warnings {
+        eprintln!("{}", warning);
     }
+    result
+}
 
+/// Prints the high score table to standard output.
+fn print_scores() -> io::Result<()> {
+    let high_scores = HighScores::default_path()
+        .map(|path| HighScores::load_from(&path))
+        .unwrap_or_default();
+    for line in high_scores.table_lines() {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// Prints the leaderboard of today's daily challenge to standard output.
+fn print_daily_scores() -> io::Result<()> {
+    let leaderboard = DailyLeaderboard::default_path()
+        .map(|path| DailyLeaderboard::load_from(&path))
+        .unwrap_or_default();
+    for line in leaderboard.table_lines(DailyChallenge::today()) {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// Simulates a batch of games with the autopilot and prints a summary.
+///
+/// # Arguments
+/// * `games` - The number of games to simulate.
+/// * `options` - The `GameOptions` shared by every game.
+fn run_simulation(games: u32, options: GameOptions) -> io::Result<()> {
+    let summary = sim::simulate(games, options);
+    for line in summary.summary_lines() {
+        println!("{}", line);
+    }
     Ok(())
 }
@@ -0,0 +1,202 @@
+// asciiliens/src/net/arcade.rs
+
+//! This module hosts the game for many players at once, over telnet.
+//!
+//! Every connection gets a session of its own, on a thread of its own: a
+//! `SceneManager` starting at the title screen, drawing to the connection and
+//! reading keys from it through `TelnetInput`. Sessions share nothing but the
+//! high score table, which every finished game is entered into.
+
+use super::telnet::{TelnetInput, NEGOTIATION};
+use crate::cli::PlayArgs;
+use crate::config::Settings;
+use crate::display::terminal;
+use crate::input::InputSource;
+use crate::scene::title::TitleScene;
+use crate::scene::{SceneContext, SceneManager};
+use crate::util::constants::{ARCADE_FULL_MESSAGE, ARCADE_SIZE_WAIT_MS, GAME_HEIGHT, GAME_WIDTH};
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    execute, queue,
+    style::{Print, ResetColor},
+    terminal::{Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use std::io::{self, BufWriter, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Accepts players and runs a game session for each of them.
+#[derive(Debug)]
+pub struct ArcadeServer {
+    listener: TcpListener,
+    max_players: usize,
+    high_scores_path: Option<PathBuf>, // Shared by every session.
+    players: Arc<AtomicUsize>,         // The number of sessions running.
+}
+
+/// Counts a session as running for as long as it is alive.
+struct PlayerSlot(Arc<AtomicUsize>);
+
+impl Drop for PlayerSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl ArcadeServer {
+    /// Starts listening for players.
+    ///
+    /// # Arguments
+    /// * `address` - The address to listen on, e.g. `("0.0.0.0", 2323)`.
+    /// * `max_players` - How many sessions may run at once; later players are turned away.
+    /// * `high_scores_path` - The high score file every session enters its games
+    ///   into, or `None` to keep no high scores.
+    ///
+    /// # Returns
+    /// The `ArcadeServer`, or an `io::Error` if the address cannot be bound.
+    pub fn bind(
+        address: impl ToSocketAddrs,
+        max_players: usize,
+        high_scores_path: Option<PathBuf>,
+    ) -> io::Result<Self> {
+        Ok(Self {
+            listener: TcpListener::bind(address)?,
+            max_players,
+            high_scores_path,
+            players: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    /// Returns the address the server listens on.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Returns the number of sessions currently running.
+    pub fn player_count(&self) -> usize {
+        self.players.load(Ordering::SeqCst)
+    }
+
+    /// Accepts players forever. A connection that fails is dropped without
+    /// affecting the server or the other players.
+    ///
+    /// # Returns
+    /// Only returns if accepting itself fails.
+    pub fn serve(&self) -> io::Result<()> {
+        loop {
+            match self.accept() {
+                Ok(_) => {} // The session runs on without being joined.
+                Err(e)
+                    if matches!(
+                        e.kind(),
+                        io::ErrorKind::ConnectionAborted
+                            | io::ErrorKind::ConnectionReset
+                            | io::ErrorKind::Interrupted
+                    ) => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Waits for the next player, and starts a session for them if there is room.
+    ///
+    /// # Returns
+    /// The thread running the session, or `None` if the player was turned away
+    /// because the arcade is full.
+    pub fn accept(&self) -> io::Result<Option<JoinHandle<io::Result<()>>>> {
+        let (mut stream, _) = self.listener.accept()?;
+        if self.players.fetch_add(1, Ordering::SeqCst) >= self.max_players {
+            self.players.fetch_sub(1, Ordering::SeqCst);
+            // The player may already be gone; there is nothing more to tell them.
+            let _ = write!(stream, "{}\r\n", ARCADE_FULL_MESSAGE);
+            return Ok(None);
+        }
+        let slot = PlayerSlot(Arc::clone(&self.players));
+        let high_scores_path = self.high_scores_path.clone();
+        Ok(Some(thread::spawn(move || {
+            let _slot = slot; // Frees the slot when the session ends, even by panicking.
+            run_session(stream, high_scores_path)
+        })))
+    }
+}
+
+/// Plays one player's session, from the title screen until they quit or disconnect.
+///
+/// The player's terminal is asked for character mode and its size, switched
+/// to the alternate screen for the session and restored at the end. Each
+/// session keeps its settings and profile in memory only.
+///
+/// # Arguments
+/// * `stream` - The connection to the player.
+/// * `high_scores_path` - The shared high score file, if any.
+///
+/// # Returns
+/// An `io::Result<()>`; a player disconnecting is not an error.
+fn run_session(stream: TcpStream, high_scores_path: Option<PathBuf>) -> io::Result<()> {
+    stream.set_nodelay(true)?;
+    let mut out = BufWriter::new(stream.try_clone()?);
+    let mut input = TelnetInput::new(stream);
+    out.write_all(&NEGOTIATION)?;
+    out.flush()?;
+    input.wait_for_size(Duration::from_millis(ARCADE_SIZE_WAIT_MS))?;
+    execute!(out, EnterAlternateScreen, Hide)?;
+
+    let result = wait_for_room(&mut out, &mut input).and_then(|play| {
+        if !play {
+            return Ok(());
+        }
+        let mut context = SceneContext::new(PlayArgs::default(), Settings::default());
+        context.high_scores_path = high_scores_path;
+        let mut manager = SceneManager::new(Box::new(TitleScene::new()), context);
+        manager.run_with(&mut out, &mut input)
+    });
+    // The player may already be gone, in which case there is nothing to restore.
+    let _ = execute!(
+        out,
+        ResetColor,
+        Clear(ClearType::All),
+        Show,
+        LeaveAlternateScreen
+    );
+    match result {
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(()), // The player disconnected.
+        result => result,
+    }
+}
+
+/// Asks a player whose terminal is smaller than the game to enlarge it,
+/// until it is large enough or they press a key to play anyway.
+///
+/// # Returns
+/// `Ok(true)` to start playing, or `Ok(false)` if the player pressed Ctrl-C to leave.
+fn wait_for_room<W: Write>(out: &mut W, input: &mut TelnetInput) -> io::Result<bool> {
+    let mut shown = None;
+    loop {
+        let size = match input.size() {
+            Some((width, height)) if width < GAME_WIDTH || height < GAME_HEIGHT => (width, height),
+            _ => return Ok(true), // Large enough, or unknown.
+        };
+        if shown != Some(size) {
+            queue!(
+                out,
+                Clear(ClearType::All),
+                MoveTo(0, 0),
+                Print(format!(
+                    "Your terminal is {}x{}, but ASCIIliens needs at least {}x{}.",
+                    size.0, size.1, GAME_WIDTH, GAME_HEIGHT
+                )),
+                MoveTo(0, 1),
+                Print("Please enlarge it, or press any key to play anyway."),
+            )?;
+            out.flush()?;
+            shown = Some(size);
+        }
+        if let Some(key) = input.next_key(Some(Duration::from_millis(ARCADE_SIZE_WAIT_MS)))? {
+            return Ok(!terminal::is_interrupt(&key));
+        }
+    }
+}
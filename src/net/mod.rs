@@ -0,0 +1,619 @@
+// asciiliens/src/net/mod.rs
+
+//! This module plays games between two players over TCP, in lockstep.
+//!
+//! Because a seeded `Game` is fully deterministic, the two players only need
+//! to agree on the options and exchange one `GameEvent` each per turn: every
+//! peer then applies both events in the same order and the games stay
+//! identical. Each turn message also carries a hash of the sender's state
+//! before the turn, so a desync is caught on the turn it happens.
+//!
+//! The protocol is one line of text per message:
+//!
+//! ```text
+//! asciiliens-net 1
+//! start seed=1234 difficulty=normal level=1 fire_rule=unlimited mode=co-op
+//! turn 1 F 5be8a3c0d1e2f3a4
+//! bye
+//! ```
+//!
+//! The guest opens with the protocol header and the host answers with the
+//! header and the `start` message. Then both send a `turn` message for every
+//! turn, and `bye` when they leave.
+//!
+//! The `spectator` submodule streams the screen to read-only spectators, and
+//! the `arcade` submodule hosts independent games for many players at once
+//! over `telnet`.
+
+pub mod arcade;
+pub mod spectator;
+pub mod telnet;
+
+use crate::game::difficulty::Difficulty;
+use crate::game::options::GameOptions;
+use crate::game::rules::FireRule;
+use crate::game::{Game, GameEvent, GameState};
+use crate::replay::{char_to_event, event_to_char};
+use crate::util::constants::{NET_HANDSHAKE_TIMEOUT_SECS, NET_POLL_MS};
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// The first message of both peers, naming the protocol and its version.
+const PROTOCOL_HEADER: &str = "asciiliens-net 1";
+
+/// How the two players of a networked game play together.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum NetMode {
+    /// Both players fly a ship on the same field: the host the first, the guest the second.
+    #[default]
+    CoOp,
+    /// Each player fights the same invasion on a field of their own; the higher score wins.
+    Versus,
+}
+
+impl NetMode {
+    /// Returns the lowercase name used on the command line and in the protocol.
+    pub fn name(&self) -> &'static str {
+        match self {
+            NetMode::CoOp => "co-op",
+            NetMode::Versus => "versus",
+        }
+    }
+}
+
+impl fmt::Display for NetMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for NetMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "co-op" => Ok(NetMode::CoOp),
+            "versus" => Ok(NetMode::Versus),
+            _ => Err(format!("unknown mode '{}' (expected co-op or versus)", s)),
+        }
+    }
+}
+
+/// Which end of the connection a peer is.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Side {
+    /// The peer that listened for the connection and chose the options. Plays first in every turn.
+    Host,
+    /// The peer that connected to the host.
+    Guest,
+}
+
+impl Side {
+    /// Returns the index of the side's player: 0 for the host, 1 for the guest.
+    pub fn index(&self) -> usize {
+        match self {
+            Side::Host => 0,
+            Side::Guest => 1,
+        }
+    }
+}
+
+/// A message exchanged between the two peers.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Message {
+    /// The protocol header, sent first by both peers.
+    Hello,
+    /// The host's choice of options and mode, with the seed resolved.
+    Start {
+        /// The options both games are created with.
+        options: GameOptions,
+        /// How the players play together.
+        mode: NetMode,
+    },
+    /// The sender's event for a turn.
+    Turn {
+        /// The number of the turn, starting at 1.
+        turn: u64,
+        /// The sender's event for the turn.
+        event: GameEvent,
+        /// The hash of the sender's state before the turn (see `Lockstep::state_hash`).
+        hash: u64,
+    },
+    /// The sender is leaving.
+    Bye,
+}
+
+impl Message {
+    /// Encodes the message as a line of the protocol, without the line break.
+    pub fn encode(&self) -> String {
+        match self {
+            Message::Hello => PROTOCOL_HEADER.to_string(),
+            Message::Start { options, mode } => format!(
+                "start seed={} difficulty={} level={} fire_rule={} mode={}",
+                options.seed.unwrap_or_default(),
+                options.difficulty,
+                options.level,
+                options.fire_rule,
+                mode
+            ),
+            Message::Turn { turn, event, hash } => {
+                format!("turn {} {} {:016x}", turn, event_to_char(*event), hash)
+            }
+            Message::Bye => "bye".to_string(),
+        }
+    }
+
+    /// Parses a line of the protocol.
+    ///
+    /// # Returns
+    /// The `Message`, or a description of why the line is not a valid message.
+    pub fn parse(line: &str) -> Result<Message, String> {
+        let line = line.trim();
+        if line == PROTOCOL_HEADER {
+            return Ok(Message::Hello);
+        }
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some("start") => {
+                let mut options = GameOptions::default();
+                let mut mode = NetMode::default();
+                for word in words {
+                    let Some((key, value)) = word.split_once('=') else {
+                        continue;
+                    };
+                    match key {
+                        "seed" => {
+                            options.seed = Some(
+                                value
+                                    .parse()
+                                    .map_err(|_| format!("invalid seed '{}'", value))?,
+                            );
+                        }
+                        "difficulty" => options.difficulty = value.parse::<Difficulty>()?,
+                        "level" => {
+                            options.level = value
+                                .parse()
+                                .map_err(|_| format!("invalid level '{}'", value))?;
+                        }
+                        "fire_rule" => options.fire_rule = value.parse::<FireRule>()?,
+                        "mode" => mode = value.parse()?,
+                        _ => { /* Ignore unknown keys. */ }
+                    }
+                }
+                if options.seed.is_none() {
+                    return Err("start message has no seed".to_string());
+                }
+                Ok(Message::Start { options, mode })
+            }
+            Some("turn") => {
+                let (Some(turn), Some(event), Some(hash)) =
+                    (words.next(), words.next(), words.next())
+                else {
+                    return Err(format!("incomplete turn message '{}'", line));
+                };
+                let turn = turn
+                    .parse()
+                    .map_err(|_| format!("invalid turn '{}'", turn))?;
+                let mut chars = event.chars();
+                let event = match (chars.next().and_then(char_to_event), chars.next()) {
+                    (Some(event), None) => event,
+                    _ => return Err(format!("invalid event '{}'", event)),
+                };
+                let hash = u64::from_str_radix(hash, 16)
+                    .map_err(|_| format!("invalid hash '{}'", hash))?;
+                Ok(Message::Turn { turn, event, hash })
+            }
+            Some("bye") => Ok(Message::Bye),
+            _ => Err(format!("unknown message '{}'", line)),
+        }
+    }
+}
+
+/// Why a networked game could not go on.
+#[derive(Debug)]
+pub enum NetError {
+    /// The connection failed.
+    Io(io::Error),
+    /// The other player left or the connection was closed.
+    Disconnected,
+    /// The other player sent something this peer does not understand.
+    Protocol(String),
+    /// The two games no longer agree: the other player's state before a turn
+    /// differs from this peer's.
+    Desync {
+        /// The turn the states differed before.
+        turn: u64,
+        /// The hash of this peer's state.
+        local: u64,
+        /// The hash the other player sent.
+        remote: u64,
+    },
+}
+
+impl fmt::Display for NetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetError::Io(e) => write!(f, "connection error: {}", e),
+            NetError::Disconnected => f.write_str("the other player disconnected"),
+            NetError::Protocol(message) => write!(f, "protocol error: {}", message),
+            NetError::Desync {
+                turn,
+                local,
+                remote,
+            } => write!(
+                f,
+                "the games went out of sync before turn {} (local {:016x}, remote {:016x})",
+                turn, local, remote
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NetError {}
+
+impl From<io::Error> for NetError {
+    fn from(e: io::Error) -> Self {
+        match e.kind() {
+            io::ErrorKind::UnexpectedEof
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::BrokenPipe => NetError::Disconnected,
+            _ => NetError::Io(e),
+        }
+    }
+}
+
+impl From<NetError> for io::Error {
+    fn from(e: NetError) -> Self {
+        match e {
+            NetError::Io(e) => e,
+            other => io::Error::other(other.to_string()),
+        }
+    }
+}
+
+/// A connection to the other player, sending and receiving `Message`s.
+#[derive(Debug)]
+pub struct Connection {
+    writer: TcpStream,
+    reader: BufReader<TcpStream>,
+    partial: Vec<u8>, // The start of a line whose end has not arrived yet.
+}
+
+impl Connection {
+    /// Wraps a connected stream. Reads wait at most `NET_POLL_MS`, so
+    /// `try_receive` never blocks for long.
+    ///
+    /// # Arguments
+    /// * `stream` - The stream connected to the other player.
+    ///
+    /// # Returns
+    /// The `Connection`, or an `io::Error` if the stream cannot be configured.
+    pub fn new(stream: TcpStream) -> io::Result<Self> {
+        stream.set_nodelay(true)?; // Turns are tiny and latency matters more than throughput.
+        stream.set_read_timeout(Some(Duration::from_millis(NET_POLL_MS)))?;
+        Ok(Self {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+            partial: Vec::new(),
+        })
+    }
+
+    /// Sends a message.
+    pub fn send(&mut self, message: &Message) -> Result<(), NetError> {
+        writeln!(self.writer, "{}", message.encode())?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Returns the next message if one has arrived, waiting at most `NET_POLL_MS`.
+    ///
+    /// # Returns
+    /// `Ok(Some(message))`, `Ok(None)` if no complete message has arrived yet,
+    /// or `Err(NetError::Disconnected)` once the other player has closed the connection.
+    pub fn try_receive(&mut self) -> Result<Option<Message>, NetError> {
+        match self.reader.read_until(b'\n', &mut self.partial) {
+            Ok(0) => Err(NetError::Disconnected),
+            Ok(_) if self.partial.ends_with(b"\n") => {
+                let line = String::from_utf8_lossy(&self.partial).into_owned();
+                self.partial.clear();
+                Message::parse(&line).map(Some).map_err(NetError::Protocol)
+            }
+            Ok(_) => Err(NetError::Disconnected), // The connection closed mid-line.
+            // A partial line stays in `partial` until the rest arrives.
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                ) =>
+            {
+                Ok(None)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Waits for the next message.
+    ///
+    /// # Arguments
+    /// * `timeout` - How long to wait before giving up.
+    ///
+    /// # Returns
+    /// The message, or a `NetError` if the connection fails or nothing arrives in time.
+    pub fn receive(&mut self, timeout: Duration) -> Result<Message, NetError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(message) = self.try_receive()? {
+                return Ok(message);
+            }
+            if Instant::now() >= deadline {
+                return Err(NetError::Io(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "the other player did not answer in time",
+                )));
+            }
+        }
+    }
+}
+
+/// Waits for a guest to connect, then agrees on the game with it.
+///
+/// # Arguments
+/// * `listener` - The listener the guest connects to.
+/// * `options` - The options of the game; a `None` seed is resolved randomly.
+/// * `mode` - How the players play together.
+///
+/// # Returns
+/// The connection and the `Lockstep` session of the host, or a `NetError`.
+pub fn host(
+    listener: &TcpListener,
+    options: GameOptions,
+    mode: NetMode,
+) -> Result<(Connection, Lockstep), NetError> {
+    let (stream, _) = listener.accept()?;
+    let mut connection = Connection::new(stream)?;
+    let timeout = Duration::from_secs(NET_HANDSHAKE_TIMEOUT_SECS);
+    match connection.receive(timeout)? {
+        Message::Hello => {}
+        other => return Err(unexpected(&other)),
+    }
+    let lockstep = Lockstep::new(options, mode, Side::Host);
+    connection.send(&Message::Hello)?;
+    connection.send(&Message::Start {
+        options: lockstep.options(),
+        mode,
+    })?;
+    Ok((connection, lockstep))
+}
+
+/// Connects to a host and receives the game it chose.
+///
+/// # Arguments
+/// * `address` - The host's address, e.g. `"127.0.0.1:4747"`.
+///
+/// # Returns
+/// The connection and the `Lockstep` session of the guest, or a `NetError`.
+pub fn join(address: impl ToSocketAddrs) -> Result<(Connection, Lockstep), NetError> {
+    let mut connection = Connection::new(TcpStream::connect(address)?)?;
+    let timeout = Duration::from_secs(NET_HANDSHAKE_TIMEOUT_SECS);
+    connection.send(&Message::Hello)?;
+    match connection.receive(timeout)? {
+        Message::Hello => {}
+        other => return Err(unexpected(&other)),
+    }
+    match connection.receive(timeout)? {
+        Message::Start { options, mode } => {
+            Ok((connection, Lockstep::new(options, mode, Side::Guest)))
+        }
+        other => Err(unexpected(&other)),
+    }
+}
+
+/// Returns the error for a message that does not belong at this point of the protocol.
+fn unexpected(message: &Message) -> NetError {
+    NetError::Protocol(format!("unexpected message '{}'", message.encode()))
+}
+
+/// One peer's side of a game played in lockstep.
+///
+/// Each turn, the local player's event is submitted with `submit` and sent to
+/// the other player, whose messages are fed to `receive`. Once both events of
+/// a turn are known, the turn is played: the host's event first, then the guest's.
+#[derive(Debug)]
+pub struct Lockstep {
+    mode: NetMode,
+    side: Side,
+    games: Vec<Game>, // One shared game in co-op; the host's and the guest's game in versus.
+    turn: u64,        // The number of turns played.
+    local: Option<GameEvent>, // The local player's event for the next turn, once submitted.
+    remote: Option<GameEvent>, // The other player's event for the next turn, once received.
+}
+
+impl Lockstep {
+    /// Creates the session of one peer.
+    ///
+    /// # Arguments
+    /// * `options` - The options of the game; a `None` seed is resolved randomly,
+    ///   so both peers must be given the same `Some` seed.
+    /// * `mode` - How the players play together.
+    /// * `side` - Which end of the connection this peer is.
+    ///
+    /// # Returns
+    /// A new `Lockstep` session before its first turn.
+    pub fn new(options: GameOptions, mode: NetMode, side: Side) -> Self {
+        let games = match mode {
+            NetMode::CoOp => vec![Game::with_options(GameOptions {
+                co_op: true,
+                ..options
+            })],
+            NetMode::Versus => {
+                let game = Game::with_options(GameOptions {
+                    co_op: false,
+                    ..options
+                });
+                let options = game.options(); // Both fields share the resolved seed.
+                vec![game, Game::with_options(options)]
+            }
+        };
+        Self {
+            mode,
+            side,
+            games,
+            turn: 0,
+            local: None,
+            remote: None,
+        }
+    }
+
+    /// Returns the options the games were created with, with the seed resolved.
+    pub fn options(&self) -> GameOptions {
+        GameOptions {
+            co_op: false,
+            ..self.games[0].options()
+        }
+    }
+
+    /// Returns how the players play together.
+    pub fn mode(&self) -> NetMode {
+        self.mode
+    }
+
+    /// Returns which end of the connection this peer is.
+    pub fn side(&self) -> Side {
+        self.side
+    }
+
+    /// Returns the number of turns played.
+    pub fn turn(&self) -> u64 {
+        self.turn
+    }
+
+    /// Returns the game the local player is playing: the shared game in co-op,
+    /// the player's own field in versus.
+    pub fn local_game(&self) -> &Game {
+        match self.mode {
+            NetMode::CoOp => &self.games[0],
+            NetMode::Versus => &self.games[self.side.index()],
+        }
+    }
+
+    /// Returns the other player's field in versus, or `None` in co-op.
+    pub fn opponent_game(&self) -> Option<&Game> {
+        match self.mode {
+            NetMode::CoOp => None,
+            NetMode::Versus => Some(&self.games[1 - self.side.index()]),
+        }
+    }
+
+    /// Returns whether the local player's event for the next turn has been
+    /// submitted and the other player's has not arrived yet.
+    pub fn is_waiting(&self) -> bool {
+        self.local.is_some()
+    }
+
+    /// Returns whether the game is over: the shared game in co-op, both fields in versus.
+    pub fn is_over(&self) -> bool {
+        self.games.iter().all(|g| g.state() != GameState::Playing)
+    }
+
+    /// Returns the hash of every game in the session, which both peers compare each turn.
+    pub fn state_hash(&self) -> u64 {
+        self.games
+            .iter()
+            .fold(0, |hash, game| hash.rotate_left(1) ^ game.state_hash())
+    }
+
+    /// Submits the local player's event for the next turn.
+    ///
+    /// # Arguments
+    /// * `event` - The local player's event.
+    ///
+    /// # Returns
+    /// The `Turn` message to send to the other player, or `None` if an event
+    /// was already submitted for this turn or the game is over.
+    pub fn submit(&mut self, event: GameEvent) -> Option<Message> {
+        if self.local.is_some() || self.is_over() {
+            return None;
+        }
+        self.local = Some(event);
+        let message = Message::Turn {
+            turn: self.turn + 1,
+            event,
+            hash: self.state_hash(),
+        };
+        self.play_turn_if_ready();
+        Some(message)
+    }
+
+    /// Handles a message from the other player.
+    ///
+    /// # Arguments
+    /// * `message` - The message received.
+    ///
+    /// # Returns
+    /// `Ok(())`, or the `NetError` that ends the session: `Disconnected` for a
+    /// `Bye`, `Desync` if the other player's state differs, and `Protocol` for
+    /// a message out of turn.
+    pub fn receive(&mut self, message: Message) -> Result<(), NetError> {
+        let (turn, event, hash) = match message {
+            Message::Turn { turn, event, hash } => (turn, event, hash),
+            Message::Bye => return Err(NetError::Disconnected),
+            other => return Err(unexpected(&other)),
+        };
+        if turn != self.turn + 1 || self.remote.is_some() {
+            return Err(NetError::Protocol(format!(
+                "received turn {} while expecting turn {}",
+                turn,
+                self.turn + 1
+            )));
+        }
+        let local = self.state_hash();
+        if hash != local {
+            return Err(NetError::Desync {
+                turn,
+                local,
+                remote: hash,
+            });
+        }
+        self.remote = Some(event);
+        self.play_turn_if_ready();
+        Ok(())
+    }
+
+    /// Plays the next turn once both players' events are known.
+    fn play_turn_if_ready(&mut self) {
+        let (Some(local), Some(remote)) = (self.local, self.remote) else {
+            return;
+        };
+        let (host_event, guest_event) = match self.side {
+            Side::Host => (local, remote),
+            Side::Guest => (remote, local),
+        };
+        match self.mode {
+            NetMode::CoOp => {
+                // Both ships act in the same frame, as in a local co-op game.
+                self.games[0].update_co_op(host_event, second_ship_event(guest_event));
+            }
+            NetMode::Versus => {
+                self.games[0].update(host_event);
+                self.games[1].update(guest_event);
+            }
+        }
+        self.turn += 1;
+        self.local = None;
+        self.remote = None;
+    }
+}
+
+/// Converts an event of the guest's ship to the matching event of the second ship in a co-op game.
+fn second_ship_event(event: GameEvent) -> GameEvent {
+    match event {
+        GameEvent::MoveLeft => GameEvent::SecondMoveLeft,
+        GameEvent::MoveRight => GameEvent::SecondMoveRight,
+        GameEvent::Fire => GameEvent::SecondFire,
+        other => other,
+    }
+}
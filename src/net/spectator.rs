@@ -0,0 +1,142 @@
+// asciiliens/src/net/spectator.rs
+
+//! This module streams the screen to spectators over TCP.
+//!
+//! A `SpectatorServer` listens on a port, and anyone connecting to it with
+//! `telnet` or `nc` sees every frame the player sees, as the same ANSI escape
+//! sequences. Spectators are read-only: anything they send is ignored.
+//!
+//! Each spectator is written to by a thread of its own, fed through a short
+//! queue, so a slow or stalled connection never holds up the game loop.
+
+use crate::util::constants::{SPECTATOR_QUEUE_FRAMES, SPECTATOR_WRITE_TIMEOUT_MS};
+use std::io::{self, BufWriter, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::thread;
+use std::time::Duration;
+
+/// Sent to a spectator when it connects: hides the cursor, so it does not
+/// flicker across the screen as frames are drawn.
+const HIDE_CURSOR: &[u8] = b"\x1b[?25l";
+
+/// One connected spectator: the queue of frames its writer thread sends.
+#[derive(Debug)]
+struct Spectator {
+    frames: SyncSender<Vec<u8>>,
+}
+
+impl Spectator {
+    /// Starts the writer thread of a newly connected spectator.
+    ///
+    /// # Arguments
+    /// * `stream` - The spectator's connection.
+    ///
+    /// # Returns
+    /// The `Spectator`, or an `io::Error` if the connection cannot be set up.
+    fn start(stream: TcpStream) -> io::Result<Self> {
+        stream.set_nonblocking(false)?;
+        stream.set_write_timeout(Some(Duration::from_millis(SPECTATOR_WRITE_TIMEOUT_MS)))?;
+        let (frames, queue) = mpsc::sync_channel(SPECTATOR_QUEUE_FRAMES);
+        thread::Builder::new()
+            .name("spectator".to_string())
+            .spawn(move || Self::write_frames(stream, queue))?;
+        Ok(Self { frames })
+    }
+
+    /// Runs on the writer thread: sends queued frames until the queue closes or a write fails.
+    ///
+    /// # Arguments
+    /// * `stream` - The spectator's connection.
+    /// * `queue` - The frames to send, in order.
+    fn write_frames(stream: TcpStream, queue: Receiver<Vec<u8>>) {
+        let mut writer = BufWriter::new(stream);
+        for frame in queue {
+            if writer
+                .write_all(&frame)
+                .and_then(|_| writer.flush())
+                .is_err()
+            {
+                return; // Dropping the queue tells the server the spectator is gone.
+            }
+        }
+    }
+
+    /// Queues a frame for the spectator without waiting.
+    ///
+    /// # Returns
+    /// `false` if the spectator has disconnected or has fallen too far behind.
+    fn send(&self, frame: &[u8]) -> bool {
+        match self.frames.try_send(frame.to_vec()) {
+            Ok(()) => true,
+            Err(TrySendError::Full(_) | TrySendError::Disconnected(_)) => false,
+        }
+    }
+}
+
+/// Accepts spectators and broadcasts frames to all of them.
+#[derive(Debug)]
+pub struct SpectatorServer {
+    listener: TcpListener,
+    spectators: Vec<Spectator>,
+    last_frame: Vec<u8>, // Sent to new spectators, so they do not wait for the next frame.
+}
+
+impl SpectatorServer {
+    /// Starts listening for spectators.
+    ///
+    /// # Arguments
+    /// * `address` - The address to listen on, e.g. `("127.0.0.1", 4748)`.
+    ///
+    /// # Returns
+    /// The `SpectatorServer`, or an `io::Error` if the address cannot be bound.
+    pub fn bind(address: impl std::net::ToSocketAddrs) -> io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        listener.set_nonblocking(true)?; // Accepting must never hold up the game.
+        Ok(Self {
+            listener,
+            spectators: Vec::new(),
+            last_frame: Vec::new(),
+        })
+    }
+
+    /// Returns the address the server listens on.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Returns the number of spectators currently watching.
+    pub fn spectator_count(&self) -> usize {
+        self.spectators.len()
+    }
+
+    /// Accepts every spectator waiting to connect, and shows each the latest frame.
+    ///
+    /// Spectators that cannot be set up are turned away; this never fails.
+    pub fn accept_pending(&mut self) {
+        while let Ok((stream, _)) = self.listener.accept() {
+            let Ok(spectator) = Spectator::start(stream) else {
+                continue;
+            };
+            let greeting = [HIDE_CURSOR, self.last_frame.as_slice()].concat();
+            if spectator.send(&greeting) {
+                self.spectators.push(spectator);
+            }
+        }
+    }
+
+    /// Sends a frame to every spectator.
+    ///
+    /// Frames are only queued, never written here. A spectator that has
+    /// disconnected, or is more than `SPECTATOR_QUEUE_FRAMES` frames behind,
+    /// is dropped, so one bad connection cannot stall the game.
+    ///
+    /// # Arguments
+    /// * `frame` - The bytes drawn to the terminal for the frame.
+    pub fn broadcast(&mut self, frame: &[u8]) {
+        self.accept_pending();
+        self.spectators.retain(|spectator| spectator.send(frame));
+        self.last_frame.clear();
+        self.last_frame.extend_from_slice(frame);
+    }
+}
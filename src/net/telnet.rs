@@ -0,0 +1,338 @@
+// asciiliens/src/net/telnet.rs
+
+//! This module speaks just enough of the telnet protocol (RFC 854) to play
+//! the game over a telnet connection.
+//!
+//! `NEGOTIATION` asks the client for character mode, where every key is sent
+//! as it is pressed instead of a line at a time, and for its window size
+//! (NAWS, RFC 1073). `TelnetDecoder` turns the bytes a client sends into key
+//! presses and window sizes, and `TelnetInput` reads them from a connection
+//! as an `InputSource`.
+
+use crate::input::InputSource;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::VecDeque;
+use std::io::{self, Read};
+use std::net::TcpStream;
+use std::time::{Duration, Instant};
+
+/// "Interpret as command": starts every telnet command.
+const IAC: u8 = 255;
+/// Asks the other side not to use an option.
+const DONT: u8 = 254;
+/// Asks the other side to use an option.
+const DO: u8 = 253;
+/// Refuses to use an option.
+const WONT: u8 = 252;
+/// Offers to use an option.
+const WILL: u8 = 251;
+/// Starts a subnegotiation.
+const SB: u8 = 250;
+/// "Interrupt process": sent by some clients instead of Ctrl-C.
+const IP: u8 = 244;
+/// Ends a subnegotiation.
+const SE: u8 = 240;
+/// The echo option. The server echoing means the client does not.
+const ECHO: u8 = 1;
+/// The suppress-go-ahead option, which together with echo puts clients into character mode.
+const SUPPRESS_GO_AHEAD: u8 = 3;
+/// The window size option.
+const NAWS: u8 = 31;
+
+/// Sent to a client when it connects: the server will echo (and never does,
+/// so typed keys are not shown), will not send go-aheads, and asks for the
+/// window size.
+pub const NEGOTIATION: [u8; 9] = [IAC, WILL, ECHO, IAC, WILL, SUPPRESS_GO_AHEAD, IAC, DO, NAWS];
+
+/// Something a telnet client sent.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TelnetEvent {
+    /// A key press.
+    Key(KeyEvent),
+    /// The client's window size, sent when it connects and whenever the window is resized.
+    Resize {
+        /// The width in columns.
+        width: u16,
+        /// The height in rows.
+        height: u16,
+    },
+}
+
+/// Where the decoder is in the byte stream.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+enum DecoderState {
+    /// Plain key bytes.
+    #[default]
+    Data,
+    /// After a carriage return, which may be followed by a line feed or NUL to skip.
+    CarriageReturn,
+    /// After an escape byte, which starts an arrow key or is the Esc key itself.
+    Escape,
+    /// Inside an escape sequence such as `ESC [ D`.
+    EscapeSequence,
+    /// After `IAC`.
+    Command,
+    /// After `IAC WILL`, `WONT`, `DO` or `DONT`, waiting for the option.
+    Option,
+    /// Inside a subnegotiation.
+    Subnegotiation,
+    /// After `IAC` inside a subnegotiation.
+    SubnegotiationCommand,
+}
+
+/// Decodes the bytes a telnet client sends into `TelnetEvent`s.
+///
+/// Telnet commands are stripped out, window size reports become `Resize`
+/// events, and key bytes, including the escape sequences of the arrow keys,
+/// become key presses.
+#[derive(Debug, Default, Clone)]
+pub struct TelnetDecoder {
+    state: DecoderState,
+    sequence: Vec<u8>, // The bytes of the escape sequence or subnegotiation being read.
+}
+
+impl TelnetDecoder {
+    /// Creates a decoder at the start of a connection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decodes the next bytes received from the client.
+    ///
+    /// An escape byte at the very end is taken to be the Esc key, since the
+    /// rest of an escape sequence always arrives together with it.
+    ///
+    /// # Arguments
+    /// * `bytes` - The bytes, as read from the connection.
+    ///
+    /// # Returns
+    /// The events the bytes complete, in order.
+    pub fn feed(&mut self, bytes: &[u8]) -> Vec<TelnetEvent> {
+        let mut events = Vec::new();
+        for &byte in bytes {
+            self.decode(byte, &mut events);
+        }
+        if self.state == DecoderState::Escape {
+            events.push(key_event(KeyCode::Esc, KeyModifiers::NONE));
+            self.state = DecoderState::Data;
+        }
+        events
+    }
+
+    /// Decodes a single byte, pushing any event it completes.
+    fn decode(&mut self, byte: u8, events: &mut Vec<TelnetEvent>) {
+        match self.state {
+            DecoderState::Command => {
+                self.state = match byte {
+                    WILL | WONT | DO | DONT => DecoderState::Option,
+                    SB => {
+                        self.sequence.clear();
+                        DecoderState::Subnegotiation
+                    }
+                    IP => {
+                        events.push(key_event(KeyCode::Char('c'), KeyModifiers::CONTROL));
+                        DecoderState::Data
+                    }
+                    _ => DecoderState::Data, // Other commands, and an escaped 255, mean nothing to the game.
+                };
+            }
+            DecoderState::Option => self.state = DecoderState::Data, // The client's answers need no reply.
+            DecoderState::Subnegotiation => match byte {
+                IAC => self.state = DecoderState::SubnegotiationCommand,
+                _ => self.sequence.push(byte),
+            },
+            DecoderState::SubnegotiationCommand => match byte {
+                IAC => {
+                    self.sequence.push(IAC); // An escaped 255, e.g. in a window size.
+                    self.state = DecoderState::Subnegotiation;
+                }
+                SE => {
+                    if let [NAWS, w1, w0, h1, h0] = self.sequence[..] {
+                        events.push(TelnetEvent::Resize {
+                            width: u16::from_be_bytes([w1, w0]),
+                            height: u16::from_be_bytes([h1, h0]),
+                        });
+                    }
+                    self.state = DecoderState::Data;
+                }
+                _ => self.state = DecoderState::Data,
+            },
+            _ if byte == IAC => self.state = DecoderState::Command,
+            DecoderState::CarriageReturn => {
+                self.state = DecoderState::Data;
+                if byte != b'\n' && byte != 0 {
+                    self.decode(byte, events);
+                }
+            }
+            DecoderState::Escape => {
+                if byte == b'[' || byte == b'O' {
+                    self.sequence.clear();
+                    self.state = DecoderState::EscapeSequence;
+                } else {
+                    events.push(key_event(KeyCode::Esc, KeyModifiers::NONE));
+                    self.state = DecoderState::Data;
+                    self.decode(byte, events);
+                }
+            }
+            DecoderState::EscapeSequence => {
+                if !(0x40..=0x7e).contains(&byte) {
+                    self.sequence.push(byte); // A parameter, as in `ESC [ 3 ~`.
+                    return;
+                }
+                let code = match (byte, &self.sequence[..]) {
+                    (b'A', _) => Some(KeyCode::Up),
+                    (b'B', _) => Some(KeyCode::Down),
+                    (b'C', _) => Some(KeyCode::Right),
+                    (b'D', _) => Some(KeyCode::Left),
+                    (b'~', b"3") => Some(KeyCode::Delete),
+                    _ => None, // Other function keys are not used by the game.
+                };
+                if let Some(code) = code {
+                    events.push(key_event(code, KeyModifiers::NONE));
+                }
+                self.state = DecoderState::Data;
+            }
+            DecoderState::Data => match byte {
+                0x1b => self.state = DecoderState::Escape,
+                b'\r' => {
+                    events.push(key_event(KeyCode::Enter, KeyModifiers::NONE));
+                    self.state = DecoderState::CarriageReturn;
+                }
+                b'\n' => events.push(key_event(KeyCode::Enter, KeyModifiers::NONE)),
+                b'\t' => events.push(key_event(KeyCode::Tab, KeyModifiers::NONE)),
+                0x08 | 0x7f => events.push(key_event(KeyCode::Backspace, KeyModifiers::NONE)),
+                0x01..=0x1a => {
+                    let letter = char::from(b'a' + byte - 1); // Ctrl-A is 1, Ctrl-C is 3.
+                    events.push(key_event(KeyCode::Char(letter), KeyModifiers::CONTROL));
+                }
+                0x20..=0x7e => events.push(key_event(
+                    KeyCode::Char(char::from(byte)),
+                    KeyModifiers::NONE,
+                )),
+                _ => {} // Other control bytes and non-ASCII characters are not used by the game.
+            },
+        }
+    }
+}
+
+/// Wraps a key press as a `TelnetEvent`.
+fn key_event(code: KeyCode, modifiers: KeyModifiers) -> TelnetEvent {
+    TelnetEvent::Key(KeyEvent::new(code, modifiers))
+}
+
+/// Reads key presses from a telnet connection.
+///
+/// Window size reports are kept, so the size of the client's terminal can be
+/// checked at any time. Once the client disconnects, `next_key` returns an
+/// `UnexpectedEof` error, which ends a scene manager's run.
+#[derive(Debug)]
+pub struct TelnetInput {
+    stream: TcpStream,
+    decoder: TelnetDecoder,
+    pending: VecDeque<KeyEvent>, // Keys received but not yet returned.
+    size: Option<(u16, u16)>,    // The last window size reported, as columns and rows.
+}
+
+impl TelnetInput {
+    /// Creates an input source reading from the given connection.
+    ///
+    /// # Arguments
+    /// * `stream` - The connection to the telnet client.
+    pub fn new(stream: TcpStream) -> Self {
+        Self {
+            stream,
+            decoder: TelnetDecoder::new(),
+            pending: VecDeque::new(),
+            size: None,
+        }
+    }
+
+    /// Returns the client's window size as columns and rows, or `None` if it has not reported one.
+    pub fn size(&self) -> Option<(u16, u16)> {
+        self.size
+    }
+
+    /// Waits for the client to report its window size. Keys pressed meanwhile are kept.
+    ///
+    /// # Arguments
+    /// * `timeout` - How long to wait; clients that do not support NAWS never report a size.
+    ///
+    /// # Returns
+    /// The window size, or `None` if none was reported in time.
+    pub fn wait_for_size(&mut self, timeout: Duration) -> io::Result<Option<(u16, u16)>> {
+        let deadline = Instant::now() + timeout;
+        while self.size.is_none() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() || !self.read_events(Some(remaining))? {
+                break;
+            }
+        }
+        Ok(self.size)
+    }
+
+    /// Reads and decodes whatever the client sends next.
+    ///
+    /// # Arguments
+    /// * `timeout` - How long to wait, or `None` to wait until something arrives.
+    ///
+    /// # Returns
+    /// `Ok(true)` if anything was read, `Ok(false)` if the timeout passed first,
+    /// or an `UnexpectedEof` error if the client disconnected.
+    fn read_events(&mut self, timeout: Option<Duration>) -> io::Result<bool> {
+        self.stream.set_read_timeout(timeout)?;
+        let mut buffer = [0; 256];
+        let read = loop {
+            match self.stream.read(&mut buffer) {
+                Ok(read) => break read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e)
+                    if matches!(
+                        e.kind(),
+                        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                    ) =>
+                {
+                    return Ok(false);
+                }
+                Err(e) => return Err(e),
+            }
+        };
+        if read == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "the player disconnected",
+            ));
+        }
+        for event in self.decoder.feed(&buffer[..read]) {
+            match event {
+                TelnetEvent::Key(key) => self.pending.push_back(key),
+                TelnetEvent::Resize { width, height } => self.size = Some((width, height)),
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl InputSource for TelnetInput {
+    /// Telnet commands and size reports are handled without restarting the timeout.
+    fn next_key(&mut self, timeout: Option<Duration>) -> io::Result<Option<KeyEvent>> {
+        let deadline = timeout.map(|t| Instant::now() + t);
+        loop {
+            if let Some(key) = self.pending.pop_front() {
+                return Ok(Some(key));
+            }
+            let remaining = match deadline {
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    if remaining.is_zero() {
+                        return Ok(None); // The timeout passed.
+                    }
+                    Some(remaining)
+                }
+                None => None,
+            };
+            if !self.read_events(remaining)? {
+                return Ok(None);
+            }
+        }
+    }
+}
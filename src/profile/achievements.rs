@@ -0,0 +1,95 @@
+// asciiliens/src/profile/achievements.rs
+
+//! This module defines the achievements a player can unlock and the conditions
+//! under which each one is earned. Conditions are evaluated against the state
+//! of a `Game` after each update.
+
+use crate::game::{Game, GameState};
+
+/// An achievement that can be unlocked once and is then kept in the player's profile.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Achievement {
+    /// Destroy an ASCIIlien for the first time.
+    FirstBlood,
+    /// Hit five aliens in a row without a miss.
+    Sharpshooter,
+    /// Win a game without moving the ship once.
+    Immovable,
+    /// Win a game with every blast hitting an alien.
+    PerfectAim,
+    /// Clear a wave with a score above the score the game started with.
+    InTheBlack,
+}
+
+impl Achievement {
+    /// Every achievement, in the order they are listed on the profile screen.
+    pub const ALL: [Achievement; 5] = [
+        Achievement::FirstBlood,
+        Achievement::Sharpshooter,
+        Achievement::Immovable,
+        Achievement::PerfectAim,
+        Achievement::InTheBlack,
+    ];
+
+    /// Returns the stable identifier used to store the achievement in the profile file.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Achievement::FirstBlood => "first_blood",
+            Achievement::Sharpshooter => "sharpshooter",
+            Achievement::Immovable => "immovable",
+            Achievement::PerfectAim => "perfect_aim",
+            Achievement::InTheBlack => "in_the_black",
+        }
+    }
+
+    /// Looks up an achievement by its stored identifier.
+    ///
+    /// # Returns
+    /// `Some(Achievement)` if the identifier is known, `None` otherwise.
+    pub fn from_id(id: &str) -> Option<Achievement> {
+        Achievement::ALL.iter().copied().find(|a| a.id() == id)
+    }
+
+    /// Returns the achievement's display name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Achievement::FirstBlood => "First Blood",
+            Achievement::Sharpshooter => "Sharpshooter",
+            Achievement::Immovable => "Immovable Object",
+            Achievement::PerfectAim => "Perfect Aim",
+            Achievement::InTheBlack => "In the Black",
+        }
+    }
+
+    /// Returns a short description of how to unlock the achievement.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Achievement::FirstBlood => "Destroy your first ASCIIlien.",
+            Achievement::Sharpshooter => "Hit 5 ASCIIliens in a row without missing.",
+            Achievement::Immovable => "Win without moving your ship.",
+            Achievement::PerfectAim => "Win with 100% accuracy.",
+            Achievement::InTheBlack => "Clear a wave with more points than you started with.",
+        }
+    }
+
+    /// Checks whether the current state of a game meets this achievement's condition.
+    ///
+    /// # Arguments
+    /// * `game` - The `Game` to evaluate, typically right after an update.
+    ///
+    /// # Returns
+    /// `true` if the achievement is earned by this game.
+    pub fn is_met_by(&self, game: &Game) -> bool {
+        let stats = game.stats();
+        let won = game.state() == GameState::Win;
+        match self {
+            Achievement::FirstBlood => stats.kills > 0,
+            Achievement::Sharpshooter => stats.longest_streak >= 5,
+            Achievement::Immovable => won && stats.moves_made == 0,
+            Achievement::PerfectAim => {
+                won && stats.shots_fired > 0 && stats.hits == stats.shots_fired
+            }
+            Achievement::InTheBlack => won && game.score() > game.scoring().initial_score,
+        }
+    }
+}
@@ -0,0 +1,162 @@
+// asciiliens/src/profile/mod.rs
+
+//! This module manages the player's lifetime profile: statistics aggregated
+//! across every session and the achievements unlocked so far. The profile is
+//! persisted as a simple `key=value` text file in the user's data directory.
+
+pub mod achievements;
+
+use self::achievements::Achievement;
+use crate::game::{Game, GameState};
+use crate::util::paths;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The name of the profile file inside the game's data directory.
+const PROFILE_FILE_NAME: &str = "profile.txt";
+
+/// Statistics and achievements aggregated across all of a player's sessions.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Profile {
+    /// The number of games that ran to a win or a loss.
+    pub games_played: u32,
+    /// The number of games won.
+    pub wins: u32,
+    /// The total number of aliens destroyed across all games.
+    pub aliens_destroyed: u32,
+    /// The best accuracy percentage achieved in a game with at least one blast unleashed.
+    pub best_accuracy: Option<f64>,
+    /// The achievements unlocked so far, in the order they were unlocked.
+    pub achievements: Vec<Achievement>,
+}
+
+impl Profile {
+    /// Returns the default location of the profile file, inside the user's data directory.
+    ///
+    /// # Returns
+    /// `Some(PathBuf)` for the profile file, or `None` if no data directory is known.
+    pub fn default_path() -> Option<PathBuf> {
+        paths::data_file(PROFILE_FILE_NAME)
+    }
+
+    /// Loads the profile from the given file.
+    ///
+    /// A missing file yields a fresh profile, and unknown or malformed lines are
+    /// ignored, so a damaged profile never prevents the game from starting.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the profile file.
+    ///
+    /// # Returns
+    /// The loaded `Profile`.
+    pub fn load_from(path: &Path) -> Profile {
+        match fs::read_to_string(path) {
+            Ok(contents) => Profile::parse(&contents),
+            Err(_) => Profile::default(),
+        }
+    }
+
+    /// Parses a profile from the contents of a profile file.
+    fn parse(contents: &str) -> Profile {
+        let mut profile = Profile::default();
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim() {
+                "games_played" => profile.games_played = value.parse().unwrap_or(0),
+                "wins" => profile.wins = value.parse().unwrap_or(0),
+                "aliens_destroyed" => profile.aliens_destroyed = value.parse().unwrap_or(0),
+                "best_accuracy" => profile.best_accuracy = value.parse().ok(),
+                "achievements" => {
+                    profile.achievements = value
+                        .split(',')
+                        .filter_map(|id| Achievement::from_id(id.trim()))
+                        .collect();
+                }
+                _ => { /* Ignore unknown keys. */ }
+            }
+        }
+        profile
+    }
+
+    /// Saves the profile to the given file, creating its directory if needed.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the profile file.
+    ///
+    /// # Returns
+    /// An `io::Result<()>` indicating success or failure of the write.
+    pub fn save_to(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.serialize())
+    }
+
+    /// Serializes the profile into the `key=value` file format.
+    fn serialize(&self) -> String {
+        let achievements: Vec<&str> = self.achievements.iter().map(|a| a.id()).collect();
+        let mut contents = format!(
+            "games_played={}\nwins={}\naliens_destroyed={}\n",
+            self.games_played, self.wins, self.aliens_destroyed
+        );
+        if let Some(accuracy) = self.best_accuracy {
+            contents.push_str(&format!("best_accuracy={:.1}\n", accuracy));
+        }
+        contents.push_str(&format!("achievements={}\n", achievements.join(",")));
+        contents
+    }
+
+    /// Returns whether the given achievement has been unlocked.
+    pub fn has_unlocked(&self, achievement: Achievement) -> bool {
+        self.achievements.contains(&achievement)
+    }
+
+    /// Evaluates every locked achievement against the game and unlocks those now met.
+    ///
+    /// Call this after each `Game::update` so achievements unlock the moment they are earned.
+    ///
+    /// # Arguments
+    /// * `game` - The `Game` being played.
+    ///
+    /// # Returns
+    /// The achievements unlocked by this call, for displaying as toasts.
+    pub fn check_achievements(&mut self, game: &Game) -> Vec<Achievement> {
+        let newly_unlocked: Vec<Achievement> = Achievement::ALL
+            .iter()
+            .copied()
+            .filter(|a| !self.has_unlocked(*a) && a.is_met_by(game))
+            .collect();
+        self.achievements.extend(newly_unlocked.iter().copied());
+        newly_unlocked
+    }
+
+    /// Adds a finished game's statistics to the lifetime totals.
+    ///
+    /// Games that were quit before ending are not counted as played, but the
+    /// aliens destroyed in them still count.
+    ///
+    /// # Arguments
+    /// * `game` - The finished `Game`.
+    pub fn record_game(&mut self, game: &Game) {
+        let stats = game.stats();
+        match game.state() {
+            GameState::Win => {
+                self.games_played += 1;
+                self.wins += 1;
+            }
+            GameState::GameOver => self.games_played += 1,
+            GameState::Playing | GameState::Quit => {}
+        }
+        self.aliens_destroyed = self.aliens_destroyed.saturating_add(stats.kills);
+        if stats.shots_fired > 0 {
+            let accuracy = stats.accuracy_percent();
+            if self.best_accuracy.is_none_or(|best| accuracy > best) {
+                self.best_accuracy = Some(accuracy);
+            }
+        }
+    }
+}
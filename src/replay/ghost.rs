@@ -0,0 +1,67 @@
+// asciiliens/src/replay/ghost.rs
+
+//! This module re-simulates a previous run alongside a live game, so its
+//! ship can be shown as a "ghost" to race against.
+//!
+//! A `Ghost` owns a second `Game`, created from the stored run's options and
+//! fed the run's events one by one as the live game advances, so on every
+//! frame it knows where the previous run's ship was.
+
+use super::Replay;
+use crate::game::{Game, GameEvent, GameState};
+
+/// A previous run, played back in step with a live game.
+#[derive(Debug)]
+pub struct Ghost {
+    game: Game,
+    events: Vec<GameEvent>,
+    next: usize, // The index of the next event to feed to `game`.
+}
+
+impl Ghost {
+    /// Starts playing back the given run from its first frame.
+    ///
+    /// # Arguments
+    /// * `replay` - The recording of the previous run.
+    pub fn new(replay: Replay) -> Self {
+        Self {
+            game: Game::with_options(replay.options),
+            events: replay.events,
+            next: 0,
+        }
+    }
+
+    /// Returns the game being played back.
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Plays the run forward until it reaches the given frame, or runs out of events.
+    ///
+    /// # Arguments
+    /// * `frame` - The frame the live game is on.
+    pub fn catch_up(&mut self, frame: u64) {
+        while self.game.frame_counter() < frame && self.next < self.events.len() {
+            self.game.update(self.events[self.next]);
+            self.next += 1;
+        }
+    }
+
+    /// Plays the run again from its first frame up to the given frame, for
+    /// when the live game has been rewound.
+    ///
+    /// # Arguments
+    /// * `frame` - The frame the live game is back on.
+    pub fn rewind_to(&mut self, frame: u64) {
+        self.game = Game::with_options(self.game.options());
+        self.next = 0;
+        self.catch_up(frame);
+    }
+
+    /// Returns the center x-coordinate of the ghost ship, or `None` once the
+    /// run has ended or its ship has been destroyed.
+    pub fn ship_x(&self) -> Option<u16> {
+        let player = self.game.player();
+        (self.game.state() == GameState::Playing && !player.destroyed()).then(|| player.x())
+    }
+}
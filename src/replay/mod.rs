@@ -0,0 +1,221 @@
+// asciiliens/src/replay/mod.rs
+
+//! This module records and plays back games. Because a seeded `Game` is fully
+//! deterministic, a replay only needs the game's options and the sequence of
+//! `GameEvent`s it received.
+//!
+//! Replays are stored as a small text file:
+//!
+//! ```text
+//! asciiliens-replay 1
+//! seed=1234
+//! difficulty=normal
+//! level=1
+//! co_op=false
+//! fire_rule=unlimited
+//! events=LLRF..F.Q
+//! ```
+//!
+//! The best run of each seeded level is also kept, in a `ghosts` directory,
+//! for the `ghost` submodule to play back beside later attempts.
+
+pub mod ghost;
+
+use crate::game::difficulty::Difficulty;
+use crate::game::options::GameOptions;
+use crate::game::rules::FireRule;
+use crate::game::{Game, GameEvent};
+use crate::util::paths;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The first line of every replay file.
+const REPLAY_HEADER: &str = "asciiliens-replay 1";
+/// The name of the file the most recent game is saved to, inside the data directory.
+const LAST_REPLAY_FILE_NAME: &str = "last.replay";
+/// The name of the directory the best run of each seeded level is kept in, inside the data directory.
+const GHOSTS_DIR_NAME: &str = "ghosts";
+
+/// A recorded game: the options it was created with and every event it received.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Replay {
+    /// The options the game was created with. The seed is always `Some`.
+    pub options: GameOptions,
+    /// The events fed to `Game::update`, in order.
+    pub events: Vec<GameEvent>,
+}
+
+impl Replay {
+    /// Starts an empty recording for the given game.
+    ///
+    /// # Arguments
+    /// * `game` - The freshly created `Game` about to be recorded.
+    ///
+    /// # Returns
+    /// A `Replay` with the game's resolved options and no events.
+    pub fn record(game: &Game) -> Self {
+        Self {
+            options: game.options(),
+            events: Vec::new(),
+        }
+    }
+
+    /// Returns the default location of the most recent game's replay.
+    pub fn last_replay_path() -> Option<PathBuf> {
+        paths::data_file(LAST_REPLAY_FILE_NAME)
+    }
+
+    /// Returns the default directory the best run of each seeded level is kept in.
+    pub fn ghosts_dir() -> Option<PathBuf> {
+        paths::data_file(GHOSTS_DIR_NAME)
+    }
+
+    /// Returns the name of the file the best run with the given options is kept in.
+    ///
+    /// # Arguments
+    /// * `options` - The options of the run. Unseeded options share seed 0.
+    ///
+    /// # Returns
+    /// A file name such as `1234-normal-1.replay`, or `1234-normal-1-max3.replay`
+    /// for a game played by a fire rule other than the default.
+    pub fn ghost_file_name(options: &GameOptions) -> String {
+        let fire_rule = match options.fire_rule {
+            FireRule::Unlimited => String::new(),
+            rule => format!("-{}", rule.to_string().replace(':', "")),
+        };
+        format!(
+            "{}-{}-{}{}.replay",
+            options.seed.unwrap_or(0),
+            options.difficulty,
+            options.level,
+            fire_rule
+        )
+    }
+
+    /// Re-creates the recorded game and feeds it every recorded event.
+    ///
+    /// # Returns
+    /// The `Game` in the state it was in when the recording ended.
+    pub fn play_out(&self) -> Game {
+        let mut game = Game::with_options(self.options);
+        for event in &self.events {
+            game.update(*event);
+        }
+        game
+    }
+
+    /// Serializes the replay into the replay file format.
+    pub fn serialize(&self) -> String {
+        let events: String = self.events.iter().map(|e| event_to_char(*e)).collect();
+        format!(
+            "{}\nseed={}\ndifficulty={}\nlevel={}\nco_op={}\nfire_rule={}\nevents={}\n",
+            REPLAY_HEADER,
+            self.options.seed.unwrap_or_default(),
+            self.options.difficulty,
+            self.options.level,
+            self.options.co_op,
+            self.options.fire_rule,
+            events
+        )
+    }
+
+    /// Parses a replay from the contents of a replay file.
+    ///
+    /// # Returns
+    /// The `Replay`, or a message describing why the contents are not a valid replay.
+    pub fn parse(contents: &str) -> Result<Replay, String> {
+        let mut lines = contents.lines();
+        if lines.next().map(str::trim) != Some(REPLAY_HEADER) {
+            return Err("not an asciiliens replay file".to_string());
+        }
+
+        let mut seed = None;
+        let mut options = GameOptions::default();
+        let mut events = Vec::new();
+        for line in lines {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim() {
+                "seed" => {
+                    seed = Some(
+                        value
+                            .parse()
+                            .map_err(|_| format!("invalid seed '{}'", value))?,
+                    );
+                }
+                "difficulty" => options.difficulty = value.parse::<Difficulty>()?,
+                "level" => {
+                    options.level = value
+                        .parse()
+                        .map_err(|_| format!("invalid level '{}'", value))?;
+                }
+                "co_op" => {
+                    options.co_op = value
+                        .parse()
+                        .map_err(|_| format!("invalid co_op '{}'", value))?;
+                }
+                "fire_rule" => options.fire_rule = value.parse::<FireRule>()?,
+                "events" => {
+                    events = value
+                        .chars()
+                        .map(|c| char_to_event(c).ok_or_else(|| format!("invalid event '{}'", c)))
+                        .collect::<Result<_, _>>()?;
+                }
+                _ => { /* Ignore unknown keys. */ }
+            }
+        }
+
+        // Without its seed, a replay cannot be reproduced.
+        options.seed = Some(seed.ok_or_else(|| "replay has no seed".to_string())?);
+        Ok(Replay { options, events })
+    }
+
+    /// Loads a replay from the given file.
+    ///
+    /// # Returns
+    /// The `Replay`, or an `io::Error` if the file cannot be read or is not a valid replay.
+    pub fn load_from(path: &Path) -> io::Result<Replay> {
+        let contents = fs::read_to_string(path)?;
+        Replay::parse(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Saves the replay to the given file, creating its directory if needed.
+    pub fn save_to(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.serialize())
+    }
+}
+
+/// Encodes a `GameEvent` as the single character used in replay files.
+pub(crate) fn event_to_char(event: GameEvent) -> char {
+    match event {
+        GameEvent::MoveLeft => 'L',
+        GameEvent::MoveRight => 'R',
+        GameEvent::Fire => 'F',
+        GameEvent::Quit => 'Q',
+        GameEvent::AdvanceFrame => '.',
+        GameEvent::SecondMoveLeft => 'l',
+        GameEvent::SecondMoveRight => 'r',
+        GameEvent::SecondFire => 'f',
+    }
+}
+
+/// Decodes a replay file character back into a `GameEvent`.
+pub(crate) fn char_to_event(c: char) -> Option<GameEvent> {
+    match c {
+        'L' => Some(GameEvent::MoveLeft),
+        'R' => Some(GameEvent::MoveRight),
+        'F' => Some(GameEvent::Fire),
+        'Q' => Some(GameEvent::Quit),
+        '.' => Some(GameEvent::AdvanceFrame),
+        'l' => Some(GameEvent::SecondMoveLeft),
+        'r' => Some(GameEvent::SecondMoveRight),
+        'f' => Some(GameEvent::SecondFire),
+        _ => None,
+    }
+}
@@ -0,0 +1,471 @@
+// asciiliens/src/scene/game.rs
+
+//! The gameplay scene: feeds key presses (and, in real-time mode, timer ticks)
+//! to a `Game`, records the replay, announces achievements, and saves the
+//! results once the game ends. A won or lost game lets its last effects play
+//! out before the results are shown.
+//!
+//! A game can also be one turn of a match, in which case the scene running
+//! the match takes over once the game ends, or a co-op game, in which two
+//! players fly a ship each with their own keys. A run of the daily challenge
+//! is entered into the daily leaderboard instead of the high scores.
+//!
+//! The best run of each seeded level is kept, and with the ghost setting on,
+//! later attempts show its ship as a dim ghost to race against.
+//!
+//! In turn-based mode, the rewind key steps back one turn at a time. A game
+//! that was rewound is practice: it is not entered into the high scores, the
+//! daily leaderboard or the ghost runs.
+
+use super::pause::PauseScene;
+use super::results::ResultsScene;
+use super::{Scene, SceneContext, Transition};
+use crate::cli::PlayMode;
+use crate::config::keys::{KeyAction, KeyBindings};
+use crate::daily::{DailyChallenge, DailyEntry, DailyLeaderboard};
+use crate::display::{achievement_toast, draw_corner_label, draw_toast, terminal};
+use crate::game::options::GameOptions;
+use crate::game::{Game, GameEvent, GameState};
+use crate::profile::achievements::Achievement;
+use crate::replay::ghost::Ghost;
+use crate::replay::Replay;
+use crate::scores::{HighScoreEntry, HighScores};
+use crate::util::constants::{EFFECT_FRAME_MS, REALTIME_TICK_MS};
+use crossterm::event::{KeyCode, KeyEvent};
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// The number of frames an achievement toast stays on screen.
+const TOAST_FRAMES: u8 = 5;
+
+/// The number of turns that can be rewound.
+const REWIND_HISTORY: usize = 100;
+
+/// The label shown in the corner once a game has been rewound.
+const PRACTICE_LABEL: &str = "PRACTICE";
+
+/// The label shown on the results screen of a game that was rewound.
+const PRACTICE_RESULTS_LABEL: &str = "PRACTICE RUN: NOT RANKED";
+
+/// A game being played.
+#[derive(Debug)]
+pub struct GameScene {
+    game: Game,
+    replay: Replay,                   // The recording of this game.
+    unlocked: Vec<Achievement>,       // Achievements unlocked during this game.
+    toast: Option<(String, u8)>,      // The toast on screen and the frames it has left.
+    tick_interval: Option<Duration>,  // Set in real-time mode.
+    ending: bool,                     // Set while the effects of a finished game play out.
+    turn: Option<String>,             // For a turn of a match, the label shown in the corner.
+    over: bool,                       // Set once a turn of a match has ended.
+    ghost_path: Option<PathBuf>,      // Where the best run of this seeded level is kept.
+    ghost: Option<Ghost>,             // The best run, played back beside this one.
+    history: VecDeque<(Game, usize)>, // The game before each recent turn, with the replay's length then.
+    rewound: bool,                    // Set once a turn has been rewound.
+}
+
+impl GameScene {
+    /// Starts a new game with the context's options and play mode.
+    ///
+    /// # Arguments
+    /// * `context` - The state shared by all scenes.
+    pub fn new(context: &SceneContext) -> Self {
+        Self::build(context, context.game_options(), None)
+    }
+
+    /// Starts a co-op game with the context's options: two ships share the
+    /// field, the second one flown with the co-op keys from the settings.
+    ///
+    /// Co-op games do not enter the high scores, which are for one player.
+    ///
+    /// # Arguments
+    /// * `context` - The state shared by all scenes.
+    pub fn co_op(context: &SceneContext) -> Self {
+        let options = GameOptions {
+            co_op: true,
+            ..context.game_options()
+        };
+        Self::build(context, options, None)
+    }
+
+    /// Starts a game played as one turn of a match.
+    ///
+    /// Once the game ends, the scene stays active and `is_over` returns `true`,
+    /// so the scene running the match can read the result. Turns are not saved
+    /// and do not count towards the profile or high scores.
+    ///
+    /// # Arguments
+    /// * `context` - The state shared by all scenes.
+    /// * `options` - The options of this turn's game.
+    /// * `label` - The label shown in the corner, e.g. `"PLAYER 2"`.
+    pub fn for_turn(context: &SceneContext, options: GameOptions, label: String) -> Self {
+        Self::build(context, options, Some(label))
+    }
+
+    /// Creates the scene for a game with the given options.
+    ///
+    /// A seeded game of one player outside a match is raced against the best
+    /// previous run of its level, if the ghost setting is on and there is one.
+    fn build(context: &SceneContext, options: GameOptions, turn: Option<String>) -> Self {
+        let game = Game::with_options(options);
+        let replay = Replay::record(&game);
+        let ghost_path = match &context.ghosts_dir {
+            Some(dir) if options.seed.is_some() && !options.co_op && turn.is_none() => {
+                Some(dir.join(Replay::ghost_file_name(&replay.options)))
+            }
+            _ => None,
+        };
+        let ghost = ghost_path
+            .as_deref()
+            .filter(|_| context.settings.ghost)
+            .and_then(|path| Replay::load_from(path).ok())
+            .filter(|best| best.options == replay.options) // A renamed file is not this level's run.
+            .map(Ghost::new);
+        let mut scene = Self {
+            game,
+            replay,
+            unlocked: Vec::new(),
+            toast: None,
+            tick_interval: None, // Set from the play mode by `apply_settings`.
+            ending: false,
+            turn,
+            over: false,
+            ghost_path,
+            ghost,
+            history: VecDeque::new(),
+            rewound: false,
+        };
+        scene
+            .game
+            .set_ghost_x(scene.ghost.as_ref().and_then(Ghost::ship_x));
+        scene.apply_settings(context);
+        scene
+    }
+
+    /// Applies the display settings and the play mode to the game. They can
+    /// change while the game is paused; the difficulty, fire rule and ghost
+    /// run only apply to the next game.
+    fn apply_settings(&mut self, context: &SceneContext) {
+        let settings = &context.settings;
+        self.game.set_starfield_visible(settings.show_starfield());
+        self.game.set_preview_visible(settings.preview);
+        self.game.set_reduced_motion(settings.reduced_motion);
+        self.tick_interval =
+            (settings.mode == PlayMode::Realtime).then(|| Duration::from_millis(REALTIME_TICK_MS));
+    }
+
+    /// Returns whether this game was played as a turn of a match and has ended.
+    pub fn is_over(&self) -> bool {
+        self.over
+    }
+
+    /// Returns the game being played.
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Returns whether a turn of this game has been rewound, making it a practice run.
+    pub fn is_rewound(&self) -> bool {
+        self.rewound
+    }
+
+    /// Returns whether turns of this game can be rewound: only in turn-based
+    /// mode, and never in a turn of a match.
+    fn can_rewind(&self) -> bool {
+        self.tick_interval.is_none() && self.turn.is_none()
+    }
+
+    /// Steps back one turn, restoring the game as it was before it and
+    /// dropping the turn from the replay. This also undoes the move that ended
+    /// a game whose last effects are still playing out.
+    ///
+    /// # Returns
+    /// `true` if a turn was rewound, or `false` if there was none left to rewind.
+    fn rewind(&mut self) -> bool {
+        let Some((game, events)) = self.history.pop_back() else {
+            return false;
+        };
+        self.game = game;
+        self.replay.events.truncate(events);
+        if let Some(ghost) = &mut self.ghost {
+            ghost.rewind_to(self.game.frame_counter());
+            self.game.set_ghost_x(ghost.ship_x());
+        }
+        self.ending = false;
+        self.rewound = true;
+        self.toast = None;
+        true
+    }
+
+    /// Maps a key press during play to the `GameEvent` it triggers, using the player's key bindings.
+    ///
+    /// Esc always quits, as does Ctrl-C: raw mode delivers it as a key press
+    /// instead of raising SIGINT. The pause key is handled before this is called.
+    /// In a co-op game, keys the first ship does not use are looked up in the
+    /// second ship's bindings.
+    fn key_to_event(
+        key: KeyEvent,
+        keys: &KeyBindings,
+        co_op_keys: Option<&KeyBindings>,
+    ) -> GameEvent {
+        if terminal::is_interrupt(&key) || key.code == KeyCode::Esc {
+            return GameEvent::Quit;
+        }
+        let second_ship = co_op_keys.and_then(|keys| keys.action_for(key.code));
+        match (keys.action_for(key.code), second_ship) {
+            (Some(KeyAction::MoveLeft), _) => GameEvent::MoveLeft,
+            (Some(KeyAction::MoveRight), _) => GameEvent::MoveRight,
+            (Some(KeyAction::Fire), _) => GameEvent::Fire,
+            (Some(KeyAction::Quit), _) => GameEvent::Quit,
+            (None, Some(KeyAction::MoveLeft)) => GameEvent::SecondMoveLeft,
+            (None, Some(KeyAction::MoveRight)) => GameEvent::SecondMoveRight,
+            (None, Some(KeyAction::Fire)) => GameEvent::SecondFire,
+            _ => GameEvent::AdvanceFrame, // Any other key simply advances the game frame.
+        }
+    }
+
+    /// Feeds one event to the game and records it, switching to the results once the game ends.
+    fn step(&mut self, event: GameEvent, context: &mut SceneContext) -> Transition {
+        if self.can_rewind() {
+            if self.history.len() == REWIND_HISTORY {
+                self.history.pop_front();
+            }
+            self.history
+                .push_back((self.game.clone(), self.replay.events.len()));
+        }
+        let hits_before = self.game.stats().hits;
+        self.game.update(event);
+        self.replay.events.push(event);
+        if let Some(ghost) = &mut self.ghost {
+            ghost.catch_up(self.game.frame_counter());
+            self.game.set_ghost_x(ghost.ship_x());
+        }
+        if self.game.stats().hits > hits_before {
+            context.ring_bell(); // An alien was hit.
+        }
+
+        // Unlock any achievements earned this frame and announce the latest one.
+        self.toast = self
+            .toast
+            .take()
+            .and_then(|(text, frames)| (frames > 1).then(|| (text, frames - 1)));
+        if self.turn.is_none() && !self.rewound {
+            let newly_unlocked = context.profile.check_achievements(&self.game);
+            if let Some(latest) = newly_unlocked.last() {
+                self.toast = Some((achievement_toast(*latest), TOAST_FRAMES));
+            }
+            self.unlocked.extend(newly_unlocked);
+        }
+
+        match self.game.state() {
+            GameState::Playing => Transition::None,
+            GameState::Win | GameState::GameOver | GameState::Quit => {
+                if self.game.state() == GameState::Quit {
+                    return self.end(context);
+                }
+                context.ring_bell();
+                if self.game.effects().is_active() && !context.settings.reduced_motion {
+                    self.ending = true; // Let the debris settle and the ship flash first.
+                    return Transition::None;
+                }
+                self.end(context)
+            }
+        }
+    }
+
+    /// Saves the finished game and switches to the results. A turn of a match
+    /// is only marked as over, for the match to take over.
+    fn end(&mut self, context: &mut SceneContext) -> Transition {
+        self.ending = false;
+        if self.turn.is_some() {
+            self.over = true;
+            return Transition::None;
+        }
+        let daily_label = self.finish(context);
+        let unlocked = std::mem::take(&mut self.unlocked);
+        let mut results = ResultsScene::new(&self.game, unlocked);
+        if let Some(label) = daily_label {
+            results = results.with_label(label);
+        }
+        Transition::Switch(Box::new(results))
+    }
+
+    /// Saves the replay, enters a finished game into the high scores (or a
+    /// daily run into the daily leaderboard) and folds the game into the
+    /// lifetime profile, unless it was rewound. Failures are kept as warnings.
+    ///
+    /// # Returns
+    /// For a finished daily run, the label showing its rank on the results
+    /// screen, and for a rewound game, the label marking it as practice.
+    fn finish(&self, context: &mut SceneContext) -> Option<String> {
+        if let Some(path) = &context.replay_path {
+            if let Err(e) = self.replay.save_to(path) {
+                context.warnings.push(format!(
+                    "Could not save replay to {}: {}",
+                    path.display(),
+                    e
+                ));
+            }
+        }
+
+        // Co-op games are not entered anywhere: the tables are for one player.
+        // Neither are rewound games, which are practice.
+        let finished = matches!(self.game.state(), GameState::Win | GameState::GameOver)
+            && !self.game.options().co_op
+            && !self.rewound;
+        let mut daily_label = self.rewound.then(|| PRACTICE_RESULTS_LABEL.to_string());
+        match context.daily {
+            Some(daily) if finished => daily_label = Some(self.record_daily(daily, context)),
+            None if finished => {
+                if let Some(path) = &context.high_scores_path {
+                    let entry = HighScoreEntry::from_game(&self.game);
+                    if let Err(e) = HighScores::record_in(path, entry) {
+                        context.warnings.push(format!(
+                            "Could not save high scores to {}: {}",
+                            path.display(),
+                            e
+                        ));
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        if finished {
+            self.keep_if_best(context);
+        }
+
+        // A rewound game's kills and accuracy do not count towards the profile either.
+        if self.rewound {
+            return daily_label;
+        }
+        context.profile.record_game(&self.game);
+        if let Some(path) = &context.profile_path {
+            if let Err(e) = context.profile.save_to(path) {
+                context.warnings.push(format!(
+                    "Could not save profile to {}: {}",
+                    path.display(),
+                    e
+                ));
+            }
+        }
+        daily_label
+    }
+
+    /// Keeps the replay of a finished seeded game as its level's ghost, if it
+    /// beat the best previous run. A failure is kept as a warning.
+    fn keep_if_best(&self, context: &mut SceneContext) {
+        let Some(path) = &self.ghost_path else {
+            return;
+        };
+        let best_score = Replay::load_from(path)
+            .ok()
+            .map(|best| best.play_out().score());
+        if best_score.is_some_and(|best| best >= self.game.score()) {
+            return;
+        }
+        if let Err(e) = self.replay.save_to(path) {
+            context.warnings.push(format!(
+                "Could not save the ghost run to {}: {}",
+                path.display(),
+                e
+            ));
+        }
+    }
+
+    /// Enters a finished daily run into the daily leaderboard and keeps its
+    /// result card to print once the terminal is restored.
+    ///
+    /// # Returns
+    /// The label showing the run's rank on the results screen.
+    fn record_daily(&self, daily: DailyChallenge, context: &mut SceneContext) -> String {
+        let entry = DailyEntry::from_game(daily, &self.game);
+        let mut rank = None;
+        if let Some(path) = &context.daily_path {
+            match DailyLeaderboard::record_in(path, entry) {
+                Ok(ranked) => rank = Some(ranked),
+                Err(e) => context.warnings.push(format!(
+                    "Could not save the daily leaderboard to {}: {}",
+                    path.display(),
+                    e
+                )),
+            }
+        }
+        context.result_cards.push(entry.card(rank));
+        match rank {
+            Some((rank, runs)) => format!("DAILY {}: #{} OF {}", daily, rank, runs),
+            None => format!("DAILY {}", daily),
+        }
+    }
+}
+
+impl Scene for GameScene {
+    fn name(&self) -> &'static str {
+        "game"
+    }
+
+    fn draw(&self, mut out: &mut dyn Write, _context: &SceneContext) -> io::Result<()> {
+        self.game.draw(&mut out)?;
+        if let Some((text, _)) = &self.toast {
+            draw_toast(&mut out, text)?;
+        }
+        if let Some(label) = &self.turn {
+            draw_corner_label(&mut out, label)?;
+        } else if self.rewound {
+            draw_corner_label(&mut out, PRACTICE_LABEL)?;
+        }
+        Ok(())
+    }
+
+    /// The pause key pauses the game and, in turn-based mode, the rewind key
+    /// steps back a turn; every other key is mapped to a `GameEvent`. Once the
+    /// game has ended, any other key skips the remaining effects.
+    fn handle_key(&mut self, key: KeyEvent, context: &mut SceneContext) -> Transition {
+        let action = context.settings.keys.action_for(key.code);
+        if action == Some(KeyAction::Rewind) && self.can_rewind() {
+            self.rewind();
+            return Transition::None;
+        }
+        if self.ending {
+            return self.end(context);
+        }
+        if action == Some(KeyAction::Pause) {
+            return Transition::Push(Box::new(PauseScene));
+        }
+        let co_op_keys = self
+            .game
+            .options()
+            .co_op
+            .then_some(&context.settings.co_op_keys);
+        let event = Self::key_to_event(key, &context.settings.keys, co_op_keys);
+        self.step(event, context)
+    }
+
+    /// Picks up the display settings and play mode, which may have been changed
+    /// on the options screen opened from the pause menu.
+    fn resume(&mut self, context: &mut SceneContext) {
+        self.apply_settings(context);
+    }
+
+    fn tick_interval(&self, _context: &SceneContext) -> Option<Duration> {
+        if self.ending {
+            return Some(Duration::from_millis(EFFECT_FRAME_MS));
+        }
+        self.tick_interval
+    }
+
+    /// In real-time mode, the game advances on its own when no key arrives in time.
+    /// Once the game has ended, ticks play out its effects instead.
+    fn tick(&mut self, context: &mut SceneContext) -> Transition {
+        if self.ending {
+            self.game.animate_effects();
+            if !self.game.effects().is_active() {
+                return self.end(context);
+            }
+            return Transition::None;
+        }
+        self.step(GameEvent::AdvanceFrame, context)
+    }
+}
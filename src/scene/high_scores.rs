@@ -0,0 +1,42 @@
+// asciiliens/src/scene/high_scores.rs
+
+//! The high score scene: the table of the best finished games.
+
+use super::{Scene, SceneContext, Transition};
+use crate::display::draw_high_scores_screen;
+use crate::scores::HighScores;
+use crossterm::event::KeyEvent;
+use std::io::{self, Write};
+
+/// Shows the high score table until any key is pressed.
+#[derive(Debug)]
+pub struct HighScoresScene {
+    high_scores: HighScores, // Loaded once when the scene opens.
+}
+
+impl HighScoresScene {
+    /// Opens the high score table saved for the context.
+    ///
+    /// # Arguments
+    /// * `context` - The state shared by all scenes.
+    pub fn new(context: &SceneContext) -> Self {
+        Self {
+            high_scores: context.load_high_scores(),
+        }
+    }
+}
+
+impl Scene for HighScoresScene {
+    fn name(&self) -> &'static str {
+        "high_scores"
+    }
+
+    fn draw(&self, mut out: &mut dyn Write, _context: &SceneContext) -> io::Result<()> {
+        draw_high_scores_screen(&mut out, &self.high_scores)
+    }
+
+    /// Any key press returns to the previous screen.
+    fn handle_key(&mut self, _key: KeyEvent, _context: &mut SceneContext) -> Transition {
+        Transition::Pop
+    }
+}
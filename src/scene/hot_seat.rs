@@ -0,0 +1,265 @@
+// asciiliens/src/scene/hot_seat.rs
+
+//! The hot-seat scene: a match in which two players take turns at the same
+//! keyboard. Every round, each player plays one game, which lasts one life
+//! and one wave. Both players face the same seed in a round, so the match is
+//! fair, and the highest total score wins.
+
+use super::game::GameScene;
+use super::title::TitleScene;
+use super::{Scene, SceneContext, Transition};
+use crate::display::{draw_match_end_screen, draw_turn_screen, terminal};
+use crate::game::options::GameOptions;
+use crate::game::GameState;
+use crate::util::constants::{HOT_SEAT_PLAYERS, HOT_SEAT_ROUNDS};
+use crossterm::event::{KeyCode, KeyEvent};
+use rand::Rng;
+use std::io::{self, Write};
+use std::time::Duration;
+
+/// The score keeping of a hot-seat match: whose turn it is, the seed of each
+/// round, and every player's scores.
+#[derive(Debug, Clone)]
+pub struct HotSeatMatch {
+    options: GameOptions, // The options every turn starts from, with the seed resolved.
+    rounds: usize,        // The number of rounds in the match.
+    scores: Vec<Vec<i32>>, // Each player's score for every round they have played.
+    current: usize,       // The index of the player whose turn is next.
+}
+
+impl HotSeatMatch {
+    /// Creates a match in which nobody has played yet.
+    ///
+    /// # Arguments
+    /// * `options` - The options of the games; a `None` seed is resolved randomly, once for the match.
+    /// * `players` - The number of players (at least 1).
+    /// * `rounds` - The number of rounds (at least 1).
+    ///
+    /// # Returns
+    /// A new `HotSeatMatch`, with player 1 to play first.
+    pub fn new(options: GameOptions, players: usize, rounds: usize) -> Self {
+        let seed = options
+            .seed
+            .unwrap_or_else(|| rand::thread_rng().gen::<u64>());
+        Self {
+            options: GameOptions {
+                seed: Some(seed),
+                ..options
+            },
+            rounds: rounds.max(1),
+            scores: vec![Vec::new(); players.max(1)],
+            current: 0,
+        }
+    }
+
+    /// Returns the index of the player whose turn is next (0 for player 1).
+    pub fn current_player(&self) -> usize {
+        self.current
+    }
+
+    /// Returns the index of the round the next turn belongs to (0 for the first).
+    pub fn round(&self) -> usize {
+        self.scores[self.current].len()
+    }
+
+    /// Returns the number of rounds in the match.
+    pub fn rounds(&self) -> usize {
+        self.rounds
+    }
+
+    /// Returns whether every player has played every round.
+    pub fn is_finished(&self) -> bool {
+        self.scores.iter().all(|s| s.len() >= self.rounds)
+    }
+
+    /// Returns the options of the next turn's game. Every player gets the same seed in a round.
+    pub fn turn_options(&self) -> GameOptions {
+        let seed = self.options.seed.unwrap_or(0);
+        GameOptions {
+            seed: Some(seed.wrapping_add(self.round() as u64)),
+            ..self.options
+        }
+    }
+
+    /// Records the score of the turn just played and passes the turn to the next player.
+    ///
+    /// # Arguments
+    /// * `score` - The final score of the current player's game.
+    pub fn record_turn(&mut self, score: i32) {
+        if self.is_finished() {
+            return;
+        }
+        self.scores[self.current].push(score);
+        self.current = (self.current + 1) % self.scores.len();
+    }
+
+    /// Returns each player's score for every round they have played.
+    pub fn scores(&self) -> &[Vec<i32>] {
+        &self.scores
+    }
+
+    /// Returns each player's total score.
+    pub fn totals(&self) -> Vec<i32> {
+        self.scores.iter().map(|s| s.iter().sum()).collect()
+    }
+
+    /// Returns the index of the player with the highest total, or `None` if several share it.
+    pub fn winner(&self) -> Option<usize> {
+        let totals = self.totals();
+        let best = *totals.iter().max()?;
+        let mut leaders = totals.iter().enumerate().filter(|(_, t)| **t == best);
+        match (leaders.next(), leaders.next()) {
+            (Some((player, _)), None) => Some(player),
+            _ => None,
+        }
+    }
+}
+
+/// What the hot-seat scene is showing.
+#[derive(Debug)]
+enum Phase {
+    /// Announcing the next turn, waiting for the player to be ready.
+    Announce,
+    /// A player's turn.
+    Playing(Box<GameScene>),
+    /// The combined results of the finished match.
+    Finished,
+}
+
+/// Runs a hot-seat match: announces each turn, plays it, and finally shows the winner.
+#[derive(Debug)]
+pub struct HotSeatScene {
+    hot_seat: HotSeatMatch,
+    phase: Phase,
+}
+
+impl HotSeatScene {
+    /// Starts a match of `HOT_SEAT_ROUNDS` rounds for `HOT_SEAT_PLAYERS` players,
+    /// with the context's options.
+    ///
+    /// # Arguments
+    /// * `context` - The state shared by all scenes.
+    pub fn new(context: &SceneContext) -> Self {
+        Self {
+            hot_seat: HotSeatMatch::new(context.game_options(), HOT_SEAT_PLAYERS, HOT_SEAT_ROUNDS),
+            phase: Phase::Announce,
+        }
+    }
+
+    /// Returns the match being played.
+    pub fn hot_seat(&self) -> &HotSeatMatch {
+        &self.hot_seat
+    }
+
+    /// Starts the current player's turn.
+    fn start_turn(&mut self, context: &SceneContext) {
+        let label = format!(
+            "PLAYER {} - ROUND {}/{}",
+            self.hot_seat.current_player() + 1,
+            self.hot_seat.round() + 1,
+            self.hot_seat.rounds()
+        );
+        let turn = GameScene::for_turn(context, self.hot_seat.turn_options(), label);
+        self.phase = Phase::Playing(Box::new(turn));
+    }
+
+    /// Takes over once the current turn has ended: records its score and moves
+    /// on to the next turn or the results. Quitting a turn abandons the match.
+    ///
+    /// # Arguments
+    /// * `transition` - The transition returned by the turn's game scene.
+    ///
+    /// # Returns
+    /// The `Transition` to apply.
+    fn after_turn(&mut self, transition: Transition) -> Transition {
+        let Phase::Playing(turn) = &self.phase else {
+            return transition;
+        };
+        if !turn.is_over() {
+            return transition;
+        }
+        let game = turn.game();
+        if game.state() == GameState::Quit {
+            return Transition::Switch(Box::new(TitleScene::new()));
+        }
+        self.hot_seat.record_turn(game.score());
+        self.phase = if self.hot_seat.is_finished() {
+            Phase::Finished
+        } else {
+            Phase::Announce
+        };
+        Transition::None
+    }
+}
+
+impl Scene for HotSeatScene {
+    fn name(&self) -> &'static str {
+        "hot_seat"
+    }
+
+    fn draw(&self, mut out: &mut dyn Write, context: &SceneContext) -> io::Result<()> {
+        match &self.phase {
+            Phase::Announce => draw_turn_screen(
+                &mut out,
+                self.hot_seat.current_player(),
+                self.hot_seat.round(),
+                self.hot_seat.scores(),
+                self.hot_seat.rounds(),
+            ),
+            Phase::Playing(turn) => turn.draw(out, context),
+            Phase::Finished => draw_match_end_screen(
+                &mut out,
+                self.hot_seat.scores(),
+                self.hot_seat.rounds(),
+                self.hot_seat.winner(),
+            ),
+        }
+    }
+
+    /// Between turns, any key starts the next turn and Esc abandons the match.
+    /// During a turn, keys go to the game. On the results, 'Y' returns to the
+    /// title screen and 'N' or Esc quit. Ctrl-C quits outside a turn.
+    fn handle_key(&mut self, key: KeyEvent, context: &mut SceneContext) -> Transition {
+        if let Phase::Playing(turn) = &mut self.phase {
+            let transition = turn.handle_key(key, context);
+            return self.after_turn(transition);
+        }
+        if terminal::is_interrupt(&key) {
+            return Transition::Quit;
+        }
+        match (&self.phase, key.code) {
+            (Phase::Announce, KeyCode::Esc) => Transition::Switch(Box::new(TitleScene::new())),
+            (Phase::Announce, _) => {
+                self.start_turn(context);
+                Transition::None
+            }
+            (Phase::Finished, KeyCode::Char(c)) if c.eq_ignore_ascii_case(&'y') => {
+                Transition::Switch(Box::new(TitleScene::new()))
+            }
+            (Phase::Finished, KeyCode::Char(c)) if c.eq_ignore_ascii_case(&'n') => Transition::Quit,
+            (Phase::Finished, KeyCode::Esc) => Transition::Quit,
+            _ => Transition::None, // Ignore other keys.
+        }
+    }
+
+    fn resume(&mut self, context: &mut SceneContext) {
+        if let Phase::Playing(turn) = &mut self.phase {
+            turn.resume(context);
+        }
+    }
+
+    fn tick_interval(&self, context: &SceneContext) -> Option<Duration> {
+        match &self.phase {
+            Phase::Playing(turn) => turn.tick_interval(context),
+            _ => None,
+        }
+    }
+
+    fn tick(&mut self, context: &mut SceneContext) -> Transition {
+        let Phase::Playing(turn) = &mut self.phase else {
+            return Transition::None;
+        };
+        let transition = turn.tick(context);
+        self.after_turn(transition)
+    }
+}
@@ -0,0 +1,434 @@
+// asciiliens/src/scene/mod.rs
+
+//! This module drives the interactive application as a stack of scenes.
+//!
+//! Every screen (title, options, high scores, profile, gameplay, pause,
+//! results, hot-seat matches, networked games, replay playback and the
+//! practice sandbox) implements the `Scene` trait. The `SceneManager` owns the
+//! terminal for the whole run, draws the active scene, routes key presses from
+//! an `InputSource` and timer ticks to it, and applies the `Transition` it
+//! returns. Adding a screen means adding a scene and a transition that reaches
+//! it. Every frame can also be streamed to spectators.
+
+pub mod game;
+pub mod high_scores;
+pub mod hot_seat;
+pub mod net;
+pub mod options;
+pub mod pause;
+pub mod profile;
+pub mod replay;
+pub mod results;
+pub mod sandbox;
+pub mod title;
+
+use crate::cli::PlayArgs;
+use crate::config::Settings;
+use crate::daily::{DailyChallenge, DailyLeaderboard};
+use crate::display::terminal::TerminalSession;
+use crate::display::theme::GlyphWriter;
+use crate::game::options::GameOptions;
+use crate::input::{CrosstermInput, InputSource};
+use crate::net::spectator::SpectatorServer;
+use crate::profile::Profile;
+use crate::replay::Replay;
+use crate::scores::HighScores;
+use crossterm::{
+    cursor::MoveTo,
+    event::KeyEvent,
+    queue,
+    style::{Print, ResetColor, SetForegroundColor},
+    terminal::{Clear, ClearType},
+};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// What the `SceneManager` should do after a scene handled input or a tick.
+pub enum Transition {
+    /// Stay on the active scene.
+    None,
+    /// Open a scene on top of the active one, returning to it when the new scene pops.
+    Push(Box<dyn Scene>),
+    /// Close the active scene and return to the one below.
+    Pop,
+    /// Close the active scene and hand the key press to the scene below.
+    PopWith(KeyEvent),
+    /// Replace the active scene with another one.
+    Switch(Box<dyn Scene>),
+    /// Close every scene and end the run.
+    Quit,
+}
+
+/// One screen of the application.
+pub trait Scene {
+    /// Returns a short, stable name for the scene, e.g. `"title"`.
+    fn name(&self) -> &'static str;
+
+    /// Draws the scene. The manager clears the screen beforehand and flushes afterwards.
+    ///
+    /// # Arguments
+    /// * `out` - The terminal (or any other `Write` target) to draw to.
+    /// * `context` - The state shared by all scenes.
+    ///
+    /// # Returns
+    /// An `io::Result<()>` indicating success or failure of the drawing operations.
+    fn draw(&self, out: &mut dyn Write, context: &SceneContext) -> io::Result<()>;
+
+    /// Handles a key press while the scene is active.
+    ///
+    /// # Arguments
+    /// * `key` - The key press.
+    /// * `context` - The state shared by all scenes.
+    ///
+    /// # Returns
+    /// The `Transition` to apply.
+    fn handle_key(&mut self, key: KeyEvent, context: &mut SceneContext) -> Transition;
+
+    /// Returns how long to wait for a key before calling `tick`, or `None` to wait indefinitely.
+    ///
+    /// # Arguments
+    /// * `context` - The state shared by all scenes.
+    fn tick_interval(&self, _context: &SceneContext) -> Option<Duration> {
+        None
+    }
+
+    /// Advances the scene when no key arrived within `tick_interval`.
+    ///
+    /// # Arguments
+    /// * `context` - The state shared by all scenes.
+    ///
+    /// # Returns
+    /// The `Transition` to apply.
+    fn tick(&mut self, _context: &mut SceneContext) -> Transition {
+        Transition::None
+    }
+
+    /// Called when the scene becomes active again because the scene above it
+    /// popped, e.g. to pick up settings changed on the options screen.
+    ///
+    /// # Arguments
+    /// * `context` - The state shared by all scenes.
+    fn resume(&mut self, _context: &mut SceneContext) {}
+
+    /// Returns whether the scene is drawn over the scene below instead of replacing it.
+    fn is_overlay(&self) -> bool {
+        false
+    }
+}
+
+/// The state shared by every scene during a run.
+#[derive(Debug, Clone)]
+pub struct SceneContext {
+    /// The seed and level new games are created with. Their difficulty comes from `settings`.
+    pub options: GameOptions,
+    /// The player's settings, changed on the options screen.
+    pub settings: Settings,
+    /// Where the settings are saved, or `None` to keep them in memory only.
+    pub config_path: Option<PathBuf>,
+    /// The player's lifetime profile.
+    pub profile: Profile,
+    /// Where the profile is saved, or `None` to keep it in memory only.
+    pub profile_path: Option<PathBuf>,
+    /// Where finished games are entered into the high scores, or `None` to skip them.
+    pub high_scores_path: Option<PathBuf>,
+    /// The daily challenge being played, or `None` for ordinary games.
+    pub daily: Option<DailyChallenge>,
+    /// Where finished daily runs are entered into the daily leaderboard, or `None` to skip them.
+    pub daily_path: Option<PathBuf>,
+    /// Where each game is saved as the last replay, or `None` to skip it.
+    pub replay_path: Option<PathBuf>,
+    /// Where the best run of each seeded level is kept as a ghost, or `None` to keep none.
+    pub ghosts_dir: Option<PathBuf>,
+    /// Problems to report once the terminal is restored, e.g. files that could not be saved.
+    pub warnings: Vec<String>,
+    /// Result cards to print once the terminal is restored, e.g. of daily runs.
+    pub result_cards: Vec<Vec<String>>,
+    /// Whether the terminal bell should ring after the next frame is drawn.
+    bell_pending: bool,
+}
+
+impl SceneContext {
+    /// Creates a context that keeps everything in memory and persists nothing.
+    ///
+    /// # Arguments
+    /// * `args` - The `PlayArgs` new games are created with; their play mode,
+    ///   difficulty and fire rule replace those in `settings`.
+    /// * `settings` - The player's settings.
+    pub fn new(args: PlayArgs, settings: Settings) -> Self {
+        Self {
+            options: args.options,
+            settings: Settings {
+                mode: args.mode,
+                difficulty: args.options.difficulty,
+                fire_rule: args.options.fire_rule,
+                ..settings
+            },
+            config_path: None,
+            profile: Profile::default(),
+            profile_path: None,
+            high_scores_path: None,
+            daily: None,
+            daily_path: None,
+            replay_path: None,
+            ghosts_dir: None,
+            warnings: Vec::new(),
+            result_cards: Vec::new(),
+            bell_pending: false,
+        }
+    }
+
+    /// Creates a context that loads and saves the profile, high scores, daily
+    /// leaderboard, last replay and ghosts in the user's data directory, and
+    /// the settings in its config directory.
+    ///
+    /// # Arguments
+    /// * `args` - The `PlayArgs` new games are created with; their play mode,
+    ///   and difficulty replace those in `settings`.
+    /// * `settings` - The player's settings, usually loaded from the config file.
+    pub fn with_default_paths(args: PlayArgs, settings: Settings) -> Self {
+        let profile_path = Profile::default_path();
+        // A missing or damaged profile file starts a fresh one.
+        let profile = profile_path
+            .as_deref()
+            .map(Profile::load_from)
+            .unwrap_or_default();
+        Self {
+            config_path: Settings::default_path(),
+            profile,
+            profile_path,
+            high_scores_path: HighScores::default_path(),
+            daily_path: DailyLeaderboard::default_path(),
+            replay_path: Replay::last_replay_path(),
+            ghosts_dir: Replay::ghosts_dir(),
+            ..Self::new(args, settings)
+        }
+    }
+
+    /// Returns the options for a new game: the context's seed and level at the
+    /// difficulty and fire rule chosen in the settings, or those of the daily
+    /// challenge.
+    pub fn game_options(&self) -> GameOptions {
+        if let Some(daily) = self.daily {
+            return daily.options();
+        }
+        GameOptions {
+            difficulty: self.settings.difficulty,
+            fire_rule: self.settings.fire_rule,
+            ..self.options
+        }
+    }
+
+    /// Saves the settings to `config_path`, if there is one, keeping any failure as a warning.
+    pub fn save_settings(&mut self) {
+        if let Some(path) = &self.config_path {
+            if let Err(e) = self.settings.save_to(path) {
+                self.warnings.push(format!(
+                    "Could not save settings to {}: {}",
+                    path.display(),
+                    e
+                ));
+            }
+        }
+    }
+
+    /// Rings the terminal bell after the next frame is drawn, if sound is on.
+    pub fn ring_bell(&mut self) {
+        if self.settings.sound {
+            self.bell_pending = true;
+        }
+    }
+
+    /// Returns the high score table, loaded from `high_scores_path` if there is one.
+    pub fn load_high_scores(&self) -> HighScores {
+        self.high_scores_path
+            .as_deref()
+            .map(HighScores::load_from)
+            .unwrap_or_default()
+    }
+}
+
+/// Owns the terminal and the scene stack, and runs the application.
+pub struct SceneManager {
+    stack: Vec<Box<dyn Scene>>, // The active scene is last.
+    context: SceneContext,
+    spectators: Option<SpectatorServer>, // Receives a copy of every frame, if set.
+}
+
+impl SceneManager {
+    /// Creates a manager whose first scene is `first`.
+    ///
+    /// # Arguments
+    /// * `first` - The scene shown when the run starts.
+    /// * `context` - The state shared by all scenes.
+    pub fn new(first: Box<dyn Scene>, context: SceneContext) -> Self {
+        Self {
+            stack: vec![first],
+            context,
+            spectators: None,
+        }
+    }
+
+    /// Streams every frame drawn from now on to the spectators of the given server.
+    ///
+    /// # Arguments
+    /// * `server` - The `SpectatorServer` spectators connect to.
+    pub fn set_spectators(&mut self, server: SpectatorServer) {
+        self.spectators = Some(server);
+    }
+
+    /// Returns the state shared by all scenes.
+    pub fn context(&self) -> &SceneContext {
+        &self.context
+    }
+
+    /// Returns the name of the active scene, or `None` once the run has ended.
+    pub fn active_scene_name(&self) -> Option<&'static str> {
+        self.stack.last().map(|scene| scene.name())
+    }
+
+    /// Returns whether the run has ended.
+    pub fn is_finished(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Runs the application until a scene quits or the last scene pops.
+    ///
+    /// The terminal is put into game mode once, for the whole run, and restored
+    /// when this returns, whether successfully or with an error.
+    ///
+    /// # Arguments
+    /// * `out` - The terminal to draw to, typically `io::stdout()`.
+    ///
+    /// # Returns
+    /// An `io::Result<()>` indicating whether the run completed successfully.
+    pub fn run<W: Write>(&mut self, out: &mut W) -> io::Result<()> {
+        let _session = TerminalSession::enter(out)?;
+        self.run_with(out, &mut CrosstermInput)
+    }
+
+    /// Runs the scenes until a scene quits or the last scene pops, reading keys
+    /// from the given input source.
+    ///
+    /// Unlike `run`, this leaves the terminal mode alone, so it can draw to any
+    /// writer, such as an in-memory buffer in tests.
+    ///
+    /// # Arguments
+    /// * `out` - The writer to draw to.
+    /// * `input` - Where key presses come from.
+    ///
+    /// # Returns
+    /// An `io::Result<()>` indicating whether the run completed successfully.
+    pub fn run_with<W: Write>(
+        &mut self,
+        out: &mut W,
+        input: &mut dyn InputSource,
+    ) -> io::Result<()> {
+        while !self.is_finished() {
+            match self.spectators.take() {
+                Some(mut spectators) => {
+                    // Draw the frame once, then send the same bytes to the terminal and the spectators.
+                    let mut frame = Vec::new();
+                    self.draw(&mut frame)?;
+                    out.write_all(&frame)?;
+                    out.flush()?;
+                    spectators.broadcast(&frame);
+                    self.spectators = Some(spectators);
+                }
+                None => self.draw(out)?,
+            }
+            if std::mem::take(&mut self.context.bell_pending) {
+                queue!(out, Print('\u{7}'))?;
+                out.flush()?;
+            }
+
+            // Scenes with a tick interval advance on their own when no key arrives in time.
+            let tick_interval = self
+                .stack
+                .last()
+                .and_then(|scene| scene.tick_interval(&self.context));
+            match input.next_key(tick_interval)? {
+                Some(key) => self.handle_key(key),
+                None => self.tick(),
+            }
+        }
+        Ok(())
+    }
+
+    /// Clears the screen and draws the active scene, with any scenes it overlays beneath it.
+    ///
+    /// Everything is drawn in the theme's color and converted to the glyph set
+    /// chosen in the settings.
+    ///
+    /// # Arguments
+    /// * `out` - The `Write` target to draw to.
+    ///
+    /// # Returns
+    /// An `io::Result<()>` indicating success or failure of the drawing operations.
+    pub fn draw<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        let mut out = GlyphWriter::new(out, self.context.settings.glyphs);
+        queue!(out, Clear(ClearType::All), MoveTo(0, 0))?;
+        match self.context.settings.theme.color() {
+            Some(color) => queue!(out, SetForegroundColor(color))?,
+            None => queue!(out, ResetColor)?,
+        }
+        // Start from the topmost scene that is not an overlay.
+        let base = self
+            .stack
+            .iter()
+            .rposition(|scene| !scene.is_overlay())
+            .unwrap_or(0);
+        for scene in &self.stack[base..] {
+            scene.draw(&mut out, &self.context)?;
+        }
+        out.flush()
+    }
+
+    /// Routes a key press to the active scene and applies the resulting transition.
+    ///
+    /// # Arguments
+    /// * `key` - The key press.
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        if let Some(scene) = self.stack.last_mut() {
+            let transition = scene.handle_key(key, &mut self.context);
+            self.apply(transition);
+        }
+    }
+
+    /// Ticks the active scene and applies the resulting transition.
+    pub fn tick(&mut self) {
+        if let Some(scene) = self.stack.last_mut() {
+            let transition = scene.tick(&mut self.context);
+            self.apply(transition);
+        }
+    }
+
+    /// Lets the active scene pick up any changes made while it was covered.
+    fn resume_active(&mut self) {
+        if let Some(scene) = self.stack.last_mut() {
+            scene.resume(&mut self.context);
+        }
+    }
+
+    /// Applies a transition to the scene stack.
+    fn apply(&mut self, transition: Transition) {
+        match transition {
+            Transition::None => {}
+            Transition::Push(scene) => self.stack.push(scene),
+            Transition::Pop => {
+                self.stack.pop();
+                self.resume_active();
+            }
+            Transition::PopWith(key) => {
+                self.stack.pop();
+                self.resume_active();
+                self.handle_key(key);
+            }
+            Transition::Switch(scene) => {
+                self.stack.pop();
+                self.stack.push(scene);
+            }
+            Transition::Quit => self.stack.clear(),
+        }
+    }
+}
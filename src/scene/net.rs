@@ -0,0 +1,201 @@
+// asciiliens/src/scene/net.rs
+
+//! The networked game scene: plays a `Lockstep` session against another
+//! player over an established `Connection`. Key presses are submitted as the
+//! local player's turns, ticks poll for the other player's, and the game
+//! advances once both are known. In versus, once the local field is over,
+//! ticks submit the local turns instead, so the opponent can play on without
+//! waiting for key presses. A disconnect or desync ends the game with an
+//! explanation instead of a crash.
+
+use super::{Scene, SceneContext, Transition};
+use crate::config::keys::KeyAction;
+use crate::display::{draw_corner_label, draw_net_end_screen, terminal};
+use crate::game::{GameEvent, GameState};
+use crate::net::{Connection, Lockstep, Message, NetError, NetMode};
+use crate::util::constants::NET_POLL_MS;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::io::{self, Write};
+use std::time::Duration;
+
+/// A game played against another player over the network.
+#[derive(Debug)]
+pub struct NetScene {
+    connection: Connection,
+    lockstep: Lockstep,
+    error: Option<NetError>, // Set once a disconnect or desync has ended the game.
+}
+
+impl NetScene {
+    /// Creates the scene for a session whose handshake is complete.
+    ///
+    /// # Arguments
+    /// * `connection` - The connection to the other player.
+    /// * `lockstep` - This peer's side of the session.
+    pub fn new(connection: Connection, lockstep: Lockstep) -> Self {
+        Self {
+            connection,
+            lockstep,
+            error: None,
+        }
+    }
+
+    /// Returns this peer's side of the session.
+    pub fn lockstep(&self) -> &Lockstep {
+        &self.lockstep
+    }
+
+    /// Returns whether the session has ended, normally or not.
+    fn is_finished(&self) -> bool {
+        self.error.is_some() || self.lockstep.is_over()
+    }
+
+    /// Ends the session with an error, unless the game had already ended.
+    /// A player leaving after the game is over is not worth reporting.
+    fn fail(&mut self, error: NetError) {
+        if self.error.is_none() && !self.lockstep.is_over() {
+            self.error = Some(error);
+        }
+    }
+
+    /// Submits the local player's event for the next turn and sends it to the
+    /// other player, unless an event was already submitted for this turn.
+    fn submit(&mut self, event: GameEvent) {
+        if let Some(message) = self.lockstep.submit(event) {
+            if let Err(e) = self.connection.send(&message) {
+                self.fail(e);
+            }
+        }
+    }
+
+    /// Tells the other player this peer is leaving and ends the run.
+    fn leave(&mut self) -> Transition {
+        let _ = self.connection.send(&Message::Bye); // The other player may already be gone.
+        Transition::Quit
+    }
+
+    /// Returns the lines describing how the session ended.
+    fn outcome_lines(&self) -> Vec<String> {
+        if let Some(error) = &self.error {
+            return vec![
+                "THE GAME WAS INTERRUPTED".to_string(),
+                String::new(),
+                error.to_string(),
+            ];
+        }
+        let local = self.lockstep.local_game();
+        let you = self.lockstep.side().index() + 1;
+        match self.lockstep.opponent_game() {
+            None => {
+                let verdict = match local.state() {
+                    GameState::Win => "YOU SAVED THE PLANET TOGETHER!",
+                    _ => "THE INVASION SUCCEEDED!",
+                };
+                let mut lines = vec![
+                    verdict.to_string(),
+                    String::new(),
+                    format!("Team score: {}", local.score()),
+                ];
+                lines.extend(local.players().iter().enumerate().map(|(i, player)| {
+                    let marker = if i + 1 == you { " (you)" } else { "" };
+                    format!("P{}: {} points{}", i + 1, player.points(), marker)
+                }));
+                lines
+            }
+            Some(opponent) => {
+                let verdict = match local.score().cmp(&opponent.score()) {
+                    std::cmp::Ordering::Greater => "YOU WIN THE DUEL!",
+                    std::cmp::Ordering::Less => "YOU LOSE THE DUEL!",
+                    std::cmp::Ordering::Equal => "THE DUEL IS A DRAW!",
+                };
+                vec![
+                    verdict.to_string(),
+                    String::new(),
+                    format!("Your score: {}", local.score()),
+                    format!("Opponent's score: {}", opponent.score()),
+                ]
+            }
+        }
+    }
+}
+
+impl Scene for NetScene {
+    fn name(&self) -> &'static str {
+        "net"
+    }
+
+    fn draw(&self, mut out: &mut dyn Write, _context: &SceneContext) -> io::Result<()> {
+        if self.is_finished() {
+            return draw_net_end_screen(&mut out, &self.outcome_lines());
+        }
+        self.lockstep.local_game().draw(&mut out)?;
+        let mut label = match self.lockstep.mode() {
+            NetMode::CoOp => format!("YOU ARE P{}", self.lockstep.side().index() + 1),
+            NetMode::Versus => {
+                let opponent = self.lockstep.opponent_game().map_or(0, |g| g.score());
+                if self.lockstep.local_game().state() == GameState::Playing {
+                    format!("OPPONENT: {}", opponent)
+                } else {
+                    format!("OPPONENT FINISHING: {}", opponent)
+                }
+            }
+        };
+        if self.lockstep.is_waiting() {
+            label = format!("WAITING... {}", label);
+        }
+        draw_corner_label(&mut out, &label)
+    }
+
+    /// Each key press is the local player's turn: the move and fire keys act,
+    /// other keys just advance the frame. Keys pressed while waiting for the
+    /// other player are ignored. Esc, the quit key and Ctrl-C leave the game;
+    /// once it has ended, any key does.
+    fn handle_key(&mut self, key: KeyEvent, context: &mut SceneContext) -> Transition {
+        let action = context.settings.keys.action_for(key.code);
+        if self.is_finished()
+            || terminal::is_interrupt(&key)
+            || key.code == KeyCode::Esc
+            || action == Some(KeyAction::Quit)
+        {
+            return self.leave();
+        }
+        let event = match action {
+            Some(KeyAction::MoveLeft) => GameEvent::MoveLeft,
+            Some(KeyAction::MoveRight) => GameEvent::MoveRight,
+            Some(KeyAction::Fire) => GameEvent::Fire,
+            _ => GameEvent::AdvanceFrame, // A networked game cannot be paused.
+        };
+        self.submit(event);
+        Transition::None
+    }
+
+    fn tick_interval(&self, _context: &SceneContext) -> Option<Duration> {
+        (!self.is_finished()).then(|| Duration::from_millis(NET_POLL_MS))
+    }
+
+    /// Handles every message the other player has sent since the last tick.
+    /// Once the local field is over in versus, also plays the local turn, as
+    /// the opponent's field only advances with it.
+    fn tick(&mut self, _context: &mut SceneContext) -> Transition {
+        loop {
+            match self.connection.try_receive() {
+                Ok(Some(message)) => {
+                    if let Err(e) = self.lockstep.receive(message) {
+                        self.fail(e);
+                        break;
+                    }
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    self.fail(e);
+                    break;
+                }
+            }
+        }
+        let local_over = self.lockstep.local_game().state() != GameState::Playing;
+        if self.lockstep.mode() == NetMode::Versus && local_over && !self.is_finished() {
+            self.submit(GameEvent::AdvanceFrame);
+        }
+        Transition::None
+    }
+}
@@ -0,0 +1,193 @@
+// asciiliens/src/scene/options.rs
+
+//! The options scene: the player's settings, saved to the config file when
+//! the screen is closed.
+
+use super::{Scene, SceneContext, Transition};
+use crate::config::keys::{key_name, parse_key_name, KeyAction};
+use crate::config::Settings;
+use crate::display::{draw_options_screen, terminal};
+use crate::util::constants::OPTIONS_HELP_TEXT;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::io::{self, Write};
+
+/// A setting listed on the options screen.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Setting {
+    Mode,
+    Difficulty,
+    FireRule,
+    Theme,
+    Glyphs,
+    Sound,
+    ReducedMotion,
+    Starfield,
+    Ghost,
+    Preview,
+    Key(KeyAction),
+    CoOpKey(KeyAction), // One of the `KeyAction::SECOND_SHIP` keys of a co-op game.
+}
+
+impl Setting {
+    /// Every setting, in display order.
+    const ALL: [Setting; 19] = [
+        Setting::Mode,
+        Setting::Difficulty,
+        Setting::FireRule,
+        Setting::Theme,
+        Setting::Glyphs,
+        Setting::Sound,
+        Setting::ReducedMotion,
+        Setting::Starfield,
+        Setting::Ghost,
+        Setting::Preview,
+        Setting::Key(KeyAction::MoveLeft),
+        Setting::Key(KeyAction::MoveRight),
+        Setting::Key(KeyAction::Fire),
+        Setting::Key(KeyAction::Pause),
+        Setting::Key(KeyAction::Quit),
+        Setting::Key(KeyAction::Rewind),
+        Setting::CoOpKey(KeyAction::MoveLeft),
+        Setting::CoOpKey(KeyAction::MoveRight),
+        Setting::CoOpKey(KeyAction::Fire),
+    ];
+
+    /// Returns the label shown for the setting.
+    fn label(&self) -> &'static str {
+        match self {
+            Setting::Mode => "Play mode",
+            Setting::Difficulty => "Difficulty",
+            Setting::FireRule => "Fire rule",
+            Setting::Theme => "Theme",
+            Setting::Glyphs => "Glyph set",
+            Setting::Sound => "Sound",
+            Setting::ReducedMotion => "Reduced motion",
+            Setting::Starfield => "Starfield",
+            Setting::Ghost => "Ghost run",
+            Setting::Preview => "Turn preview",
+            Setting::Key(action) => action.label(),
+            Setting::CoOpKey(action) => action.second_ship_label(),
+        }
+    }
+
+    /// Returns the current value of the setting, as shown on screen.
+    fn value(&self, settings: &Settings) -> String {
+        let on_off = |on: bool| if on { "ON" } else { "OFF" }.to_string();
+        match self {
+            Setting::Mode => settings.mode.name().to_uppercase(),
+            Setting::Difficulty => settings.difficulty.name().to_uppercase(),
+            Setting::FireRule => settings.fire_rule.to_string().to_uppercase(),
+            Setting::Theme => settings.theme.name().to_uppercase(),
+            Setting::Glyphs => settings.glyphs.name().to_uppercase(),
+            Setting::Sound => on_off(settings.sound),
+            Setting::ReducedMotion => on_off(settings.reduced_motion),
+            Setting::Starfield => on_off(settings.starfield),
+            Setting::Ghost => on_off(settings.ghost),
+            Setting::Preview => on_off(settings.preview),
+            Setting::Key(action) => key_name(settings.keys.key(*action)),
+            Setting::CoOpKey(action) => key_name(settings.co_op_keys.key(*action)),
+        }
+    }
+
+    /// Binds a key to the setting's action, if it is a key binding. A key
+    /// already in use by either ship is swapped with the action's previous key.
+    fn bind(&self, settings: &mut Settings, key: KeyCode) {
+        match self {
+            Setting::Key(action) => settings.bind_key(*action, key),
+            Setting::CoOpKey(action) => settings.bind_co_op_key(*action, key),
+            _ => {}
+        }
+    }
+
+    /// Changes the setting to its next value. Key bindings are changed by rebinding instead.
+    fn cycle(&self, settings: &mut Settings) {
+        match self {
+            Setting::Mode => settings.mode = settings.mode.next(),
+            Setting::Difficulty => settings.difficulty = settings.difficulty.next(),
+            Setting::FireRule => settings.fire_rule = settings.fire_rule.next(),
+            Setting::Theme => settings.theme = settings.theme.next(),
+            Setting::Glyphs => settings.glyphs = settings.glyphs.next(),
+            Setting::Sound => settings.sound = !settings.sound,
+            Setting::ReducedMotion => settings.reduced_motion = !settings.reduced_motion,
+            Setting::Starfield => settings.starfield = !settings.starfield,
+            Setting::Ghost => settings.ghost = !settings.ghost,
+            Setting::Preview => settings.preview = !settings.preview,
+            Setting::Key(_) | Setting::CoOpKey(_) => {}
+        }
+    }
+}
+
+/// Lets the player change their settings, including the keys used during play.
+#[derive(Debug, Default)]
+pub struct OptionsScene {
+    selected: usize,            // The index of the selected setting.
+    rebinding: Option<Setting>, // Set while waiting for the new key of a key binding.
+}
+
+impl OptionsScene {
+    /// Opens the options screen with the first setting selected.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Scene for OptionsScene {
+    fn name(&self) -> &'static str {
+        "options"
+    }
+
+    fn draw(&self, mut out: &mut dyn Write, context: &SceneContext) -> io::Result<()> {
+        let settings: Vec<(&str, String)> = Setting::ALL
+            .iter()
+            .map(|s| (s.label(), s.value(&context.settings)))
+            .collect();
+        let help = match self.rebinding {
+            Some(Setting::CoOpKey(action)) => format!(
+                "Press the new key for P2's '{}' (ESC cancels)",
+                action.config_name()
+            ),
+            Some(Setting::Key(action)) => format!(
+                "Press the new key for '{}' (ESC cancels)",
+                action.config_name()
+            ),
+            Some(_) | None => OPTIONS_HELP_TEXT.to_string(),
+        };
+        draw_options_screen(&mut out, &settings, self.selected, &help)
+    }
+
+    /// UP/DOWN select a setting and LEFT/RIGHT/ENTER/SPACE change it; on a key
+    /// binding, ENTER waits for the new key. ESC saves the settings and returns.
+    fn handle_key(&mut self, key: KeyEvent, context: &mut SceneContext) -> Transition {
+        if let Some(setting) = self.rebinding.take() {
+            // Only keys that can be written to the config file can be bound.
+            let bindable = parse_key_name(&key_name(key.code)).is_some();
+            if bindable && key.code != KeyCode::Esc && !terminal::is_interrupt(&key) {
+                setting.bind(&mut context.settings, key.code);
+            }
+            return Transition::None;
+        }
+        if terminal::is_interrupt(&key) {
+            context.save_settings();
+            return Transition::Pop;
+        }
+        let count = Setting::ALL.len();
+        let setting = Setting::ALL[self.selected];
+        match key.code {
+            KeyCode::Up => self.selected = (self.selected + count - 1) % count,
+            KeyCode::Down => self.selected = (self.selected + 1) % count,
+            KeyCode::Enter => match setting {
+                Setting::Key(_) | Setting::CoOpKey(_) => self.rebinding = Some(setting),
+                _ => setting.cycle(&mut context.settings),
+            },
+            KeyCode::Left | KeyCode::Right | KeyCode::Char(' ') => {
+                setting.cycle(&mut context.settings)
+            }
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => {
+                context.save_settings();
+                return Transition::Pop;
+            }
+            _ => { /* Ignore other keys. */ }
+        }
+        Transition::None
+    }
+}
@@ -0,0 +1,48 @@
+// asciiliens/src/scene/pause.rs
+
+//! The pause scene, drawn as a banner over the game it pauses.
+
+use super::options::OptionsScene;
+use super::{Scene, SceneContext, Transition};
+use crate::config::keys::KeyAction;
+use crate::display::{draw_pause_overlay, terminal};
+use crossterm::event::{KeyCode, KeyEvent};
+use std::io::{self, Write};
+
+/// Pauses the game below it until the player resumes or quits.
+#[derive(Debug, Default)]
+pub struct PauseScene;
+
+impl Scene for PauseScene {
+    fn name(&self) -> &'static str {
+        "pause"
+    }
+
+    fn draw(&self, mut out: &mut dyn Write, _context: &SceneContext) -> io::Result<()> {
+        draw_pause_overlay(&mut out)
+    }
+
+    /// The pause key or Esc resumes and 'O' opens the options. The quit key
+    /// and Ctrl-C are handed to the game, which quits.
+    fn handle_key(&mut self, key: KeyEvent, context: &mut SceneContext) -> Transition {
+        if terminal::is_interrupt(&key) {
+            return Transition::PopWith(key);
+        }
+        match context.settings.keys.action_for(key.code) {
+            Some(KeyAction::Pause) => return Transition::Pop,
+            Some(KeyAction::Quit) => return Transition::PopWith(key),
+            _ => {}
+        }
+        match key.code {
+            KeyCode::Esc => Transition::Pop,
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                Transition::Push(Box::new(OptionsScene::new()))
+            }
+            _ => Transition::None, // The game stays frozen.
+        }
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,27 @@
+// asciiliens/src/scene/profile.rs
+
+//! The pilot profile scene: lifetime statistics and achievements.
+
+use super::{Scene, SceneContext, Transition};
+use crate::display::draw_profile_screen;
+use crossterm::event::KeyEvent;
+use std::io::{self, Write};
+
+/// Shows the player's profile until any key is pressed.
+#[derive(Debug, Default)]
+pub struct ProfileScene;
+
+impl Scene for ProfileScene {
+    fn name(&self) -> &'static str {
+        "profile"
+    }
+
+    fn draw(&self, mut out: &mut dyn Write, context: &SceneContext) -> io::Result<()> {
+        draw_profile_screen(&mut out, &context.profile)
+    }
+
+    /// Any key press returns to the previous screen.
+    fn handle_key(&mut self, _key: KeyEvent, _context: &mut SceneContext) -> Transition {
+        Transition::Pop
+    }
+}
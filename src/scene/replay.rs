@@ -0,0 +1,78 @@
+// asciiliens/src/scene/replay.rs
+
+//! The replay scene: plays back a recorded game one frame at a time.
+
+use super::{Scene, SceneContext, Transition};
+use crate::display::terminal;
+use crate::game::Game;
+use crate::replay::Replay;
+use crate::util::constants::REPLAY_FRAME_MS;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::io::{self, Write};
+use std::time::Duration;
+
+/// Plays back a `Replay`, advancing one recorded event per tick.
+#[derive(Debug)]
+pub struct ReplayScene {
+    game: Game,
+    replay: Replay,
+    next_event: usize, // The index of the next recorded event to play.
+}
+
+impl ReplayScene {
+    /// Starts playing back a recording from its first frame.
+    ///
+    /// # Arguments
+    /// * `replay` - The recorded game.
+    /// * `context` - The state shared by all scenes; its settings decide whether the starfield is shown.
+    pub fn new(replay: Replay, context: &SceneContext) -> Self {
+        let mut game = Game::with_options(replay.options);
+        game.set_starfield_visible(context.settings.show_starfield());
+        game.set_reduced_motion(context.settings.reduced_motion);
+        Self {
+            game,
+            replay,
+            next_event: 0,
+        }
+    }
+
+    /// Returns whether every recorded event has been played.
+    pub fn is_finished(&self) -> bool {
+        self.next_event >= self.replay.events.len()
+    }
+}
+
+impl Scene for ReplayScene {
+    fn name(&self) -> &'static str {
+        "replay"
+    }
+
+    fn draw(&self, mut out: &mut dyn Write, _context: &SceneContext) -> io::Result<()> {
+        self.game.draw(&mut out)
+    }
+
+    /// 'Q', Esc or Ctrl-C stop the playback early. Once the recording is over,
+    /// the final frame stays on screen until any key is pressed.
+    fn handle_key(&mut self, key: KeyEvent, _context: &mut SceneContext) -> Transition {
+        if self.is_finished()
+            || terminal::is_interrupt(&key)
+            || matches!(key.code, KeyCode::Char('q') | KeyCode::Esc)
+        {
+            return Transition::Quit;
+        }
+        Transition::None
+    }
+
+    fn tick_interval(&self, _context: &SceneContext) -> Option<Duration> {
+        // Hold the final frame: wait for a key instead of ticking.
+        (!self.is_finished()).then(|| Duration::from_millis(REPLAY_FRAME_MS))
+    }
+
+    fn tick(&mut self, _context: &mut SceneContext) -> Transition {
+        if let Some(event) = self.replay.events.get(self.next_event) {
+            self.game.update(*event);
+            self.next_event += 1;
+        }
+        Transition::None
+    }
+}
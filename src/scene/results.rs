@@ -0,0 +1,94 @@
+// asciiliens/src/scene/results.rs
+
+//! The results scene: the outcome, final score and session statistics of a
+//! finished game, and the "Play again?" prompt. After a co-op game, each
+//! ship's own points are shown in the corner, and after a daily run, its rank.
+
+use super::title::TitleScene;
+use super::{Scene, SceneContext, Transition};
+use crate::display::{draw_corner_label, draw_game_end_screen, terminal};
+use crate::game::stats::SessionStats;
+use crate::game::{Game, GameState};
+use crate::profile::achievements::Achievement;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::io::{self, Write};
+
+/// The end screen of a game.
+#[derive(Debug)]
+pub struct ResultsScene {
+    state: GameState,
+    score: i32,
+    stats: SessionStats,
+    label: Option<String>, // Shown in the corner, e.g. each co-op ship's own points.
+    unlocked: Vec<Achievement>, // Achievements unlocked during the game.
+}
+
+impl ResultsScene {
+    /// Creates the results of a finished game.
+    ///
+    /// # Arguments
+    /// * `game` - The finished `Game`.
+    /// * `unlocked` - The achievements unlocked during the game.
+    pub fn new(game: &Game, unlocked: Vec<Achievement>) -> Self {
+        let label = (game.players().len() > 1).then(|| {
+            let points: Vec<String> = game
+                .players()
+                .iter()
+                .enumerate()
+                .map(|(i, player)| format!("P{}: {}", i + 1, player.points()))
+                .collect();
+            points.join("  ")
+        });
+        Self {
+            state: game.state(),
+            score: game.score(),
+            stats: *game.stats(),
+            label,
+            unlocked,
+        }
+    }
+
+    /// Replaces the label shown in the corner.
+    ///
+    /// # Arguments
+    /// * `label` - The label, e.g. `"DAILY 2026-10-18: #2 OF 5"`.
+    pub fn with_label(mut self, label: String) -> Self {
+        self.label = Some(label);
+        self
+    }
+}
+
+impl Scene for ResultsScene {
+    fn name(&self) -> &'static str {
+        "results"
+    }
+
+    fn draw(&self, mut out: &mut dyn Write, _context: &SceneContext) -> io::Result<()> {
+        draw_game_end_screen(
+            &mut out,
+            self.state,
+            self.score,
+            &self.stats,
+            &self.unlocked,
+        )?;
+        match &self.label {
+            Some(label) => draw_corner_label(&mut out, label),
+            None => Ok(()),
+        }
+    }
+
+    /// 'Y' returns to the title screen for another game; 'N', Esc and Ctrl-C quit.
+    fn handle_key(&mut self, key: KeyEvent, _context: &mut SceneContext) -> Transition {
+        if terminal::is_interrupt(&key) {
+            return Transition::Quit;
+        }
+        match key.code {
+            KeyCode::Char(c) if c.eq_ignore_ascii_case(&'y') => {
+                Transition::Switch(Box::new(TitleScene::new()))
+            }
+            KeyCode::Char(c) if c.eq_ignore_ascii_case(&'n') => Transition::Quit,
+            KeyCode::Esc => Transition::Quit,
+            _ => Transition::None, // Ignore other keys.
+        }
+    }
+}
@@ -0,0 +1,189 @@
+// asciiliens/src/scene/sandbox.rs
+
+//! The practice sandbox: a game whose field can be edited and whose rules can
+//! be tweaked while it runs, to explore balance and reproduce bug reports.
+//!
+//! The game only advances when the ship acts or a step key is pressed. A
+//! cursor places aliens and blasts anywhere on the field, the descent
+//! frequency and invulnerability can be changed at any time, and the field
+//! can be cleared or reset. Emptying the field does not win the game, so
+//! aliens can be placed on it again. Nothing played in the sandbox is saved.
+
+use super::{Scene, SceneContext, Transition};
+use crate::config::keys::KeyAction;
+use crate::display::{draw_sandbox_panel, terminal};
+use crate::game::alien::{Alien, AlienSprite};
+use crate::game::blast::Blast;
+use crate::game::options::GameOptions;
+use crate::game::{Game, GameEvent, GameState};
+use crate::util::constants::{
+    ALIEN_DESIGNS, GAME_HEIGHT, GAME_WIDTH, SANDBOX_HELP_TEXT, SANDBOX_MAX_DESCENT_FREQ,
+};
+use crossterm::event::{KeyCode, KeyEvent};
+use std::io::{self, Write};
+
+/// The number of frames the `>` key steps.
+const SANDBOX_FAST_STEPS: u32 = 10;
+
+/// A game being edited in the sandbox.
+#[derive(Debug)]
+pub struct SandboxScene {
+    options: GameOptions, // The options a reset starts the game from.
+    game: Game,
+    cursor: (u16, u16),     // Where aliens and blasts are placed.
+    next_kind: usize,       // The kind of the next alien placed.
+    descent_frequency: u64, // Kept across resets.
+    invulnerable: bool,     // Kept across resets.
+}
+
+impl SandboxScene {
+    /// Opens the sandbox on a new game with the given options, with the cursor
+    /// in the middle of the field.
+    ///
+    /// # Arguments
+    /// * `options` - The options of the game, and of every reset.
+    /// * `context` - The state shared by all scenes, for the display settings.
+    pub fn new(options: GameOptions, context: &SceneContext) -> Self {
+        let mut scene = Self {
+            options,
+            game: Game::with_options(options),
+            cursor: (GAME_WIDTH / 2, GAME_HEIGHT / 2),
+            next_kind: 0,
+            descent_frequency: options.difficulty.descent_frequency(),
+            invulnerable: false,
+        };
+        scene.reset(context);
+        scene
+    }
+
+    /// Returns the game being edited.
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Returns the cursor's x and y coordinates.
+    pub fn cursor(&self) -> (u16, u16) {
+        self.cursor
+    }
+
+    /// Starts the game over from its options, keeping the tweaked rules.
+    fn reset(&mut self, context: &SceneContext) {
+        self.game = Game::with_options(self.options);
+        self.game
+            .set_starfield_visible(context.settings.show_starfield());
+        self.game.set_preview_visible(context.settings.preview);
+        self.game
+            .set_reduced_motion(context.settings.reduced_motion);
+        self.game.set_descent_frequency(self.descent_frequency);
+        self.game.set_invulnerable(self.invulnerable);
+        self.game.set_endless(true); // Clearing the field must not end the game.
+    }
+
+    /// Moves the cursor, keeping it on the field above the score line.
+    fn move_cursor(&mut self, dx: i32, dy: i32) {
+        let (x, y) = self.cursor;
+        self.cursor = (
+            (i32::from(x) + dx).clamp(0, i32::from(GAME_WIDTH) - 1) as u16,
+            (i32::from(y) + dy).clamp(0, i32::from(GAME_HEIGHT) - 2) as u16,
+        );
+    }
+
+    /// Places an alien with its top-left corner at the cursor. Each alien
+    /// placed is of the next kind, cycling through them all.
+    ///
+    /// Near the right edge or the player's row, the alien is moved left or up
+    /// so it fits on the field and does not land an invasion on the spot.
+    fn place_alien(&mut self) {
+        let kind = self.next_kind;
+        self.next_kind = (kind + 1) % ALIEN_DESIGNS.len();
+        let sprite = AlienSprite::for_kind(kind);
+        let (x, y) = self.cursor;
+        let x = x.min(GAME_WIDTH.saturating_sub(sprite.width()));
+        // The alien's bottom row must stay above the row the ships fly on.
+        let y = y.min(self.game.player().y_pos().saturating_sub(sprite.height()));
+        self.game
+            .aliens_mut()
+            .push(Alien::with_sprite(x, y, kind, sprite));
+    }
+
+    /// Changes the descent frequency by the given number of frames.
+    fn change_descent_frequency(&mut self, change: i64) {
+        self.descent_frequency = self
+            .descent_frequency
+            .saturating_add_signed(change)
+            .clamp(1, SANDBOX_MAX_DESCENT_FREQ);
+        self.game.set_descent_frequency(self.descent_frequency);
+    }
+
+    /// Advances the game by the given number of frames, stopping early if it ends.
+    fn step(&mut self, event: GameEvent, frames: u32) {
+        for _ in 0..frames {
+            if self.game.state() != GameState::Playing {
+                break;
+            }
+            self.game.update(event);
+        }
+    }
+
+    /// Returns the status line: the tweaked rules and the frame.
+    fn status(&self) -> String {
+        format!(
+            "SANDBOX  Descent every {} frame(s)  Invulnerable: {}  Frame {}",
+            self.descent_frequency,
+            if self.invulnerable { "ON" } else { "OFF" },
+            self.game.frame_counter()
+        )
+    }
+}
+
+impl Scene for SandboxScene {
+    fn name(&self) -> &'static str {
+        "sandbox"
+    }
+
+    fn draw(&self, mut out: &mut dyn Write, _context: &SceneContext) -> io::Result<()> {
+        self.game.draw(&mut out)?;
+        draw_sandbox_panel(&mut out, &self.status(), SANDBOX_HELP_TEXT, self.cursor)
+    }
+
+    /// The ship's keys play a frame as usual; the sandbox's own keys, listed in
+    /// `SANDBOX_HELP_TEXT`, edit the game without advancing it, except for the
+    /// step keys. The quit key, Esc and Ctrl-C leave the sandbox.
+    fn handle_key(&mut self, key: KeyEvent, context: &mut SceneContext) -> Transition {
+        if terminal::is_interrupt(&key) || key.code == KeyCode::Esc {
+            return Transition::Pop;
+        }
+        match context.settings.keys.action_for(key.code) {
+            Some(KeyAction::MoveLeft) => self.step(GameEvent::MoveLeft, 1),
+            Some(KeyAction::MoveRight) => self.step(GameEvent::MoveRight, 1),
+            Some(KeyAction::Fire) => self.step(GameEvent::Fire, 1),
+            Some(KeyAction::Quit) => return Transition::Pop,
+            _ => match key.code {
+                KeyCode::Char('h') => self.move_cursor(-1, 0),
+                KeyCode::Char('j') => self.move_cursor(0, 1),
+                KeyCode::Char('k') => self.move_cursor(0, -1),
+                KeyCode::Char('l') => self.move_cursor(1, 0),
+                KeyCode::Char('a') => self.place_alien(),
+                KeyCode::Char('b') => {
+                    let (x, y) = self.cursor;
+                    self.game.blasts_mut().push(Blast::new(x, y));
+                }
+                KeyCode::Char('.') => self.step(GameEvent::AdvanceFrame, 1),
+                KeyCode::Char('>') => self.step(GameEvent::AdvanceFrame, SANDBOX_FAST_STEPS),
+                KeyCode::Char('+') | KeyCode::Char('=') => self.change_descent_frequency(1),
+                KeyCode::Char('-') => self.change_descent_frequency(-1),
+                KeyCode::Char('i') => {
+                    self.invulnerable = !self.invulnerable;
+                    self.game.set_invulnerable(self.invulnerable);
+                }
+                KeyCode::Char('c') => {
+                    self.game.aliens_mut().clear();
+                    self.game.blasts_mut().clear();
+                }
+                KeyCode::Char('r') => self.reset(context),
+                _ => {} // Other keys do nothing: the sandbox only steps when asked to.
+            },
+        }
+        Transition::None
+    }
+}
@@ -0,0 +1,104 @@
+// asciiliens/src/scene/title.rs
+
+//! The title scene: instructions, scoring, the difficulty picker and the
+//! "Ready?" prompt, plus the ways into the other menu screens. Unless reduced
+//! motion is on, the screen animates on a timer. While playing the daily
+//! challenge, its date and wave are shown in the corner.
+
+use super::game::GameScene;
+use super::high_scores::HighScoresScene;
+use super::hot_seat::HotSeatScene;
+use super::options::OptionsScene;
+use super::profile::ProfileScene;
+use super::{Scene, SceneContext, Transition};
+use crate::display::{draw_corner_label, draw_intro_screen, terminal};
+use crate::util::constants::{TAUNT_PHRASES, TITLE_FRAME_MS};
+use crossterm::event::{KeyCode, KeyEvent};
+use std::io::{self, Write};
+use std::time::Duration;
+
+/// The title screen shown when the game starts and after each game.
+#[derive(Debug, Default)]
+pub struct TitleScene {
+    taunt: Option<usize>, // The taunt shown after the player declined, if any.
+    frame: u64,           // The current frame of the title animation.
+}
+
+impl TitleScene {
+    /// Creates the title scene, with no taunt shown yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the index into `TAUNT_PHRASES` of the taunt on screen, if any.
+    pub fn taunt(&self) -> Option<usize> {
+        self.taunt
+    }
+
+    /// Returns the current frame of the title animation.
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+}
+
+impl Scene for TitleScene {
+    fn name(&self) -> &'static str {
+        "title"
+    }
+
+    fn draw(&self, mut out: &mut dyn Write, context: &SceneContext) -> io::Result<()> {
+        let frame = (!context.settings.reduced_motion).then_some(self.frame);
+        // The daily challenge has a difficulty of its own.
+        let difficulty = context.game_options().difficulty;
+        draw_intro_screen(&mut out, difficulty, self.taunt, frame)?;
+        match context.daily {
+            Some(daily) => draw_corner_label(
+                &mut out,
+                &format!("DAILY CHALLENGE {}  WAVE {}", daily, daily.wave()),
+            ),
+            None => Ok(()),
+        }
+    }
+
+    /// With reduced motion on, the screen is static and only redraws after key presses.
+    fn tick_interval(&self, context: &SceneContext) -> Option<Duration> {
+        (!context.settings.reduced_motion).then(|| Duration::from_millis(TITLE_FRAME_MS))
+    }
+
+    fn tick(&mut self, _context: &mut SceneContext) -> Transition {
+        self.frame = self.frame.wrapping_add(1);
+        Transition::None
+    }
+
+    /// 'Y' starts a game and 'N' cycles through the taunts. '2' starts a
+    /// two-player hot-seat match and 'C' a two-player co-op game. 'D' changes
+    /// the difficulty, 'O', 'H' and 'P' open the options, high score and
+    /// profile screens, and Ctrl-C quits. Other keys are ignored.
+    fn handle_key(&mut self, key: KeyEvent, context: &mut SceneContext) -> Transition {
+        if terminal::is_interrupt(&key) {
+            return Transition::Quit;
+        }
+        let KeyCode::Char(c) = key.code else {
+            return Transition::None; // Ignore all other key presses.
+        };
+        match c.to_ascii_lowercase() {
+            'y' => Transition::Switch(Box::new(GameScene::new(context))), // Start game.
+            '2' => Transition::Switch(Box::new(HotSeatScene::new(context))),
+            'c' => Transition::Switch(Box::new(GameScene::co_op(context))),
+            'n' => {
+                // Activate taunts after the first 'n', cycling to the next phrase each time.
+                self.taunt = Some((self.taunt.unwrap_or(0) + 1) % TAUNT_PHRASES.len());
+                Transition::None
+            }
+            'd' => {
+                context.settings.difficulty = context.settings.difficulty.next();
+                context.save_settings();
+                Transition::None
+            }
+            'o' => Transition::Push(Box::new(OptionsScene::new())),
+            'h' => Transition::Push(Box::new(HighScoresScene::new(context))),
+            'p' => Transition::Push(Box::new(ProfileScene)),
+            _ => Transition::None,
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// asciiliens/src/scores/mod.rs
+
+//! This module maintains the high score table: the best finished games,
+//! persisted as one `|`-separated line per entry in the user's data directory.
+//!
+//! `HighScores::record_in` enters a game into the file under a lock, so the
+//! sessions of the arcade server can share one table.
+
+use crate::game::difficulty::Difficulty;
+use crate::game::{Game, GameState};
+use crate::util::paths;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+/// The name of the high score file inside the game's data directory.
+const HIGH_SCORES_FILE_NAME: &str = "highscores.txt";
+/// The number of entries kept in the table.
+pub const MAX_HIGH_SCORES: usize = 10;
+
+/// Held while a high score file is read, updated and written back, so games
+/// finishing at the same time cannot overwrite each other's entries.
+static RECORD_LOCK: Mutex<()> = Mutex::new(());
+
+/// A single finished game in the high score table.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct HighScoreEntry {
+    /// The final score.
+    pub score: i32,
+    /// Whether the game was won.
+    pub won: bool,
+    /// The difficulty the game was played at.
+    pub difficulty: Difficulty,
+    /// The level the game was started at.
+    pub level: u16,
+    /// The seed of the game, so the run can be played again.
+    pub seed: u64,
+}
+
+impl HighScoreEntry {
+    /// Creates an entry describing a finished game.
+    pub fn from_game(game: &Game) -> Self {
+        let options = game.options();
+        Self {
+            score: game.score(),
+            won: game.state() == GameState::Win,
+            difficulty: options.difficulty,
+            level: options.level,
+            seed: game.seed(),
+        }
+    }
+
+    /// Serializes the entry as one line of the high score file.
+    fn serialize(&self) -> String {
+        format!(
+            "{}|{}|{}|{}|{}",
+            self.score,
+            if self.won { "win" } else { "loss" },
+            self.difficulty,
+            self.level,
+            self.seed
+        )
+    }
+
+    /// Parses one line of the high score file.
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.trim().split('|');
+        let entry = Self {
+            score: fields.next()?.parse().ok()?,
+            won: fields.next()? == "win",
+            difficulty: fields.next()?.parse().ok()?,
+            level: fields.next()?.parse().ok()?,
+            seed: fields.next()?.parse().ok()?,
+        };
+        Some(entry)
+    }
+}
+
+/// The high score table, best score first.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct HighScores {
+    entries: Vec<HighScoreEntry>,
+}
+
+impl HighScores {
+    /// Returns the default location of the high score file.
+    pub fn default_path() -> Option<PathBuf> {
+        paths::data_file(HIGH_SCORES_FILE_NAME)
+    }
+
+    /// Returns the entries, best score first.
+    pub fn entries(&self) -> &[HighScoreEntry] {
+        &self.entries
+    }
+
+    /// Adds an entry if it is good enough to make the table.
+    ///
+    /// # Returns
+    /// The entry's 1-based rank if it made the table, or `None` otherwise.
+    pub fn add(&mut self, entry: HighScoreEntry) -> Option<usize> {
+        // Ties keep the earlier entry ahead.
+        let index = self
+            .entries
+            .iter()
+            .position(|e| entry.score > e.score)
+            .unwrap_or(self.entries.len());
+        if index >= MAX_HIGH_SCORES {
+            return None;
+        }
+        self.entries.insert(index, entry);
+        self.entries.truncate(MAX_HIGH_SCORES);
+        Some(index + 1)
+    }
+
+    /// Loads the table from the given file. A missing file yields an empty table
+    /// and malformed lines are skipped.
+    pub fn load_from(path: &Path) -> HighScores {
+        let mut scores = HighScores::default();
+        if let Ok(contents) = fs::read_to_string(path) {
+            for entry in contents.lines().filter_map(HighScoreEntry::parse) {
+                scores.add(entry);
+            }
+        }
+        scores
+    }
+
+    /// Saves the table to the given file, creating its directory if needed.
+    ///
+    /// The table is written to a temporary file that then replaces the old
+    /// one, so the file is never seen half written.
+    pub fn save_to(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let contents: String = self.entries.iter().map(|e| e.serialize() + "\n").collect();
+        let mut temporary = path.as_os_str().to_owned();
+        temporary.push(".tmp");
+        fs::write(&temporary, contents)?;
+        fs::rename(&temporary, path)
+    }
+
+    /// Enters a finished game into the high score file at the given path.
+    ///
+    /// The file is loaded, updated and saved while holding a lock, so games
+    /// finishing at the same time in other threads keep their entries too.
+    ///
+    /// # Arguments
+    /// * `path` - The high score file.
+    /// * `entry` - The finished game.
+    ///
+    /// # Returns
+    /// The entry's 1-based rank if it made the table, or `None` otherwise.
+    pub fn record_in(path: &Path, entry: HighScoreEntry) -> io::Result<Option<usize>> {
+        let _guard = RECORD_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut scores = Self::load_from(path);
+        let rank = scores.add(entry);
+        if rank.is_some() {
+            scores.save_to(path)?;
+        }
+        Ok(rank)
+    }
+
+    /// Formats the table as plain text lines, for printing or for a screen.
+    pub fn table_lines(&self) -> Vec<String> {
+        if self.entries.is_empty() {
+            return vec!["No high scores yet. Go make some history!".to_string()];
+        }
+        let mut lines = vec![format!(
+            "{:>4}  {:>7}  {:<6}  {:<10}  {:>5}  {:>20}",
+            "Rank", "Score", "Result", "Difficulty", "Level", "Seed"
+        )];
+        for (rank, entry) in self.entries.iter().enumerate() {
+            lines.push(format!(
+                "{:>4}  {:>7}  {:<6}  {:<10}  {:>5}  {:>20}",
+                rank + 1,
+                entry.score,
+                if entry.won { "Win" } else { "Loss" },
+                entry.difficulty,
+                entry.level,
+                entry.seed
+            ));
+        }
+        lines
+    }
+}
@@ -0,0 +1,140 @@
+// asciiliens/src/sim/mod.rs
+
+//! This module runs games headlessly with a simple autopilot, to check game
+//! balance without a terminal. It powers the `simulate` command.
+
+use crate::game::options::GameOptions;
+use crate::game::{Game, GameEvent, GameState};
+
+/// A safety limit on the length of a simulated game, in frames.
+const MAX_SIMULATED_FRAMES: u64 = 100_000;
+
+/// Aggregated results of a batch of simulated games.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct SimulationSummary {
+    /// The number of games simulated.
+    pub games: u32,
+    /// The number of games won.
+    pub wins: u32,
+    /// The sum of all final scores.
+    pub total_score: i64,
+    /// The best final score, if any game was played.
+    pub best_score: Option<i32>,
+    /// The total number of blasts unleashed.
+    pub shots_fired: u64,
+    /// The total number of blasts that hit an alien.
+    pub hits: u64,
+    /// The total number of frames played.
+    pub frames: u64,
+}
+
+impl SimulationSummary {
+    /// Adds a finished game to the summary.
+    pub fn record(&mut self, game: &Game) {
+        let stats = game.stats();
+        self.games += 1;
+        if game.state() == GameState::Win {
+            self.wins += 1;
+        }
+        self.total_score += i64::from(game.score());
+        self.best_score = Some(
+            self.best_score
+                .map_or(game.score(), |b| b.max(game.score())),
+        );
+        self.shots_fired += u64::from(stats.shots_fired);
+        self.hits += u64::from(stats.hits);
+        self.frames += stats.frames_survived;
+    }
+
+    /// Formats the summary as plain text lines.
+    pub fn summary_lines(&self) -> Vec<String> {
+        let games = f64::from(self.games.max(1));
+        let accuracy = if self.shots_fired == 0 {
+            0.0
+        } else {
+            self.hits as f64 * 100.0 / self.shots_fired as f64
+        };
+        vec![
+            format!("Games simulated:  {}", self.games),
+            format!(
+                "Wins:             {} ({:.1}%)",
+                self.wins,
+                f64::from(self.wins) * 100.0 / games
+            ),
+            format!("Average score:    {:.1}", self.total_score as f64 / games),
+            format!(
+                "Best score:       {}",
+                self.best_score.map_or("-".to_string(), |s| s.to_string())
+            ),
+            format!("Accuracy:         {:.1}%", accuracy),
+            format!("Average frames:   {:.1}", self.frames as f64 / games),
+        ]
+    }
+}
+
+/// Chooses the autopilot's next move: line up under the lowest alien and fire.
+///
+/// # Arguments
+/// * `game` - The `Game` being played.
+///
+/// # Returns
+/// The `GameEvent` the autopilot would input this turn.
+pub fn autopilot(game: &Game) -> GameEvent {
+    let player_x = game.player().x();
+    // The most urgent target is the lowest active alien, closest to the player on ties.
+    let target = game
+        .aliens()
+        .iter()
+        .filter(|alien| alien.alive() && alien.explosion_frame() == 0)
+        .max_by_key(|alien| (alien.y(), std::cmp::Reverse(alien.x().abs_diff(player_x))));
+
+    match target {
+        Some(alien) if player_x < alien.x() => GameEvent::MoveRight,
+        Some(alien) if player_x >= alien.x() + alien.width() => GameEvent::MoveLeft,
+        Some(_) => GameEvent::Fire,
+        None => GameEvent::AdvanceFrame,
+    }
+}
+
+/// Plays a single game to the end with the autopilot.
+///
+/// # Arguments
+/// * `options` - The `GameOptions` for the game.
+///
+/// # Returns
+/// The finished `Game`.
+pub fn simulate_game(options: GameOptions) -> Game {
+    let mut game = Game::with_options(options);
+    while game.state() == GameState::Playing {
+        if game.frame_counter() >= MAX_SIMULATED_FRAMES {
+            game.update(GameEvent::Quit);
+            break;
+        }
+        let event = autopilot(&game);
+        game.update(event);
+    }
+    game
+}
+
+/// Simulates a batch of games with the autopilot.
+///
+/// With a seed in `options`, game `i` uses seed `seed + i`, so the whole batch
+/// is reproducible; without one, every game gets a random seed.
+///
+/// # Arguments
+/// * `games` - The number of games to simulate.
+/// * `options` - The `GameOptions` shared by every game.
+///
+/// # Returns
+/// The aggregated `SimulationSummary`.
+pub fn simulate(games: u32, options: GameOptions) -> SimulationSummary {
+    let mut summary = SimulationSummary::default();
+    for i in 0..games {
+        let game_options = GameOptions {
+            seed: options.seed.map(|seed| seed.wrapping_add(u64::from(i))),
+            ..options
+        };
+        summary.record(&simulate_game(game_options));
+    }
+    summary
+}
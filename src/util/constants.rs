@@ -10,8 +10,15 @@ pub const GAME_HEIGHT: u16 = 24;
 
 /// The complete ASCII art string for the player's ship.
 pub const PLAYER_SHIP_ART: &str = "║_||_║";
+/// The ASCII art of the second ship in a co-op game. It is as wide as `PLAYER_SHIP_ART`.
+pub const PLAYER_TWO_SHIP_ART: &str = "╠_/\\_╣";
 /// The character used to represent blasts.
 pub const BLAST_CHAR: char = '*';
+/// The character marking, in the turn preview, the row an alien will land on when it next descends.
+pub const DESCENT_PREVIEW_CHAR: char = 'v';
+
+/// The number of stars in the optional playfield starfield.
+pub const STAR_COUNT: usize = 36;
 
 /// The frequency at which aliens move down, in game frames.
 /// For example, a value of 10 means aliens move down every 10 frames.
@@ -19,9 +26,9 @@ pub const ALIEN_MOVE_DOWN_FREQ: u64 = 10;
 
 /// The visual width of the player's ship in characters.
 pub const PLAYER_WIDTH: u16 = 6;
-/// The visual width of an alien in characters.
+/// The visual width of the standard alien sprites in characters. Custom sprites may be wider.
 pub const ALIEN_WIDTH: u16 = 2;
-/// The visual height of an alien in characters.
+/// The visual height of the standard alien sprites in characters. Custom sprites may be taller.
 pub const ALIEN_HEIGHT: u16 = 2;
 
 /// The fixed vertical offset from the bottom of the screen for the player's ship.
@@ -30,6 +37,28 @@ pub const PLAYER_Y_OFFSET: u16 = 2;
 /// The starting score for a new game.
 pub const INITIAL_SCORE: i32 = 100;
 
+/// The highest level a game can be started at.
+pub const MAX_LEVEL: u16 = 9;
+
+/// In real-time mode, the number of milliseconds after which the game advances
+/// a frame on its own if no key has been pressed.
+pub const REALTIME_TICK_MS: u64 = 150;
+
+/// The number of milliseconds each frame is shown for when watching a replay.
+pub const REPLAY_FRAME_MS: u64 = 120;
+
+/// The number of milliseconds each frame of the animated title screen is shown for.
+pub const TITLE_FRAME_MS: u64 = 150;
+
+/// The number of title screen frames the "Ready?" prompt stays shown, and then hidden, while blinking.
+pub const TITLE_BLINK_FRAMES: u64 = 4;
+
+/// The number of columns between the aliens marching across the title screen.
+pub const TITLE_PARADE_SPACING: usize = 10;
+
+/// The number of stars on each empty row of the title screen.
+pub const TITLE_STARS_PER_ROW: usize = 5;
+
 /// A collection of 2x2 ASCII character designs for various alien types.
 /// Each inner array represents [top-left, top-right, bottom-left, bottom-right] characters.
 pub const ALIEN_DESIGNS: [[char; 4]; 4] = [
@@ -41,6 +70,28 @@ pub const ALIEN_DESIGNS: [[char; 4]; 4] = [
     [234 as char, 234 as char, 196 as char, 196 as char], // ΩΩ, ── (Omega, Box Drawing Light Horizontal)
 ];
 
+/// The animation poses of each alien kind, which alternate every time an alien
+/// steps. Each pose lists the characters of an `ALIEN_WIDTH` x `ALIEN_HEIGHT`
+/// sprite row by row; the first pose is the kind's design in `ALIEN_DESIGNS`.
+pub static ALIEN_POSES: [&[&[char]]; 4] = [
+    &[
+        &ALIEN_DESIGNS[0],
+        &[176 as char, 176 as char, 178 as char, 178 as char], // Shades swapped.
+    ],
+    &[
+        &ALIEN_DESIGNS[1],
+        &[223 as char, 223 as char, 219 as char, 219 as char], // Rows swapped.
+    ],
+    &[
+        &ALIEN_DESIGNS[2],
+        &[220 as char, 220 as char, 223 as char, 223 as char], // Legs drawn in.
+    ],
+    &[
+        &ALIEN_DESIGNS[3],
+        &[196 as char, 196 as char, 234 as char, 234 as char], // Rows swapped.
+    ],
+];
+
 /// Defines the stages of the alien explosion animation.
 /// Each array indicates which of the 2x2 characters turn to '*' (1) or remain
 /// their original design (0) at a given explosion frame.
@@ -53,6 +104,28 @@ pub const EXPLOSION_STAGE_3: [u8; 4] = [1, 1, 1, 0]; // Top-left, top-right, bot
 /// The fourth and final stage of the alien explosion animation before disappearance.
 pub const EXPLOSION_STAGE_4: [u8; 4] = [1, 1, 1, 1]; // All become '*'
 
+/// The debris thrown out when an alien is destroyed: (columns per frame, rows per frame, glyph).
+pub const DEBRIS_PARTICLES: [(i16, i16, char); 8] = [
+    (-2, -1, '\\'),
+    (0, -1, '|'),
+    (2, -1, '/'),
+    (-3, 0, '-'),
+    (3, 0, '-'),
+    (-2, 1, '/'),
+    (0, 1, '|'),
+    (2, 1, '\\'),
+];
+/// The number of frames debris keeps flying before it fades.
+pub const DEBRIS_FRAMES: u8 = 3;
+/// The number of frames a score popup floats upward before it fades.
+pub const SCORE_POPUP_FRAMES: u8 = 4;
+/// The number of frames the player's ship flashes after being destroyed.
+pub const DEATH_FLASH_FRAMES: u8 = 6;
+/// The two alternating images of the player's ship while it flashes.
+pub const DEATH_FLASH_ART: [&str; 2] = ["*#**#*", "#*##*#"];
+/// The number of milliseconds each frame of the effects shown after a game ends is drawn for.
+pub const EFFECT_FRAME_MS: u64 = 90;
+
 // --- ASCII Art & Phrases ---
 
 /// The ASCII art for the game's introductory title screen.
@@ -67,19 +140,12 @@ pub const INTRO_TITLE_ART: [&str; 5] = [
 
 /// The basic instructional text displayed on the intro screen.
 /// This section focuses on controls.
-pub const INSTRUCTIONS_TEXT: [&str; 4] = [
+pub const INSTRUCTIONS_TEXT: [&str; 5] = [
     "Navigate your ship (║_||_║) using LEFT/RIGHT arrow keys.",
     "Press SPACE to fire blasts (*).",
-    "Each action (move or fire) advances one game frame.",
-    "Strategic action is key – you cannot move and fire in the same 'turn'!",
-];
-
-/// The scoring rules displayed on the intro screen.
-pub const SCORING_TEXT: [&str; 4] = [
-    "Scoring:",
-    "- Start with 100 points.",
-    "-1 point for each movement (left/right).",
-    "+250 points for destroying an ASCIIlien.",
+    "Each action advances one frame – you cannot move and fire in the same 'turn'!",
+    "2: two-player hot seat, taking turns  C: two-player co-op (A/D move, W fires)",
+    "D: difficulty  O: options  H: high scores  P: profile (in play, P pauses)",
 ];
 
 /// A collection of taunt phrases displayed when the player hesitates.
@@ -114,5 +180,106 @@ pub const LOSE_ART: [&str; 5] = [
     "                           GAME OVER                                          ",
 ];
 
+/// The ASCII art banner for the pilot profile screen.
+pub const PROFILE_ART: [&str; 3] = [
+    "╔══════════════════════════════════════════════════════════════════════════════╗",
+    "║                              PILOT PROFILE                                   ║",
+    "╚══════════════════════════════════════════════════════════════════════════════╝",
+];
+
+/// The ASCII art banner for the high score screen.
+pub const HIGH_SCORES_ART: [&str; 3] = [
+    "╔══════════════════════════════════════════════════════════════════════════════╗",
+    "║                               HIGH SCORES                                    ║",
+    "╚══════════════════════════════════════════════════════════════════════════════╝",
+];
+
+/// The ASCII art banner for the screens of a two-player hot-seat match.
+pub const HOT_SEAT_ART: [&str; 3] = [
+    "╔══════════════════════════════════════════════════════════════════════════════╗",
+    "║                             HOT-SEAT MATCH                                   ║",
+    "╚══════════════════════════════════════════════════════════════════════════════╝",
+];
+
+/// The number of players in a hot-seat match.
+pub const HOT_SEAT_PLAYERS: usize = 2;
+
+/// The number of rounds in a hot-seat match. Each round, every player plays one game.
+pub const HOT_SEAT_ROUNDS: usize = 3;
+
+/// The prompt shown before each turn of a hot-seat match.
+pub const TURN_PROMPT: &str = "Press any key to start your turn (ESC abandons the match). ";
+
+/// The ASCII art banner for the options screen.
+pub const OPTIONS_ART: [&str; 3] = [
+    "╔══════════════════════════════════════════════════════════════════════════════╗",
+    "║                                 OPTIONS                                      ║",
+    "╚══════════════════════════════════════════════════════════════════════════════╝",
+];
+
+/// The key help shown on the second row of the sandbox.
+pub const SANDBOX_HELP_TEXT: &str =
+    "hjkl:cursor a:alien b:blast .:step >:x10 +/-:descent i:invuln c:clear r:reset";
+
+/// The most frames the sandbox lets the aliens wait between descents.
+pub const SANDBOX_MAX_DESCENT_FREQ: u64 = 99;
+
+/// The most settings the options screen shows at once; the list scrolls to the selected one.
+pub const OPTIONS_VISIBLE_SETTINGS: usize = 17;
+
+/// The key help shown below the settings on the options screen.
+pub const OPTIONS_HELP_TEXT: &str =
+    "UP/DOWN: select   LEFT/RIGHT: change   ENTER: rebind key   ESC: save and return";
+
+/// The banner drawn over the playfield while the game is paused.
+pub const PAUSE_ART: [&str; 5] = [
+    "╔══════════════════════════════════╗",
+    "║              PAUSED              ║",
+    "║                                  ║",
+    "║ P: resume  O: options  Q: quit   ║",
+    "╚══════════════════════════════════╝",
+];
+
+/// The prompt displayed on informational screens that return to the previous screen.
+pub const RETURN_PROMPT: &str = "Press any key to return. ";
+
 /// The label for displaying the final score.
 pub const FINAL_SCORE_LABEL: &str = "Score:";
+
+/// The TCP port a networked game is hosted on when `--port` is not given.
+pub const NET_DEFAULT_PORT: u16 = 4747;
+
+/// During a networked game, the number of milliseconds between checks for the other player's messages.
+pub const NET_POLL_MS: u64 = 30;
+
+/// The number of seconds to wait for the other player during the handshake of a networked game.
+pub const NET_HANDSHAKE_TIMEOUT_SECS: u64 = 10;
+
+/// The number of milliseconds a spectator's writer thread waits for it to take a frame before giving up on it.
+pub const SPECTATOR_WRITE_TIMEOUT_MS: u64 = 2000;
+
+/// The number of frames queued for a spectator before it is dropped for falling behind.
+pub const SPECTATOR_QUEUE_FRAMES: usize = 16;
+
+/// The ASCII art banner for the end screen of a networked game.
+pub const NET_ART: [&str; 3] = [
+    "╔══════════════════════════════════════════════════════════════════════════════╗",
+    "║                               NETWORK GAME                                   ║",
+    "╚══════════════════════════════════════════════════════════════════════════════╝",
+];
+
+/// The prompt displayed once a networked game has ended.
+pub const NET_END_PROMPT: &str = "Press any key to exit. ";
+
+/// The TCP port the arcade server listens on when `--port` is not given.
+pub const ARCADE_DEFAULT_PORT: u16 = 2323;
+
+/// The number of players the arcade server admits at once when `--max-players` is not given.
+pub const ARCADE_DEFAULT_MAX_PLAYERS: usize = 16;
+
+/// The number of milliseconds the arcade server waits for a new player's
+/// terminal to report its size before starting without it.
+pub const ARCADE_SIZE_WAIT_MS: u64 = 500;
+
+/// The message shown to a player turned away because the arcade is full.
+pub const ARCADE_FULL_MESSAGE: &str = "The arcade is full. Please try again later.";
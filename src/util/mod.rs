@@ -4,3 +4,5 @@
 
 /// Declares the `constants` submodule, which holds game-wide constant values.
 pub mod constants;
+/// Declares the `paths` submodule, which locates the files persisted between sessions.
+pub mod paths;
@@ -0,0 +1,31 @@
+// asciiliens/src/util/paths.rs
+
+//! This module locates the files the game persists between sessions: data
+//! (profile, high scores, replays) and configuration (settings).
+
+use std::path::PathBuf;
+
+/// The name of the game's directory inside the user's data and config directories.
+const DATA_DIR_NAME: &str = "asciiliens";
+
+/// Returns the path of a file inside the game's data directory.
+///
+/// # Arguments
+/// * `file_name` - The name of the file, e.g. `"profile.txt"`.
+///
+/// # Returns
+/// `Some(PathBuf)` for the file, or `None` if the platform has no known data directory.
+pub fn data_file(file_name: &str) -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join(DATA_DIR_NAME).join(file_name))
+}
+
+/// Returns the path of a file inside the game's config directory.
+///
+/// # Arguments
+/// * `file_name` - The name of the file, e.g. `"config.toml"`.
+///
+/// # Returns
+/// `Some(PathBuf)` for the file, or `None` if the platform has no known config directory.
+pub fn config_file(file_name: &str) -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join(DATA_DIR_NAME).join(file_name))
+}